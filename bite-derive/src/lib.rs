@@ -0,0 +1,418 @@
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use {
+    proc_macro::TokenStream,
+    proc_macro2::Span,
+    proc_macro2::TokenStream as Tokens,
+
+    syn::Attribute,
+    syn::Data,
+    syn::DeriveInput,
+    syn::Error,
+    syn::Fields,
+    syn::Ident,
+    syn::LitStr,
+    syn::Member,
+    syn::Type,
+};
+
+
+
+// derives `bite::BiteRead` for a struct, reading each field in declaration order.
+#[proc_macro_derive(BiteRead, attributes(bite))]
+pub fn derive_read(input: TokenStream) -> TokenStream {
+    expand(input, read)
+}
+
+// derives `bite::BiteWrite` for a struct, writing each field in declaration order.
+#[proc_macro_derive(BiteWrite, attributes(bite))]
+pub fn derive_write(input: TokenStream) -> TokenStream {
+    expand(input, write)
+}
+
+// derives both `bite::BiteRead` and `bite::BiteWrite` for a c-like enum, stored as its discriminant.
+//
+// the discriminant is stored as the integer named by `#[bite(repr = "...")]`, or by the enum's own `#[repr(...)]`,
+// and reading one that matches no variant fails with `std::io::ErrorKind::InvalidData`. a variant marked
+// `#[bite(other)]` - say `Unknown(u8)` - holds any such discriminant instead. its value is written as is, unless it
+// belongs to another variant - an `Unknown(n)` like that couldn't be read back, so writing it fails with
+// `std::io::ErrorKind::InvalidInput`.
+#[proc_macro_derive(BiteEnum, attributes(bite))]
+pub fn derive_enum(input: TokenStream) -> TokenStream {
+    expand(input, enumeration)
+}
+
+fn expand(input: TokenStream, derive: fn(&DeriveInput) -> Result<Tokens, Error>) -> TokenStream {
+    let result = syn::parse::<DeriveInput>(input).and_then(|x| derive(&x));
+
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+
+
+// the options given in `#[bite(...)]` attributes.
+#[derive(Default)]
+struct Options {
+    // `endian = "big" | "little" | "native" | "network"` - the encoding of a struct or field, which otherwise
+    // inherits the encoding that it's being read or written with.
+    endian: Option<Tokens>,
+
+    // `len = "field"` - the earlier field that holds the number of elements in a `Vec` field.
+    len:    Option<Ident>,
+
+    // `repr = "u8" | "i8" | ... | "i64"` - the integer type that an enum's discriminant is stored as.
+    repr:   Option<Ident>,
+
+    // `other` - marks the single-field variant that holds any discriminant not matching another variant.
+    other:  bool,
+}
+
+fn options(attributes: &[Attribute]) -> Result<Options, Error> {
+    let mut options = Options::default();
+
+    for attribute in attributes.iter().filter(|x| x.path().is_ident("bite")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("other") {
+                options.other = true;
+                return Ok(());
+            }
+
+            let value: LitStr = meta.value()?.parse()?;
+
+            if meta.path.is_ident("endian") {
+                options.endian = Some(match value.value().as_str() {
+                    "big"     => quote!(::bite::BigEndian),
+                    "little"  => quote!(::bite::LittleEndian),
+                    "native"  => quote!(::bite::NativeEndian),
+                    "network" => quote!(::bite::NetworkEndian),
+                    _         => return Err(Error::new(value.span(), "expected one of \"big\", \"little\", \"native\" or \"network\"")),
+                });
+            } else if meta.path.is_ident("len") {
+                options.len = Some(value.parse()?);
+            } else if meta.path.is_ident("repr") {
+                options.repr = Some(match value.value().as_str() {
+                    "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" => value.parse()?,
+                    _ => return Err(Error::new(value.span(), "expected an integer type from \"u8\" to \"i64\"")),
+                });
+            } else {
+                return Err(meta.error("unknown bite attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+
+
+// a field of the struct being derived.
+struct Field<'a> {
+    member:  Member,
+    binding: Ident,
+    ty:      &'a Type,
+    endian:  Tokens,
+    len:     Option<Ident>,
+}
+
+fn fields<'a>(input: &'a DeriveInput) -> Result<(&'a Fields, Vec<Field<'a>>), Error> {
+    let data = match input.data {
+        Data::Struct(ref x) => x,
+        _                   => return Err(Error::new(Span::call_site(), "BiteRead and BiteWrite can only be derived for structs - use BiteEnum for enums")),
+    };
+
+    let outer     = options(&input.attrs)?;
+    let inherited = outer.endian.unwrap_or_else(|| quote!(E));
+    let mut list  = Vec::<Field>::new();
+
+    if outer.len.is_some() || outer.repr.is_some() || outer.other {
+        return Err(Error::new(Span::call_site(), "only `endian` can be used on structs"));
+    }
+
+    for (i, field) in data.fields.iter().enumerate() {
+        let options = options(&field.attrs)?;
+
+        if options.repr.is_some() || options.other {
+            return Err(Error::new(Span::call_site(), "only `endian` and `len` can be used on fields"));
+        }
+
+        let (member, binding) = match field.ident {
+            Some(ref x) => (Member::Named(x.clone()), format_ident!("field_{}", x)),
+            None        => (Member::Unnamed(i.into()), format_ident!("field_{}", i)),
+        };
+
+        if let Some(ref len) = options.len {
+            if !list.iter().any(|x| x.member == Member::Named(len.clone())) {
+                return Err(Error::new(len.span(), "`len` must name an earlier field"));
+            }
+        }
+
+        list.push(Field {
+            member,
+            binding,
+            ty:     &field.ty,
+            endian: options.endian.unwrap_or_else(|| inherited.clone()),
+            len:    options.len,
+        });
+    }
+
+    Ok((&data.fields, list))
+}
+
+// the generics of `input`, with `bound` added to each of its type parameters.
+fn bounded(input: &DeriveInput, bound: Tokens) -> syn::Generics {
+    let mut generics = input.generics.clone();
+
+    for parameter in generics.type_params_mut() {
+        parameter.bounds.push(syn::parse_quote!(#bound));
+    }
+
+    generics
+}
+
+
+
+fn read(input: &DeriveInput) -> Result<Tokens, Error> {
+    let name                   = &input.ident;
+    let generics               = bounded(input, quote!(::bite::BiteRead));
+    let (shape, fields)        = fields(input)?;
+    let (impls, types, wheres) = generics.split_for_impl();
+
+    let reads = fields.iter().map(|field| {
+        let Field { ref binding, ty, ref endian, .. } = *field;
+
+        match field.len {
+            Some(ref len) => {
+                let length = format_ident!("field_{}", len);
+
+                quote! {
+                    let #binding: #ty = {
+                        let length = <usize as ::std::convert::TryFrom<_>>::try_from(#length)
+                            .map_err(|_| ::std::io::Error::new(::std::io::ErrorKind::InvalidData, "length is out of range"))?;
+
+                        let mut values = ::std::vec::Vec::new();
+
+                        for _ in 0..length {
+                            values.push(::bite::BiteRead::read_from::<#endian, R>(stream)?);
+                        }
+
+                        values
+                    };
+                }
+            },
+            None => quote! {
+                let #binding = <#ty as ::bite::BiteRead>::read_from::<#endian, R>(stream)?;
+            },
+        }
+    });
+
+    let bindings = fields.iter().map(|x| &x.binding);
+    let members  = fields.iter().map(|x| &x.member);
+
+    let value = match *shape {
+        Fields::Named(_)   => quote!(#name { #(#members: #bindings),* }),
+        Fields::Unnamed(_) => quote!(#name ( #(#bindings),* )),
+        Fields::Unit       => quote!(#name),
+    };
+
+    Ok(quote! {
+        impl #impls ::bite::BiteRead for #name #types #wheres {
+            #[inline]
+            fn read_from<E: ::bite::Endianness, R: ::std::io::Read + ?Sized>(stream: &mut R) -> ::std::result::Result<Self, ::std::io::Error> {
+                #(#reads)*
+                ::std::result::Result::Ok(#value)
+            }
+        }
+    })
+}
+
+fn write(input: &DeriveInput) -> Result<Tokens, Error> {
+    let name                   = &input.ident;
+    let generics               = bounded(input, quote!(::bite::BiteWrite));
+    let (_, fields)            = fields(input)?;
+    let (impls, types, wheres) = generics.split_for_impl();
+
+    let writes = fields.iter().map(|field| {
+        let Field { ref member, ref endian, .. } = *field;
+
+        match field.len {
+            Some(ref len) => {
+                let message = format!("`{}::{{}}` has {{}} elements, but `{}::{}` is {{}}", name, name, len);
+                let field   = match *member { Member::Named(ref x) => x.to_string(), Member::Unnamed(ref x) => x.index.to_string() };
+
+                quote! {
+                    if ::std::convert::TryFrom::try_from(self.#len).ok() != ::std::option::Option::Some(self.#member.len()) {
+                        let message = ::std::format!(#message, #field, self.#member.len(), self.#len);
+                        return ::std::result::Result::Err(::std::io::Error::new(::std::io::ErrorKind::InvalidInput, message));
+                    }
+
+                    for value in self.#member.iter() {
+                        ::bite::BiteWrite::write_to::<#endian, W>(value, stream)?;
+                    }
+                }
+            },
+            None => quote! {
+                ::bite::BiteWrite::write_to::<#endian, W>(&self.#member, stream)?;
+            },
+        }
+    });
+
+    Ok(quote! {
+        impl #impls ::bite::BiteWrite for #name #types #wheres {
+            #[inline]
+            fn write_to<E: ::bite::Endianness, W: ::std::io::Write + ?Sized>(&self, stream: &mut W) -> ::std::result::Result<(), ::std::io::Error> {
+                #(#writes)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+
+
+fn enumeration(input: &DeriveInput) -> Result<Tokens, Error> {
+    let name  = &input.ident;
+    let outer = options(&input.attrs)?;
+
+    let data = match input.data {
+        Data::Enum(ref x) => x,
+        _                 => return Err(Error::new(Span::call_site(), "BiteEnum can only be derived for enums")),
+    };
+
+    if outer.len.is_some() || outer.other {
+        return Err(Error::new(Span::call_site(), "only `repr` and `endian` can be used on enums"));
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(Span::call_site(), "BiteEnum can't be derived for generic enums"));
+    }
+
+    // the discriminant type falls back to the enum's own `#[repr(...)]`.
+    let repr = match outer.repr {
+        Some(x) => x,
+        None    => repr(&input.attrs)?.ok_or_else(|| Error::new(Span::call_site(), "BiteEnum needs a `#[bite(repr = \"...\")]` attribute"))?,
+    };
+
+    let endian = outer.endian.unwrap_or_else(|| quote!(E));
+
+    let mut constants = Vec::new();
+    let mut declared  = Vec::new();
+    let mut reads     = Vec::new();
+    let mut writes    = Vec::new();
+    let mut other     = None;
+    let mut previous  = None;
+
+    for (i, variant) in data.variants.iter().enumerate() {
+        let ident = &variant.ident;
+
+        if options(&variant.attrs)?.other {
+            match variant.fields {
+                Fields::Unnamed(ref x) if x.unnamed.len() == 1 && other.is_none() && variant.discriminant.is_none() => {
+                    other = Some(ident);
+                    continue;
+                },
+                _ => return Err(Error::new(ident.span(), "`other` must mark a single variant with one unnamed field and no discriminant")),
+            }
+        }
+
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(ident.span(), "BiteEnum variants can't have fields, other than the `other` variant"));
+        }
+
+        // variants without an explicit discriminant follow on from the one before, as they do in rust.
+        let constant = format_ident!("DISCRIMINANT_{}", i);
+        let value    = match (&variant.discriminant, previous) {
+            (&Some((_, ref x)), _) => quote!(#x),
+            (&None, Some(ref x))   => quote!(#x + 1),
+            (&None, None)          => quote!(0),
+        };
+
+        constants.push(quote!(const #constant: #repr = #value;));
+        declared .push(quote!(#constant));
+        reads    .push(quote!(#constant => #name::#ident,));
+        writes   .push(quote!(#name::#ident => #constant,));
+        previous = Some(constant);
+    }
+
+    let unknown = match other {
+        Some(x) => quote!(value => #name::#x(value),),
+        None    => {
+            let message = format!("{{}} is not a valid `{}`", name);
+            quote!(value => return ::std::result::Result::Err(::std::io::Error::new(::std::io::ErrorKind::InvalidData, ::std::format!(#message, value))),)
+        },
+    };
+
+    // an `other` value that belongs to a declared variant would read back as that variant.
+    let known = match other {
+        Some(x) if !declared.is_empty() => {
+            let message = format!("`{}::{}({{}})` holds the discriminant of another variant", name, x);
+            quote! {
+                #name::#x(value) => match value {
+                    #(#declared)|* => return ::std::result::Result::Err(::std::io::Error::new(::std::io::ErrorKind::InvalidInput, ::std::format!(#message, value))),
+                    value => value,
+                },
+            }
+        },
+        Some(x) => quote!(#name::#x(value) => value,),
+        None    => quote!(),
+    };
+
+    let constants = quote!(#(#constants)*);
+
+    Ok(quote! {
+        impl ::bite::BiteRead for #name {
+            #[inline]
+            fn read_from<E: ::bite::Endianness, R: ::std::io::Read + ?Sized>(stream: &mut R) -> ::std::result::Result<Self, ::std::io::Error> {
+                #constants
+
+                let value = match <#repr as ::bite::BiteRead>::read_from::<#endian, R>(stream)? {
+                    #(#reads)*
+                    #unknown
+                };
+
+                ::std::result::Result::Ok(value)
+            }
+        }
+
+        impl ::bite::BiteWrite for #name {
+            #[inline]
+            fn write_to<E: ::bite::Endianness, W: ::std::io::Write + ?Sized>(&self, stream: &mut W) -> ::std::result::Result<(), ::std::io::Error> {
+                #constants
+
+                let value: #repr = match *self {
+                    #(#writes)*
+                    #known
+                };
+
+                ::bite::BiteWrite::write_to::<#endian, W>(&value, stream)
+            }
+        }
+    })
+}
+
+// the integer type given in a `#[repr(...)]` attribute, if there is one.
+fn repr(attributes: &[Attribute]) -> Result<Option<Ident>, Error> {
+    let mut repr = None;
+
+    for attribute in attributes.iter().filter(|x| x.path().is_ident("repr")) {
+        attribute.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if ["u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64"].contains(&ident.to_string().as_str()) {
+                    repr = Some(ident.clone());
+                }
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(repr)
+}
@@ -0,0 +1,141 @@
+use {
+    std,
+    std::io::Read,
+
+    bite::BigEndian,
+    bite::BiteReadExpandedExt,
+    bite::FrameFormat,
+};
+
+
+
+pub trait BiteReadBeExt: Read {
+    #[inline] fn read_u8_be           (&mut self)                      -> Result<    u8,  std::io::Error> { BiteReadExpandedExt::read_u8                        (self)          }
+    #[inline] fn read_i8_be           (&mut self)                      -> Result<    i8,  std::io::Error> { BiteReadExpandedExt::read_i8                        (self)          }
+    #[inline] fn read_u16_be          (&mut self)                      -> Result<   u16,  std::io::Error> { BiteReadExpandedExt::read_u16          ::<BigEndian>(self)          }
+    #[inline] fn read_i16_be          (&mut self)                      -> Result<   i16,  std::io::Error> { BiteReadExpandedExt::read_i16          ::<BigEndian>(self)          }
+    #[inline] fn read_u24_be          (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u24          ::<BigEndian>(self)          }
+    #[inline] fn read_i24_be          (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i24          ::<BigEndian>(self)          }
+    #[inline] fn read_u32_be          (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u32          ::<BigEndian>(self)          }
+    #[inline] fn read_i32_be          (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i32          ::<BigEndian>(self)          }
+    #[inline] fn read_u64_be          (&mut self)                      -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_u64          ::<BigEndian>(self)          }
+    #[inline] fn read_i64_be          (&mut self)                      -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_i64          ::<BigEndian>(self)          }
+    #[inline] fn read_u128_be         (&mut self)                      -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_u128         ::<BigEndian>(self)          }
+    #[inline] fn read_i128_be         (&mut self)                      -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_i128         ::<BigEndian>(self)          }
+    #[inline] fn read_uint_be         (&mut self, bytes: usize)        -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_uint         ::<BigEndian>(self, bytes)   }
+    #[inline] fn read_int_be          (&mut self, bytes: usize)        -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_int          ::<BigEndian>(self, bytes)   }
+    #[inline] fn read_uint128_be      (&mut self, bytes: usize)        -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_uint128      ::<BigEndian>(self, bytes)   }
+    #[inline] fn read_int128_be       (&mut self, bytes: usize)        -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_int128       ::<BigEndian>(self, bytes)   }
+    #[inline] fn read_f32_be          (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f32          ::<BigEndian>(self)          }
+    #[inline] fn read_f64_be          (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_f64          ::<BigEndian>(self)          }
+    #[inline] fn read_f16_be          (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f16          ::<BigEndian>(self)          }
+    #[inline] fn read_bf16_be         (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_bf16         ::<BigEndian>(self)          }
+    #[inline] fn read_ibm_f32_be      (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_ibm_f32      ::<BigEndian>(self)          }
+    #[inline] fn read_ibm_f64_be      (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_ibm_f64      ::<BigEndian>(self)          }
+    #[inline] fn read_vax_f_be        (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_vax_f        ::<BigEndian>(self)          }
+    #[inline] fn read_vax_d_be        (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_vax_d        ::<BigEndian>(self)          }
+    #[inline] fn read_vax_g_be        (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_vax_g        ::<BigEndian>(self)          }
+
+    #[inline] fn read_slice_u16_be    (&mut self, values: &mut [u16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u16    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_i16_be    (&mut self, values: &mut [i16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i16    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_u32_be    (&mut self, values: &mut [u32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u32    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_i32_be    (&mut self, values: &mut [i32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i32    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_u64_be    (&mut self, values: &mut [u64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u64    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_i64_be    (&mut self, values: &mut [i64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i64    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_u128_be   (&mut self, values: &mut [u128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u128   ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_i128_be   (&mut self, values: &mut [i128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i128   ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_f32_be    (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f32    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_f64_be    (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f64    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_f16_be    (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f16    ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_bf16_be   (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_bf16   ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_ibm_f32_be(&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_ibm_f32::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_ibm_f64_be(&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_ibm_f64::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_vax_f_be  (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_f  ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_vax_d_be  (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_d  ::<BigEndian>(self, values)  }
+    #[inline] fn read_slice_vax_g_be  (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_g  ::<BigEndian>(self, values)  }
+
+    #[inline] fn read_framed_be       (&mut self)                      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed       ::<BigEndian>(self)          }
+    #[inline] fn read_framed_max_be   (&mut self, maximum: usize)      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed_max   ::<BigEndian>(self, maximum) }
+
+    #[inline]
+    fn read_framed_format_be(&mut self, format: FrameFormat, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+        BiteReadExpandedExt::read_framed_format::<BigEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_framed_into_be(&mut self, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_into::<BigEndian>(self, buffer)
+    }
+
+    #[inline]
+    fn read_framed_max_into_be(&mut self, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_max_into::<BigEndian>(self, maximum, buffer)
+    }
+
+    #[inline]
+    fn read_framed_format_into_be(&mut self, format: FrameFormat, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_format_into::<BigEndian>(self, format, maximum, buffer)
+    }
+
+    #[inline]
+    fn read_string_be(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_string::<BigEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_string_lossy_be(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_string_lossy::<BigEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_utf16_be(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16::<BigEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf16_nul_be(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_nul::<BigEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf16_bom_be(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_bom::<BigEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf16_nul_bom_be(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_nul_bom::<BigEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf32_be(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32::<BigEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf32_nul_be(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_nul::<BigEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf32_bom_be(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_bom::<BigEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf32_nul_bom_be(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_nul_bom::<BigEndian>(self)
+    }
+
+    #[inline]
+    fn read_f80_be(&mut self) -> Result<(f64, bool), std::io::Error> {
+        BiteReadExpandedExt::read_f80::<BigEndian>(self)
+    }
+
+    #[inline]
+    fn read_slice_f80_be(&mut self, values: &mut [f64]) -> Result<bool, std::io::Error> {
+        BiteReadExpandedExt::read_slice_f80::<BigEndian>(self, values)
+    }
+}
+
+impl<T> BiteReadBeExt for T where T: Read + ?Sized { }
@@ -0,0 +1,112 @@
+use {
+    std,
+    std::io::Write,
+
+    bite::BigEndian,
+    bite::BiteWriteExpandedExt,
+    bite::FrameFormat,
+};
+
+
+
+pub trait BiteWriteBeExt: Write {
+    #[inline] fn write_u8_be           (&mut self, value: u8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u8                        (self, value)        }
+    #[inline] fn write_i8_be           (&mut self, value: i8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i8                        (self, value)        }
+    #[inline] fn write_u16_be          (&mut self, value: u16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u16          ::<BigEndian>(self, value)        }
+    #[inline] fn write_i16_be          (&mut self, value: i16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i16          ::<BigEndian>(self, value)        }
+    #[inline] fn write_u32_be          (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u32          ::<BigEndian>(self, value)        }
+    #[inline] fn write_i32_be          (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i32          ::<BigEndian>(self, value)        }
+    #[inline] fn write_u24_be          (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u24          ::<BigEndian>(self, value)        }
+    #[inline] fn write_i24_be          (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i24          ::<BigEndian>(self, value)        }
+    #[inline] fn write_u64_be          (&mut self, value: u64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u64          ::<BigEndian>(self, value)        }
+    #[inline] fn write_i64_be          (&mut self, value: i64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i64          ::<BigEndian>(self, value)        }
+    #[inline] fn write_u128_be         (&mut self, value: u128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u128         ::<BigEndian>(self, value)        }
+    #[inline] fn write_i128_be         (&mut self, value: i128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i128         ::<BigEndian>(self, value)        }
+    #[inline] fn write_uint_be         (&mut self, value: u64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint         ::<BigEndian>(self, value, bytes) }
+    #[inline] fn write_int_be          (&mut self, value: i64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int          ::<BigEndian>(self, value, bytes) }
+    #[inline] fn write_uint128_be      (&mut self, value: u128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint128      ::<BigEndian>(self, value, bytes) }
+    #[inline] fn write_int128_be       (&mut self, value: i128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int128       ::<BigEndian>(self, value, bytes) }
+    #[inline] fn write_f32_be          (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f32          ::<BigEndian>(self, value)        }
+    #[inline] fn write_f64_be          (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f64          ::<BigEndian>(self, value)        }
+    #[inline] fn write_f16_be          (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f16          ::<BigEndian>(self, value)        }
+    #[inline] fn write_bf16_be         (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_bf16         ::<BigEndian>(self, value)        }
+    #[inline] fn write_f80_be          (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f80          ::<BigEndian>(self, value)        }
+    #[inline] fn write_ibm_f32_be      (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_ibm_f32      ::<BigEndian>(self, value)        }
+    #[inline] fn write_ibm_f64_be      (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_ibm_f64      ::<BigEndian>(self, value)        }
+    #[inline] fn write_vax_f_be        (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_f        ::<BigEndian>(self, value)        }
+    #[inline] fn write_vax_d_be        (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_d        ::<BigEndian>(self, value)        }
+    #[inline] fn write_vax_g_be        (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_g        ::<BigEndian>(self, value)        }
+
+    #[inline] fn write_slice_u16_be    (&mut self, values: &[u16])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u16    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_i16_be    (&mut self, values: &[i16])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i16    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_u32_be    (&mut self, values: &[u32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u32    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_i32_be    (&mut self, values: &[i32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i32    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_u64_be    (&mut self, values: &[u64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u64    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_i64_be    (&mut self, values: &[i64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i64    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_u128_be   (&mut self, values: &[u128])           -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u128   ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_i128_be   (&mut self, values: &[i128])           -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i128   ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_f32_be    (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f32    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_f64_be    (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f64    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_f16_be    (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f16    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_bf16_be   (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_bf16   ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_f80_be    (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f80    ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_ibm_f32_be(&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_ibm_f32::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_ibm_f64_be(&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_ibm_f64::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_vax_f_be  (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_f  ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_vax_d_be  (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_d  ::<BigEndian>(self, values)       }
+    #[inline] fn write_slice_vax_g_be  (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_g  ::<BigEndian>(self, values)       }
+
+    #[inline] fn write_framed_be       (&mut self, value: &[u8])              -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_framed       ::<BigEndian>(self, value)        }
+
+    #[inline]
+    fn write_framed_format_be(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_framed_format::<BigEndian>(self, format, value)
+    }
+
+    #[inline]
+    fn write_framed_vectored_be(&mut self, value: &[u8]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_framed_vectored::<BigEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_frames_be(&mut self, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_frames::<BigEndian>(self, values)
+    }
+
+    #[inline]
+    fn write_string_be(&mut self, format: FrameFormat, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_string::<BigEndian>(self, format, value)
+    }
+
+    #[inline]
+    fn write_utf16_be(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16::<BigEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf16_nul_be(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16_nul::<BigEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf16_bom_be(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16_bom::<BigEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_be(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32::<BigEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_nul_be(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32_nul::<BigEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_bom_be(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32_bom::<BigEndian>(self, value)
+    }
+}
+
+impl<T> BiteWriteBeExt for T where T: Write + ?Sized { }
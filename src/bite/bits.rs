@@ -0,0 +1,204 @@
+use {
+    std,
+    std::io::Read,
+    std::io::Write,
+
+    bite::BiteReadExpandedExt,
+};
+
+
+
+// the order in which bits are packed into each byte.
+//
+// `MsbFirst` fills each byte from its most significant bit downwards, and places the most significant bit of a field
+// first (h.264, mpeg, jpeg). `LsbFirst` fills each byte from its least significant bit upwards, and places the least
+// significant bit of a field first (deflate, gif).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+
+
+// reads fields of 1 to 64 bits from a byte stream.
+//
+// bytes are pulled from the underlying reader one at a time, and only when a bit from them is needed, so the reader
+// can be handed back with `into_inner` to continue reading bytes after a bit-packed section. any bits left over in
+// the current byte are discarded at that point - use `is_aligned` to check that none remain.
+#[derive(Debug)]
+pub struct BitReader<R: Read> {
+    inner:     R,
+    order:     BitOrder,
+    byte:      u8,
+    available: u32,
+}
+
+impl<R: Read> BitReader<R> {
+    #[inline]
+    pub fn new(inner: R, order: BitOrder) -> BitReader<R> {
+        BitReader { inner, order, byte: 0, available: 0 }
+    }
+
+    #[inline] pub fn order     (&self)     -> BitOrder { self.order          }
+    #[inline] pub fn is_aligned(&self)     -> bool     { self.available == 0 }
+    #[inline] pub fn get_ref   (&self)     -> &R       { &self.inner         }
+    #[inline] pub fn get_mut   (&mut self) -> &mut R   { &mut self.inner     }
+    #[inline] pub fn into_inner(self)      -> R        { self.inner          }
+
+    // discards the remaining bits of the current byte, so that the next read starts on a byte boundary.
+    #[inline]
+    pub fn align(&mut self) {
+        self.available = 0;
+    }
+
+    #[inline]
+    pub fn read_bit(&mut self) -> Result<bool, std::io::Error> {
+        Ok(self.read_bits(1)? != 0)
+    }
+
+    // reads a `count` bit unsigned field, where `count` is between 1 and 64.
+    #[inline]
+    pub fn read_bits(&mut self, count: u32) -> Result<u64, std::io::Error> {
+        assert!((1..=64).contains(&count));
+
+        let mut value  = 0u64;
+        let mut filled = 0;
+
+        while filled < count {
+            if self.available == 0 {
+                self.byte      = self.inner.read_u8()?;
+                self.available = 8;
+            }
+
+            let take = std::cmp::min(self.available, count - filled);
+
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = (self.byte >> (self.available - take)) & mask(take);
+                    value = (value << take) | chunk as u64;
+                },
+                BitOrder::LsbFirst => {
+                    let chunk = (self.byte >> (8 - self.available)) & mask(take);
+                    value |= (chunk as u64) << filled;
+                },
+            }
+
+            self.available -= take;
+            filled         += take;
+        }
+
+        Ok(value)
+    }
+
+    // reads a `count` bit two's complement field, where `count` is between 1 and 64.
+    #[inline]
+    pub fn read_signed_bits(&mut self, count: u32) -> Result<i64, std::io::Error> {
+        let value = self.read_bits(count)?;
+        let shift = 64 - count;
+
+        Ok((value << shift) as i64 >> shift)
+    }
+}
+
+
+
+// writes fields of 1 to 64 bits to a byte stream.
+//
+// a byte is written to the underlying writer as soon as it has been filled. a partially filled byte is only written
+// by `align` or `into_inner`, which pad it with zero bits - it is lost if the writer is dropped while unaligned.
+#[derive(Debug)]
+pub struct BitWriter<W: Write> {
+    inner:  W,
+    order:  BitOrder,
+    byte:   u8,
+    filled: u32,
+}
+
+impl<W: Write> BitWriter<W> {
+    #[inline]
+    pub fn new(inner: W, order: BitOrder) -> BitWriter<W> {
+        BitWriter { inner, order, byte: 0, filled: 0 }
+    }
+
+    #[inline] pub fn order     (&self)     -> BitOrder { self.order       }
+    #[inline] pub fn is_aligned(&self)     -> bool     { self.filled == 0 }
+    #[inline] pub fn get_ref   (&self)     -> &W       { &self.inner      }
+    #[inline] pub fn get_mut   (&mut self) -> &mut W   { &mut self.inner  }
+
+    // pads the current byte with zero bits and writes it, so that the next write starts on a byte boundary.
+    #[inline]
+    pub fn align(&mut self) -> Result<(), std::io::Error> {
+        if self.filled != 0 {
+            self.inner.write_all(&[self.byte])?;
+
+            self.byte   = 0;
+            self.filled = 0;
+        }
+
+        Ok(())
+    }
+
+    // aligns the stream to a byte boundary, then returns the underlying writer.
+    #[inline]
+    pub fn into_inner(mut self) -> Result<W, std::io::Error> {
+        self.align()?;
+        Ok(self.inner)
+    }
+
+    #[inline]
+    pub fn write_bit(&mut self, value: bool) -> Result<(), std::io::Error> {
+        self.write_bits(value as u64, 1)
+    }
+
+    // writes `value` as a `count` bit unsigned field, where `count` is between 1 and 64 and `value` fits in it.
+    #[inline]
+    pub fn write_bits(&mut self, value: u64, count: u32) -> Result<(), std::io::Error> {
+        assert!((1..=64).contains(&count) && (count == 64 || value >> count == 0));
+
+        let mut written = 0;
+
+        while written < count {
+            let take = std::cmp::min(8 - self.filled, count - written);
+
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = (value >> (count - written - take)) as u8 & mask(take);
+                    self.byte |= chunk << (8 - self.filled - take);
+                },
+                BitOrder::LsbFirst => {
+                    let chunk = (value >> written) as u8 & mask(take);
+                    self.byte |= chunk << self.filled;
+                },
+            }
+
+            self.filled += take;
+            written     += take;
+
+            if self.filled == 8 {
+                self.align()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // writes `value` as a `count` bit two's complement field, where `count` is between 1 and 64 and `value` fits in it.
+    #[inline]
+    pub fn write_signed_bits(&mut self, value: i64, count: u32) -> Result<(), std::io::Error> {
+        assert!((1..=64).contains(&count));
+
+        let shift = 64 - count;
+
+        assert!((value << shift) >> shift == value);
+        self.write_bits((value as u64) << shift >> shift, count)
+    }
+}
+
+
+
+// a mask of the lowest `bits` bits of a byte.
+#[inline]
+fn mask(bits: u32) -> u8 {
+    ((1u16 << bits) - 1) as u8
+}
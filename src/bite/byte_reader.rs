@@ -0,0 +1,297 @@
+use {
+    core::mem::size_of_val,
+
+    bite::Decimal,
+    bite::Endianness,
+    bite::Error,
+    bite::Fixed,
+    bite::FixedBits,
+    bite::FrameFormat,
+    bite::Leb128,
+    bite::Padding,
+    bite::decimal,
+    bite::error::check_length,
+    bite::error::check_width,
+    bite::string::check_utf8,
+    bite::string::find_nul,
+};
+
+#[cfg(feature = "std")]
+use {
+    std::borrow::Cow,
+};
+
+
+
+// a cursor over a borrowed byte slice, with reads that are generic over the encoding.
+//
+// reading a primitive converts it directly out of the slice, and `read_bytes` and `read_framed_ref` return views into
+// the input instead of copying it, so that memory-mapped or already-buffered data can be parsed without allocating.
+// a read that fails consumes nothing.
+//
+// with the `std` feature, this also implements `std::io::Read`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ByteReader<'a> {
+    stream:   &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    #[inline]
+    pub fn new(stream: &'a [u8]) -> ByteReader<'a> {
+        ByteReader { stream, position: 0 }
+    }
+
+    #[inline] pub fn get_ref  (&self) -> &'a [u8] { self.stream                    }
+    #[inline] pub fn position (&self) -> usize    { self.position                  }
+    #[inline] pub fn remaining(&self) -> &'a [u8] { &self.stream[self.position..]  }
+    #[inline] pub fn len      (&self) -> usize    { self.remaining().len()         }
+    #[inline] pub fn is_empty (&self) -> bool     { self.remaining().is_empty()    }
+
+    // moves the cursor to `position`, which may not be past the end of the slice.
+    #[inline]
+    pub fn set_position(&mut self, position: usize) -> Result<(), Error> {
+        check_length(self.stream, position)?;
+
+        self.position = position;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn skip(&mut self, length: usize) -> Result<(), Error> {
+        self.read_bytes(length)?;
+        Ok(())
+    }
+
+    // consumes the next `length` bytes, returning a view of them that borrows from the input.
+    #[inline]
+    pub fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let data = check_length(self.remaining(), length)?;
+
+        self.position += length;
+        Ok(data)
+    }
+
+
+
+    #[inline] pub fn read_u8                       (&mut self)                      -> Result<  u8, Error> { Ok(self.read_bytes(1)?[0])                                                         }
+    #[inline] pub fn read_i8                       (&mut self)                      -> Result<  i8, Error> { Ok(self.read_bytes(1)?[0] as i8)                                                   }
+    #[inline] pub fn read_u16<E: Endianness>       (&mut self)                      -> Result< u16, Error> { Ok(E::read_u16(self.read_bytes(2)?))                                               }
+    #[inline] pub fn read_i16<E: Endianness>       (&mut self)                      -> Result< i16, Error> { Ok(E::read_i16(self.read_bytes(2)?))                                               }
+    #[inline] pub fn read_u24<E: Endianness>       (&mut self)                      -> Result< u32, Error> { Ok(E::read_u24(self.read_bytes(3)?))                                               }
+    #[inline] pub fn read_i24<E: Endianness>       (&mut self)                      -> Result< i32, Error> { Ok(E::read_i24(self.read_bytes(3)?))                                               }
+    #[inline] pub fn read_u32<E: Endianness>       (&mut self)                      -> Result< u32, Error> { Ok(E::read_u32(self.read_bytes(4)?))                                               }
+    #[inline] pub fn read_i32<E: Endianness>       (&mut self)                      -> Result< i32, Error> { Ok(E::read_i32(self.read_bytes(4)?))                                               }
+    #[inline] pub fn read_u64<E: Endianness>       (&mut self)                      -> Result< u64, Error> { Ok(E::read_u64(self.read_bytes(8)?))                                               }
+    #[inline] pub fn read_i64<E: Endianness>       (&mut self)                      -> Result< i64, Error> { Ok(E::read_i64(self.read_bytes(8)?))                                               }
+    #[inline] pub fn read_u128<E: Endianness>      (&mut self)                      -> Result<u128, Error> { Ok(E::read_u128(self.read_bytes(16)?))                                             }
+    #[inline] pub fn read_i128<E: Endianness>      (&mut self)                      -> Result<i128, Error> { Ok(E::read_i128(self.read_bytes(16)?))                                             }
+    #[inline] pub fn read_uint<E: Endianness>      (&mut self, bytes: usize)        -> Result< u64, Error> { Ok(E::read_uint(self.read_bytes(check_width(bytes, 8)?)?, bytes))                  }
+    #[inline] pub fn read_int<E: Endianness>       (&mut self, bytes: usize)        -> Result< i64, Error> { Ok(E::read_int(self.read_bytes(check_width(bytes, 8)?)?, bytes))                   }
+    #[inline] pub fn read_uint128<E: Endianness>   (&mut self, bytes: usize)        -> Result<u128, Error> { Ok(E::read_uint128(self.read_bytes(check_width(bytes, 16)?)?, bytes))              }
+    #[inline] pub fn read_int128<E: Endianness>    (&mut self, bytes: usize)        -> Result<i128, Error> { Ok(E::read_int128(self.read_bytes(check_width(bytes, 16)?)?, bytes))               }
+    #[inline] pub fn read_f32<E: Endianness>       (&mut self)                      -> Result< f32, Error> { Ok(E::read_f32(self.read_bytes(4)?))                                               }
+    #[inline] pub fn read_f64<E: Endianness>       (&mut self)                      -> Result< f64, Error> { Ok(E::read_f64(self.read_bytes(8)?))                                               }
+    #[inline] pub fn read_f16<E: Endianness>       (&mut self)                      -> Result< f32, Error> { Ok(E::read_f16(self.read_bytes(2)?))                                               }
+    #[inline] pub fn read_bf16<E: Endianness>      (&mut self)                      -> Result< f32, Error> { Ok(E::read_bf16(self.read_bytes(2)?))                                              }
+
+    #[inline] pub fn read_slice_u16<E: Endianness> (&mut self, values: &mut [u16])  -> Result<  (), Error> { E::read_slice_u16(self.read_bytes(size_of_val(values))?, values); Ok(())           }
+    #[inline] pub fn read_slice_i16<E: Endianness> (&mut self, values: &mut [i16])  -> Result<  (), Error> { E::read_slice_i16(self.read_bytes(size_of_val(values))?, values); Ok(())           }
+    #[inline] pub fn read_slice_u32<E: Endianness> (&mut self, values: &mut [u32])  -> Result<  (), Error> { E::read_slice_u32(self.read_bytes(size_of_val(values))?, values); Ok(())           }
+    #[inline] pub fn read_slice_i32<E: Endianness> (&mut self, values: &mut [i32])  -> Result<  (), Error> { E::read_slice_i32(self.read_bytes(size_of_val(values))?, values); Ok(())           }
+    #[inline] pub fn read_slice_u64<E: Endianness> (&mut self, values: &mut [u64])  -> Result<  (), Error> { E::read_slice_u64(self.read_bytes(size_of_val(values))?, values); Ok(())           }
+    #[inline] pub fn read_slice_i64<E: Endianness> (&mut self, values: &mut [i64])  -> Result<  (), Error> { E::read_slice_i64(self.read_bytes(size_of_val(values))?, values); Ok(())           }
+    #[inline] pub fn read_slice_u128<E: Endianness>(&mut self, values: &mut [u128]) -> Result<  (), Error> { E::read_slice_u128(self.read_bytes(size_of_val(values))?, values); Ok(())          }
+    #[inline] pub fn read_slice_i128<E: Endianness>(&mut self, values: &mut [i128]) -> Result<  (), Error> { E::read_slice_i128(self.read_bytes(size_of_val(values))?, values); Ok(())          }
+    #[inline] pub fn read_slice_f32<E: Endianness> (&mut self, values: &mut [f32])  -> Result<  (), Error> { E::read_slice_f32_unchecked(self.read_bytes(size_of_val(values))?, values); Ok(()) }
+    #[inline] pub fn read_slice_f64<E: Endianness> (&mut self, values: &mut [f64])  -> Result<  (), Error> { E::read_slice_f64_unchecked(self.read_bytes(size_of_val(values))?, values); Ok(()) }
+    #[inline] pub fn read_slice_f16<E: Endianness> (&mut self, values: &mut [f32])  -> Result<  (), Error> { E::read_slice_f16(self.read_bytes(2 * values.len())?, values); Ok(())              }
+    #[inline] pub fn read_slice_bf16<E: Endianness>(&mut self, values: &mut [f32])  -> Result<  (), Error> { E::read_slice_bf16(self.read_bytes(2 * values.len())?, values); Ok(())             }
+
+    #[inline] pub fn read_ibm_f32<E: Endianness>       (&mut self)                     -> Result<f32, Error> { Ok(E::read_ibm_f32(self.read_bytes(4)?))                                  }
+    #[inline] pub fn read_ibm_f64<E: Endianness>       (&mut self)                     -> Result<f64, Error> { Ok(E::read_ibm_f64(self.read_bytes(8)?))                                  }
+    #[inline] pub fn read_vax_f<E: Endianness>         (&mut self)                     -> Result<f32, Error> { Ok(E::read_vax_f(self.read_bytes(4)?))                                    }
+    #[inline] pub fn read_vax_d<E: Endianness>         (&mut self)                     -> Result<f64, Error> { Ok(E::read_vax_d(self.read_bytes(8)?))                                    }
+    #[inline] pub fn read_vax_g<E: Endianness>         (&mut self)                     -> Result<f64, Error> { Ok(E::read_vax_g(self.read_bytes(8)?))                                    }
+
+    #[inline] pub fn read_slice_ibm_f32<E: Endianness> (&mut self, values: &mut [f32]) -> Result< (), Error> { E::read_slice_ibm_f32(self.read_bytes(4 * values.len())?, values); Ok(()) }
+    #[inline] pub fn read_slice_ibm_f64<E: Endianness> (&mut self, values: &mut [f64]) -> Result< (), Error> { E::read_slice_ibm_f64(self.read_bytes(8 * values.len())?, values); Ok(()) }
+    #[inline] pub fn read_slice_vax_f<E: Endianness>   (&mut self, values: &mut [f32]) -> Result< (), Error> { E::read_slice_vax_f(self.read_bytes(4 * values.len())?, values); Ok(())   }
+    #[inline] pub fn read_slice_vax_d<E: Endianness>   (&mut self, values: &mut [f64]) -> Result< (), Error> { E::read_slice_vax_d(self.read_bytes(8 * values.len())?, values); Ok(())   }
+    #[inline] pub fn read_slice_vax_g<E: Endianness>   (&mut self, values: &mut [f64]) -> Result< (), Error> { E::read_slice_vax_g(self.read_bytes(8 * values.len())?, values); Ok(())   }
+
+    #[inline] pub fn read_leb128_u16               (&mut self)                      -> Result< u16, Error> { self.read_leb128(Leb128::read_u16)                                                 }
+    #[inline] pub fn read_leb128_i16               (&mut self)                      -> Result< i16, Error> { self.read_leb128(Leb128::read_i16)                                                 }
+    #[inline] pub fn read_leb128_u32               (&mut self)                      -> Result< u32, Error> { self.read_leb128(Leb128::read_u32)                                                 }
+    #[inline] pub fn read_leb128_i32               (&mut self)                      -> Result< i32, Error> { self.read_leb128(Leb128::read_i32)                                                 }
+    #[inline] pub fn read_leb128_u64               (&mut self)                      -> Result< u64, Error> { self.read_leb128(Leb128::read_u64)                                                 }
+    #[inline] pub fn read_leb128_i64               (&mut self)                      -> Result< i64, Error> { self.read_leb128(Leb128::read_i64)                                                 }
+    #[inline] pub fn read_leb128_u128              (&mut self)                      -> Result<u128, Error> { self.read_leb128(Leb128::read_u128)                                                }
+    #[inline] pub fn read_leb128_i128              (&mut self)                      -> Result<i128, Error> { self.read_leb128(Leb128::read_i128)                                                }
+
+    // reads an x87 80-bit extended float, returning it rounded to an `f64` and whether that lost any precision.
+    #[inline]
+    pub fn read_f80<E: Endianness>(&mut self) -> Result<(f64, bool), Error> {
+        Ok(E::read_f80(self.read_bytes(10)?))
+    }
+
+    #[inline]
+    pub fn read_slice_f80<E: Endianness>(&mut self, values: &mut [f64]) -> Result<bool, Error> {
+        Ok(E::read_slice_f80(self.read_bytes(10 * values.len())?, values))
+    }
+
+    // reads a q format number from a `bytes` wide integer - see `Fixed`.
+    #[inline]
+    pub fn read_fixed_point<E, I, const FRAC: u32>(&mut self, bytes: usize) -> Result<Fixed<I, FRAC>, Error>
+        where E: Endianness, I: FixedBits
+    {
+        Ok(Fixed::read::<E>(self.read_bytes(check_width(bytes, I::BYTES)?)?, bytes))
+    }
+
+    #[inline]
+    pub fn read_slice_fixed_point<E, I, const FRAC: u32>(&mut self, values: &mut [Fixed<I, FRAC>], bytes: usize) -> Result<(), Error>
+        where E: Endianness, I: FixedBits
+    {
+        let length = check_width(bytes, I::BYTES)? * values.len();
+
+        Fixed::read_slice::<E>(self.read_bytes(length)?, values, bytes);
+        Ok(())
+    }
+
+    // reads a packed bcd field of `bytes` bytes, at most 16, whose last `scale` digits follow the decimal point - see
+    // `BiteReadExpandedExt::read_packed_bcd`. nothing is consumed if the field is invalid.
+    #[inline]
+    pub fn read_packed_bcd(&mut self, bytes: usize, scale: u32) -> Result<Decimal, Error> {
+        let value = self.read_decimal(decimal::check_packed(bytes, scale)?, decimal::decode_packed)?;
+        Ok(Decimal::new(value, scale))
+    }
+
+    // as `read_packed_bcd`, for an ebcdic zoned decimal field of at most 31 bytes.
+    #[inline]
+    pub fn read_zoned_decimal(&mut self, bytes: usize, scale: u32) -> Result<Decimal, Error> {
+        let value = self.read_decimal(decimal::check_zoned(bytes, scale)?, decimal::decode_zoned)?;
+        Ok(Decimal::new(value, scale))
+    }
+
+
+
+    #[inline]
+    pub fn read_framed_ref<E: Endianness>(&mut self) -> Result<&'a [u8], Error> {
+        self.read_framed_max_ref::<E>(usize::MAX)
+    }
+
+    #[inline]
+    pub fn read_framed_max_ref<E: Endianness>(&mut self, maximum: usize) -> Result<&'a [u8], Error> {
+        self.read_framed_format_ref::<E>(FrameFormat::U32, maximum)
+    }
+
+    // reads a frame whose length prefix is laid out as `format` describes. nothing is consumed if the frame is
+    // incomplete or invalid.
+    #[inline]
+    pub fn read_framed_format_ref<E: Endianness>(&mut self, format: FrameFormat, maximum: usize) -> Result<&'a [u8], Error> {
+        let mut peek = *self;
+
+        let (length, prefix) = format.read_prefix::<E>(peek.remaining(), maximum)?;
+        peek.position += prefix;
+
+        let data = peek.read_bytes(length)?;
+
+        *self = peek;
+        Ok(data)
+    }
+
+
+
+    // reads a utf-8 string with a length prefix laid out as `format` describes, borrowed from the slice.
+    #[inline]
+    pub fn read_str_ref<E: Endianness>(&mut self, format: FrameFormat, maximum: usize) -> Result<&'a str, Error> {
+        self.read_string_data(|x| x.read_framed_format_ref::<E>(format, maximum), check_utf8)
+    }
+
+    // reads a nul-terminated string of at most `maximum` bytes, not counting the nul, which is consumed.
+    #[inline]
+    pub fn read_cstr_ref(&mut self, maximum: usize) -> Result<&'a str, Error> {
+        self.read_string_data(|x| x.read_cstr_data(maximum), check_utf8)
+    }
+
+    // reads a string from a field of exactly `width` bytes, without its padding.
+    #[inline]
+    pub fn read_fixed_str_ref(&mut self, width: usize, padding: Padding) -> Result<&'a str, Error> {
+        self.read_string_data(|x| Ok(padding.trim(x.read_bytes(width)?)), check_utf8)
+    }
+
+    // as `read_str_ref`, but invalid utf-8 is replaced with U+FFFD - which allocates, only when it's needed.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn read_str_lossy<E: Endianness>(&mut self, format: FrameFormat, maximum: usize) -> Result<Cow<'a, str>, Error> {
+        self.read_string_data(|x| x.read_framed_format_ref::<E>(format, maximum), |x| Ok(String::from_utf8_lossy(x)))
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn read_cstr_lossy(&mut self, maximum: usize) -> Result<Cow<'a, str>, Error> {
+        self.read_string_data(|x| x.read_cstr_data(maximum), |x| Ok(String::from_utf8_lossy(x)))
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn read_fixed_str_lossy(&mut self, width: usize, padding: Padding) -> Result<Cow<'a, str>, Error> {
+        self.read_string_data(|x| Ok(padding.trim(x.read_bytes(width)?)), |x| Ok(String::from_utf8_lossy(x)))
+    }
+
+    // reads the bytes of a string with `read` and converts them with `convert`, consuming nothing if either fails.
+    #[inline]
+    fn read_string_data<T, R, C>(&mut self, read: R, convert: C) -> Result<T, Error>
+        where R: FnOnce(&mut ByteReader<'a>) -> Result<&'a [u8], Error>, C: FnOnce(&'a [u8]) -> Result<T, Error>
+    {
+        let mut peek = *self;
+        let value    = convert(read(&mut peek)?)?;
+
+        *self = peek;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_cstr_data(&mut self, maximum: usize) -> Result<&'a [u8], Error> {
+        let length = find_nul(self.remaining(), maximum)?;
+        let data   = &self.remaining()[..length];
+
+        self.position += length + 1;
+        Ok(data)
+    }
+
+    #[inline]
+    fn read_leb128<T, F>(&mut self, read: F) -> Result<T, Error> where F: FnOnce(&[u8]) -> Result<(T, usize), Error> {
+        let (value, length) = read(self.remaining())?;
+
+        self.position += length;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_decimal(&mut self, length: usize, decode: fn(&[u8]) -> Result<i128, Error>) -> Result<i128, Error> {
+        let value = decode(check_length(self.remaining(), length)?)?;
+
+        self.position += length;
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> ::std::io::Read for ByteReader<'a> {
+    #[inline]
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize, ::std::io::Error> {
+        let length = ::std::cmp::min(buffer.len(), self.len());
+
+        buffer[..length].copy_from_slice(&self.remaining()[..length]);
+        self.position += length;
+
+        Ok(length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> ::std::io::BufRead for ByteReader<'a> {
+    #[inline] fn fill_buf(&mut self)              -> Result<&[u8], ::std::io::Error> { Ok(self.remaining()) }
+    #[inline] fn consume (&mut self, amount: usize)                                  { self.position = ::std::cmp::min(self.position + amount, self.stream.len()); }
+}
@@ -0,0 +1,286 @@
+use {
+    std,
+    std::io::Write,
+
+    bite::Decimal,
+    bite::Endianness,
+    bite::Error,
+    bite::Fixed,
+    bite::FixedBits,
+    bite::FrameFormat,
+    bite::Padding,
+    bite::decimal,
+    bite::decimal::MAXIMUM_PACKED_LENGTH,
+    bite::decimal::MAXIMUM_ZONED_LENGTH,
+    bite::frame::MAXIMUM_PREFIX_LENGTH,
+    bite::leb128,
+    bite::string::check_nul,
+    bite::string::check_padded,
+};
+
+
+
+// a growable buffer that primitives are written into, with writes that are generic over the encoding.
+//
+// values are encoded directly into the underlying `Vec<u8>` rather than being passed through `std::io::Write` one at a
+// time. fields whose values aren't known until later - lengths, offsets, checksums - can be reserved with one of the
+// `reserve_*` methods, and filled in with `patch` once the rest of the data has been written.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ByteWriter {
+    buffer: Vec<u8>,
+}
+
+// a field reserved in a `ByteWriter`, to be filled in with `ByteWriter::patch`.
+//
+// the slot remembers the encoding that it was reserved with, and may be patched any number of times.
+#[derive(Clone, Copy, Debug)]
+pub struct Slot<T> {
+    position: usize,
+    write:    fn(&mut [u8], T),
+}
+
+impl<T> Slot<T> {
+    // the offset of the field from the start of the buffer.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl ByteWriter {
+    #[inline] pub fn new          ()                -> ByteWriter { ByteWriter { buffer: Vec::new() }                  }
+    #[inline] pub fn with_capacity(capacity: usize) -> ByteWriter { ByteWriter { buffer: Vec::with_capacity(capacity) } }
+
+    #[inline] pub fn position     (&self)           -> usize      { self.buffer.len()                               }
+    #[inline] pub fn is_empty     (&self)           -> bool       { self.buffer.is_empty()                          }
+    #[inline] pub fn as_slice     (&self)           -> &[u8]      { &self.buffer                                    }
+    #[inline] pub fn as_mut_slice (&mut self)       -> &mut [u8]  { &mut self.buffer                                }
+    #[inline] pub fn into_inner   (self)            -> Vec<u8>    { self.buffer                                     }
+
+    // grows the buffer by `length` bytes, and encodes a field straight into them.
+    #[inline]
+    fn append<F>(&mut self, length: usize, write: F) where F: FnOnce(&mut [u8]) {
+        let position = self.buffer.len();
+
+        self.buffer.resize(position + length, 0);
+        write(&mut self.buffer[position..]);
+    }
+
+    // as `append`, for values that are encoded as `width` bytes apiece - all of them at once.
+    #[inline]
+    fn append_slice<P>(&mut self, values: &[P], width: usize, write: fn(&mut [u8], &[P])) {
+        self.append(width * values.len(), |x| write(x, values));
+    }
+
+    #[inline]
+    pub fn write_bytes(&mut self, value: &[u8]) {
+        self.buffer.extend_from_slice(value);
+    }
+
+
+
+    #[inline] pub fn write_u8                       (&mut self, value: u8)                                      { self.buffer.push(value);                                           }
+    #[inline] pub fn write_i8                       (&mut self, value: i8)                                      { self.buffer.push(value as u8);                                     }
+    #[inline] pub fn write_u16<E: Endianness>       (&mut self, value: u16)                                     { self.append(2, |x| E::write_u16(x, value));                        }
+    #[inline] pub fn write_i16<E: Endianness>       (&mut self, value: i16)                                     { self.append(2, |x| E::write_i16(x, value));                        }
+    #[inline] pub fn write_u24<E: Endianness>       (&mut self, value: u32)                                     { self.append(3, |x| E::write_u24(x, value));                        }
+    #[inline] pub fn write_i24<E: Endianness>       (&mut self, value: i32)                                     { self.append(3, |x| E::write_i24(x, value));                        }
+    #[inline] pub fn write_u32<E: Endianness>       (&mut self, value: u32)                                     { self.append(4, |x| E::write_u32(x, value));                        }
+    #[inline] pub fn write_i32<E: Endianness>       (&mut self, value: i32)                                     { self.append(4, |x| E::write_i32(x, value));                        }
+    #[inline] pub fn write_u64<E: Endianness>       (&mut self, value: u64)                                     { self.append(8, |x| E::write_u64(x, value));                        }
+    #[inline] pub fn write_i64<E: Endianness>       (&mut self, value: i64)                                     { self.append(8, |x| E::write_i64(x, value));                        }
+    #[inline] pub fn write_u128<E: Endianness>      (&mut self, value: u128)                                    { self.append(16, |x| E::write_u128(x, value));                      }
+    #[inline] pub fn write_i128<E: Endianness>      (&mut self, value: i128)                                    { self.append(16, |x| E::write_i128(x, value));                      }
+    #[inline] pub fn write_f32<E: Endianness>       (&mut self, value: f32)                                     { self.append(4, |x| E::write_f32(x, value));                        }
+    #[inline] pub fn write_f64<E: Endianness>       (&mut self, value: f64)                                     { self.append(8, |x| E::write_f64(x, value));                        }
+    #[inline] pub fn write_f16<E: Endianness>       (&mut self, value: f32)                                     { self.append(2, |x| E::write_f16(x, value));                        }
+    #[inline] pub fn write_bf16<E: Endianness>      (&mut self, value: f32)                                     { self.append(2, |x| E::write_bf16(x, value));                       }
+    #[inline] pub fn write_f80<E: Endianness>       (&mut self, value: f64)                                     { self.append(10, |x| E::write_f80(x, value));                       }
+    #[inline] pub fn write_uint<E: Endianness>      (&mut self, value: u64, bytes: usize)  -> Result<(), Error> { self.write_width(bytes, |x| E::try_write_uint(x, value, bytes))    }
+    #[inline] pub fn write_int<E: Endianness>       (&mut self, value: i64, bytes: usize)  -> Result<(), Error> { self.write_width(bytes, |x| E::try_write_int(x, value, bytes))     }
+    #[inline] pub fn write_uint128<E: Endianness>   (&mut self, value: u128, bytes: usize) -> Result<(), Error> { self.write_width(bytes, |x| E::try_write_uint128(x, value, bytes)) }
+    #[inline] pub fn write_int128<E: Endianness>    (&mut self, value: i128, bytes: usize) -> Result<(), Error> { self.write_width(bytes, |x| E::try_write_int128(x, value, bytes))  }
+
+    #[inline] pub fn write_slice_u16<E: Endianness> (&mut self, values: &[u16])                                 { self.append_slice(values, 2, E::write_slice_u16);   }
+    #[inline] pub fn write_slice_i16<E: Endianness> (&mut self, values: &[i16])                                 { self.append_slice(values, 2, E::write_slice_i16);   }
+    #[inline] pub fn write_slice_u32<E: Endianness> (&mut self, values: &[u32])                                 { self.append_slice(values, 4, E::write_slice_u32);   }
+    #[inline] pub fn write_slice_i32<E: Endianness> (&mut self, values: &[i32])                                 { self.append_slice(values, 4, E::write_slice_i32);   }
+    #[inline] pub fn write_slice_u64<E: Endianness> (&mut self, values: &[u64])                                 { self.append_slice(values, 8, E::write_slice_u64);   }
+    #[inline] pub fn write_slice_i64<E: Endianness> (&mut self, values: &[i64])                                 { self.append_slice(values, 8, E::write_slice_i64);   }
+    #[inline] pub fn write_slice_u128<E: Endianness>(&mut self, values: &[u128])                                { self.append_slice(values, 16, E::write_slice_u128); }
+    #[inline] pub fn write_slice_i128<E: Endianness>(&mut self, values: &[i128])                                { self.append_slice(values, 16, E::write_slice_i128); }
+    #[inline] pub fn write_slice_f32<E: Endianness> (&mut self, values: &[f32])                                 { self.append_slice(values, 4, E::write_slice_f32);   }
+    #[inline] pub fn write_slice_f64<E: Endianness> (&mut self, values: &[f64])                                 { self.append_slice(values, 8, E::write_slice_f64);   }
+    #[inline] pub fn write_slice_f16<E: Endianness> (&mut self, values: &[f32])                                 { self.append_slice(values, 2, E::write_slice_f16);   }
+    #[inline] pub fn write_slice_bf16<E: Endianness>(&mut self, values: &[f32])                                 { self.append_slice(values, 2, E::write_slice_bf16);  }
+    #[inline] pub fn write_slice_f80<E: Endianness> (&mut self, values: &[f64])                                 { self.append_slice(values, 10, E::write_slice_f80);  }
+
+    #[inline] pub fn write_ibm_f32<E: Endianness>       (&mut self, value: f32)     { self.append(4, |x| E::write_ibm_f32(x, value)); }
+    #[inline] pub fn write_ibm_f64<E: Endianness>       (&mut self, value: f64)     { self.append(8, |x| E::write_ibm_f64(x, value)); }
+    #[inline] pub fn write_vax_f<E: Endianness>         (&mut self, value: f32)     { self.append(4, |x| E::write_vax_f(x, value));   }
+    #[inline] pub fn write_vax_d<E: Endianness>         (&mut self, value: f64)     { self.append(8, |x| E::write_vax_d(x, value));   }
+    #[inline] pub fn write_vax_g<E: Endianness>         (&mut self, value: f64)     { self.append(8, |x| E::write_vax_g(x, value));   }
+
+    #[inline] pub fn write_slice_ibm_f32<E: Endianness> (&mut self, values: &[f32]) { self.append_slice(values, 4, E::write_slice_ibm_f32); }
+    #[inline] pub fn write_slice_ibm_f64<E: Endianness> (&mut self, values: &[f64]) { self.append_slice(values, 8, E::write_slice_ibm_f64); }
+    #[inline] pub fn write_slice_vax_f<E: Endianness>   (&mut self, values: &[f32]) { self.append_slice(values, 4, E::write_slice_vax_f);   }
+    #[inline] pub fn write_slice_vax_d<E: Endianness>   (&mut self, values: &[f64]) { self.append_slice(values, 8, E::write_slice_vax_d);   }
+    #[inline] pub fn write_slice_vax_g<E: Endianness>   (&mut self, values: &[f64]) { self.append_slice(values, 8, E::write_slice_vax_g);   }
+
+    #[inline] pub fn write_leb128_u16               (&mut self, value: u16)                                     { self.write_leb128(leb128::encode_unsigned(value as u128)); }
+    #[inline] pub fn write_leb128_i16               (&mut self, value: i16)                                     { self.write_leb128(leb128::encode_signed(value as i128));   }
+    #[inline] pub fn write_leb128_u32               (&mut self, value: u32)                                     { self.write_leb128(leb128::encode_unsigned(value as u128)); }
+    #[inline] pub fn write_leb128_i32               (&mut self, value: i32)                                     { self.write_leb128(leb128::encode_signed(value as i128));   }
+    #[inline] pub fn write_leb128_u64               (&mut self, value: u64)                                     { self.write_leb128(leb128::encode_unsigned(value as u128)); }
+    #[inline] pub fn write_leb128_i64               (&mut self, value: i64)                                     { self.write_leb128(leb128::encode_signed(value as i128));   }
+    #[inline] pub fn write_leb128_u128              (&mut self, value: u128)                                    { self.write_leb128(leb128::encode_unsigned(value));         }
+    #[inline] pub fn write_leb128_i128              (&mut self, value: i128)                                    { self.write_leb128(leb128::encode_signed(value));           }
+
+    // writes a q format number as a `bytes` wide integer - see `Fixed`.
+    #[inline]
+    pub fn write_fixed_point<E, I, const FRAC: u32>(&mut self, value: Fixed<I, FRAC>, bytes: usize) -> Result<(), Error>
+        where E: Endianness, I: FixedBits
+    {
+        self.write_width(bytes, |x| value.try_write::<E>(x, bytes))
+    }
+
+    // nothing is written if any of the values don't fit.
+    #[inline]
+    pub fn write_slice_fixed_point<E, I, const FRAC: u32>(&mut self, values: &[Fixed<I, FRAC>], bytes: usize) -> Result<(), Error>
+        where E: Endianness, I: FixedBits
+    {
+        let start = self.buffer.len();
+
+        for &value in values {
+            if let Err(error) = self.write_fixed_point::<E, I, FRAC>(value, bytes) {
+                self.buffer.truncate(start);
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    // writes the unscaled digits of `value` as a packed bcd field of `bytes` bytes, at most 16, with a `c` or `d` sign.
+    #[inline]
+    pub fn write_packed_bcd(&mut self, value: Decimal, bytes: usize) -> Result<(), Error> {
+        let mut data = [0; MAXIMUM_PACKED_LENGTH];
+        decimal::encode_packed(&mut data[..decimal::check_packed(bytes, value.scale)?], value.unscaled)?;
+
+        self.write_bytes(&data[..bytes]);
+        Ok(())
+    }
+
+    // as `write_packed_bcd`, for an ebcdic zoned decimal field of at most 31 bytes.
+    #[inline]
+    pub fn write_zoned_decimal(&mut self, value: Decimal, bytes: usize) -> Result<(), Error> {
+        let mut data = [0; MAXIMUM_ZONED_LENGTH];
+        decimal::encode_zoned(&mut data[..decimal::check_zoned(bytes, value.scale)?], value.unscaled)?;
+
+        self.write_bytes(&data[..bytes]);
+        Ok(())
+    }
+
+
+
+    #[inline]
+    pub fn write_framed<E: Endianness>(&mut self, value: &[u8]) -> Result<(), Error> {
+        self.write_framed_format::<E>(FrameFormat::U32, value)
+    }
+
+    // writes a frame whose length prefix is laid out as `format` describes.
+    #[inline]
+    pub fn write_framed_format<E: Endianness>(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), Error> {
+        let mut prefix = [0; MAXIMUM_PREFIX_LENGTH];
+        let length     = format.write_prefix::<E>(&mut prefix, value.len())?;
+
+        self.write_bytes(&prefix[..length]);
+        self.write_bytes(value);
+        Ok(())
+    }
+
+
+
+    // writes a utf-8 string with a length prefix laid out as `format` describes.
+    #[inline]
+    pub fn write_string<E: Endianness>(&mut self, format: FrameFormat, value: &str) -> Result<(), Error> {
+        self.write_framed_format::<E>(format, value.as_bytes())
+    }
+
+    // writes a string followed by a nul. a string that contains a nul of its own is rejected.
+    #[inline]
+    pub fn write_cstring(&mut self, value: &str) -> Result<(), Error> {
+        self.write_bytes(check_nul(value.as_bytes())?);
+        self.write_u8(0);
+        Ok(())
+    }
+
+    // writes a string into a field of exactly `width` bytes, filling the rest with `padding`.
+    #[inline]
+    pub fn write_fixed_string(&mut self, value: &str, width: usize, padding: Padding) -> Result<(), Error> {
+        let value = check_padded(value.as_bytes(), width, padding)?;
+        let start = self.buffer.len();
+
+        self.buffer.extend_from_slice(value);
+        self.buffer.resize(start + width, padding.byte());
+        Ok(())
+    }
+
+    // encodes a variable width value into a scratch buffer first, so that nothing is appended if it doesn't fit.
+    #[inline]
+    fn write_width<F>(&mut self, bytes: usize, write: F) -> Result<(), Error> where F: FnOnce(&mut [u8]) -> Result<(), Error> {
+        let mut data = [0; 16];
+
+        write(&mut data)?;
+        self.write_bytes(&data[..bytes]);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_leb128(&mut self, (data, length): ([u8; 19], usize)) {
+        self.write_bytes(&data[..length]);
+    }
+
+
+
+    // appends a zeroed field to be filled in later with `patch`.
+    #[inline] pub fn reserve_u8                (&mut self) -> Slot<u8>  { self.reserve(1, |x, v| x[0] = v)       }
+    #[inline] pub fn reserve_i8                (&mut self) -> Slot<i8>  { self.reserve(1, |x, v| x[0] = v as u8) }
+    #[inline] pub fn reserve_u16<E: Endianness>(&mut self) -> Slot<u16> { self.reserve(2, E::write_u16)          }
+    #[inline] pub fn reserve_i16<E: Endianness>(&mut self) -> Slot<i16> { self.reserve(2, E::write_i16)          }
+    #[inline] pub fn reserve_u24<E: Endianness>(&mut self) -> Slot<u32> { self.reserve(3, E::write_u24)          }
+    #[inline] pub fn reserve_i24<E: Endianness>(&mut self) -> Slot<i32> { self.reserve(3, E::write_i24)          }
+    #[inline] pub fn reserve_u32<E: Endianness>(&mut self) -> Slot<u32> { self.reserve(4, E::write_u32)          }
+    #[inline] pub fn reserve_i32<E: Endianness>(&mut self) -> Slot<i32> { self.reserve(4, E::write_i32)          }
+    #[inline] pub fn reserve_u64<E: Endianness>(&mut self) -> Slot<u64> { self.reserve(8, E::write_u64)          }
+    #[inline] pub fn reserve_i64<E: Endianness>(&mut self) -> Slot<i64> { self.reserve(8, E::write_i64)          }
+
+    #[inline]
+    fn reserve<T>(&mut self, length: usize, write: fn(&mut [u8], T)) -> Slot<T> {
+        let position = self.position();
+
+        self.buffer.resize(position + length, 0);
+        Slot { position, write }
+    }
+
+    // fills in a field that was reserved by this writer. panics if `slot` came from a different writer whose field
+    // would lie past the end of this buffer.
+    #[inline]
+    pub fn patch<T>(&mut self, slot: Slot<T>, value: T) {
+        (slot.write)(&mut self.buffer[slot.position..], value);
+    }
+}
+
+impl From<Vec<u8>> for ByteWriter {
+    // continues writing at the end of `buffer`.
+    #[inline]
+    fn from(buffer: Vec<u8>) -> ByteWriter {
+        ByteWriter { buffer }
+    }
+}
+
+impl Write for ByteWriter {
+    #[inline] fn write    (&mut self, buffer: &[u8]) -> Result<usize, std::io::Error> { self.write_bytes(buffer); Ok(buffer.len()) }
+    #[inline] fn write_all(&mut self, buffer: &[u8]) -> Result<(), std::io::Error>    { self.write_bytes(buffer); Ok(())           }
+    #[inline] fn flush    (&mut self)                -> Result<(), std::io::Error>    { Ok(())                                     }
+}
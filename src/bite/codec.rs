@@ -0,0 +1,116 @@
+use {
+    std,
+    std::marker::PhantomData,
+
+    bytes::Buf,
+    bytes::BufMut,
+    bytes::BytesMut,
+
+    tokio_util::codec::Decoder,
+    tokio_util::codec::Encoder,
+
+    bite::Endianness,
+    bite::Error,
+    bite::FrameFormat,
+    bite::LittleEndian,
+    bite::frame::MAXIMUM_PREFIX_LENGTH,
+};
+
+
+
+// a tokio-util codec for frames in the format of `read_framed` and `write_framed` - a u32 length in `E`, followed by
+// that many bytes. `with_format` selects another `FrameFormat`, as `read_framed_format` does.
+//
+// decoded frames are split off the front of the read buffer, so they share its allocation rather than being copied.
+// frames longer than the maximum are rejected with `InvalidData` when decoding, and with `InvalidInput` when encoding.
+//
+// the encoding of the length can't be inferred, so give it with the type - `let codec: BiteFrameCodec<BigEndian>`.
+#[derive(Debug)]
+pub struct BiteFrameCodec<E: Endianness = LittleEndian> {
+    format:  FrameFormat,
+    maximum: usize,
+    length:  Option<usize>,
+    endian:  PhantomData<fn() -> E>,
+}
+
+impl<E: Endianness> BiteFrameCodec<E> {
+    #[inline] pub fn new         ()                                    -> BiteFrameCodec<E> { BiteFrameCodec::with_maximum(usize::MAX)                                  }
+    #[inline] pub fn with_maximum(maximum: usize)                      -> BiteFrameCodec<E> { BiteFrameCodec::with_format(FrameFormat::U32, maximum)                    }
+    #[inline] pub fn with_format (format: FrameFormat, maximum: usize) -> BiteFrameCodec<E> { BiteFrameCodec { format, maximum, length: None, endian: PhantomData } }
+
+    #[inline] pub fn format      (&self)                               -> FrameFormat       { self.format                                                           }
+    #[inline] pub fn maximum     (&self)                               -> usize             { self.maximum                                                          }
+    #[inline] pub fn set_maximum (&mut self, maximum: usize)                                { self.maximum = maximum                                                }
+}
+
+impl<E: Endianness> Clone for BiteFrameCodec<E> {
+    #[inline]
+    fn clone(&self) -> BiteFrameCodec<E> {
+        BiteFrameCodec { format: self.format, maximum: self.maximum, length: self.length, endian: PhantomData }
+    }
+}
+
+impl<E: Endianness> Default for BiteFrameCodec<E> {
+    #[inline]
+    fn default() -> BiteFrameCodec<E> {
+        BiteFrameCodec::new()
+    }
+}
+
+impl<E: Endianness> Decoder for BiteFrameCodec<E> {
+    type Item  = BytesMut;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, source: &mut BytesMut) -> Result<Option<BytesMut>, std::io::Error> {
+        // the length is kept once it has been read, so that a partial frame isn't parsed again on every call.
+        let length = match self.length {
+            Some(x) => x,
+            None    => {
+                let (length, prefix) = match self.format.read_prefix::<E>(source, self.maximum) {
+                    Ok(x)                          => x,
+                    Err(Error::ShortBuffer { .. }) => return Ok(None),
+                    Err(x)                         => return Err(x.into()),
+                };
+
+                source.advance(prefix);
+                self.length = Some(length);
+                length
+            },
+        };
+
+        if source.len() < length {
+            return Ok(None);
+        }
+
+        self.length = None;
+        Ok(Some(source.split_to(length)))
+    }
+
+    fn decode_eof(&mut self, source: &mut BytesMut) -> Result<Option<BytesMut>, std::io::Error> {
+        match self.decode(source)? {
+            Some(x)                                            => Ok(Some(x)),
+            None if source.is_empty() && self.length.is_none() => Ok(None),
+            None                                               => Err(std::io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+impl<E: Endianness, T: AsRef<[u8]>> Encoder<T> for BiteFrameCodec<E> {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, value: T, destination: &mut BytesMut) -> Result<(), std::io::Error> {
+        let value = value.as_ref();
+
+        if value.len() > self.maximum {
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+
+        let mut prefix = [0; MAXIMUM_PREFIX_LENGTH];
+        let length     = self.format.write_prefix::<E>(&mut prefix, value.len())?;
+
+        destination.reserve(length + value.len());
+        destination.put_slice(&prefix[..length]);
+        destination.put_slice(value);
+        Ok(())
+    }
+}
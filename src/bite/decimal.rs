@@ -0,0 +1,165 @@
+use {
+    bite::Error,
+    bite::error::check_width,
+};
+
+
+
+// packed bcd and zoned decimal, as used by mainframe records (cobol's `comp-3` and `display` usages) and some exchange
+// feeds. both hold a signed decimal integer, most significant digit first, so neither depends on the byte order - the
+// stream methods are on `BiteReadExpandedExt` and `BiteWriteExpandedExt`, not the fixed order convenience traits.
+//
+// packed bcd holds two digits per byte, and the low nibble of its last byte is the sign - for `bytes` bytes, that's
+// `2 * bytes - 1` digits. zoned decimal holds a digit per byte in the low nibble, under an ebcdic `f` zone, with the
+// sign in the zone of its last byte. `a`, `c`, `e` and `f` signs are positive and `b` and `d` signs are negative -
+// writes use `c` and `d`.
+//
+// fields are limited to the 31 digits that fit in the 16 bytes of a packed field, and always fit in an `i128`. the
+// position of the decimal point isn't recorded in either form, and comes from the record layout instead - so reads
+// take the scale and return a `Decimal` that carries it, and writes take that `Decimal` back.
+
+
+
+// a decimal number, `unscaled / 10^scale` - `scale` is the number of digits after the point, so that an `unscaled`
+// of `12345` with a `scale` of 2 is 123.45 (a cobol `pic s9(3)v99`). equality compares the fields, not the numbers
+// that they stand for, so 1.0 and 1.00 are different values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decimal {
+    pub unscaled: i128,
+    pub scale:    u32,
+}
+
+impl Decimal {
+    #[inline] pub fn new(unscaled: i128, scale: u32) -> Decimal { Decimal { unscaled, scale } }
+}
+
+
+
+// the most digits in a field, and the widest packed and zoned fields in bytes.
+pub(crate) const MAXIMUM_DIGITS:        usize = 31;
+pub(crate) const MAXIMUM_PACKED_LENGTH: usize = MAXIMUM_DIGITS / 2 + 1;
+pub(crate) const MAXIMUM_ZONED_LENGTH:  usize = MAXIMUM_DIGITS;
+
+#[inline] pub(crate) fn packed_digits(bytes: usize) -> u32 { (2 * bytes - 1) as u32 }
+#[inline] pub(crate) fn zoned_digits(bytes: usize)  -> u32 { bytes as u32           }
+
+// checks that a field of `bytes` bytes is supported and has at least `scale` digits, returning `bytes`.
+#[inline] pub(crate) fn check_packed(bytes: usize, scale: u32) -> Result<usize, Error> { check_scale(check_width(bytes, MAXIMUM_PACKED_LENGTH)?, scale, packed_digits) }
+#[inline] pub(crate) fn check_zoned(bytes: usize, scale: u32)  -> Result<usize, Error> { check_scale(check_width(bytes, MAXIMUM_ZONED_LENGTH)?, scale, zoned_digits)   }
+
+
+
+// `stream` must hold at least one byte.
+#[inline]
+pub(crate) fn decode_packed(stream: &[u8]) -> Result<i128, Error> {
+    let (&last, rest) = stream.split_last().expect("packed decimal field is empty");
+    let mut value     = 0;
+
+    for &x in rest {
+        value = digit(digit(value, x >> 4)?, x & 0xf)?;
+    }
+
+    signed(digit(value, last >> 4)?, last & 0xf)
+}
+
+#[inline]
+pub(crate) fn decode_zoned(stream: &[u8]) -> Result<i128, Error> {
+    let (&last, rest) = stream.split_last().expect("zoned decimal field is empty");
+    let mut value     = 0;
+
+    for &x in rest {
+        if x >> 4 != 0xf {
+            return Err(Error::InvalidData("zoned decimal digit doesn't have an f zone"));
+        }
+
+        value = digit(value, x & 0xf)?;
+    }
+
+    signed(digit(value, last & 0xf)?, last >> 4)
+}
+
+// fails with `Error::ValueTooWide` if `value` has more digits than `stream` holds, leaving `stream` untouched.
+#[inline]
+pub(crate) fn encode_packed(stream: &mut [u8], value: i128) -> Result<(), Error> {
+    let mut digits   = check_digits(value, packed_digits(stream.len()), stream.len())?;
+    let (last, rest) = stream.split_last_mut().expect("packed decimal field is empty");
+
+    *last = digits.next() << 4 | sign(value);
+
+    for x in rest.iter_mut().rev() {
+        let low = digits.next();
+        *x      = digits.next() << 4 | low;
+    }
+
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn encode_zoned(stream: &mut [u8], value: i128) -> Result<(), Error> {
+    let mut digits   = check_digits(value, zoned_digits(stream.len()), stream.len())?;
+    let (last, rest) = stream.split_last_mut().expect("zoned decimal field is empty");
+
+    *last = sign(value) << 4 | digits.next();
+
+    for x in rest.iter_mut().rev() {
+        *x = 0xf0 | digits.next();
+    }
+
+    Ok(())
+}
+
+
+
+// the digits of a magnitude, least significant first, followed by as many zeroes as are needed.
+struct Digits(u128);
+
+impl Digits {
+    #[inline]
+    fn next(&mut self) -> u8 {
+        let digit = (self.0 % 10) as u8;
+
+        self.0 /= 10;
+        digit
+    }
+}
+
+#[inline]
+fn check_digits(value: i128, digits: u32, bytes: usize) -> Result<Digits, Error> {
+    match value.unsigned_abs() {
+        x if x < 10u128.pow(digits) => Ok(Digits(x)),
+        _                           => Err(Error::ValueTooWide { bytes }),
+    }
+}
+
+#[inline]
+fn check_scale(bytes: usize, scale: u32, digits: fn(usize) -> u32) -> Result<usize, Error> {
+    match scale {
+        x if x <= digits(bytes) => Ok(bytes),
+        _                       => Err(Error::InvalidInput("decimal scale is greater than the digits in the field")),
+    }
+}
+
+#[inline]
+fn digit(value: i128, nibble: u8) -> Result<i128, Error> {
+    match nibble {
+        0..=9 => Ok(value * 10 + nibble as i128),
+        _     => Err(Error::InvalidData("decimal digit is greater than 9")),
+    }
+}
+
+#[inline]
+fn signed(value: i128, nibble: u8) -> Result<i128, Error> {
+    match nibble {
+        0xa | 0xc | 0xe | 0xf => Ok(value),
+        0xb | 0xd             => Ok(-value),
+        _                     => Err(Error::InvalidData("decimal sign nibble isn't a sign")),
+    }
+}
+
+#[inline]
+fn sign(value: i128) -> u8 {
+    match value {
+        x if x < 0 => 0xd,
+        _          => 0xc,
+    }
+}
@@ -1,7 +1,29 @@
 use {
-    std,
-    std::fmt::Debug,
-    std::hash::Hash,
+    core,
+    core::fmt::Debug,
+    core::hash::Hash,
+    core::mem::size_of_val,
+
+    bite::Error,
+    bite::error::check_length,
+    bite::error::check_length_mut,
+    bite::error::check_width,
+    bite::float::bf16_to_f32,
+    bite::float::f16_to_f32,
+    bite::float::f32_to_bf16,
+    bite::float::f32_to_f16,
+    bite::float::f32_to_ibm,
+    bite::float::f32_to_vax_f,
+    bite::float::f64_to_f80,
+    bite::float::f64_to_ibm,
+    bite::float::f64_to_vax_d,
+    bite::float::f64_to_vax_g,
+    bite::float::f80_to_f64,
+    bite::float::ibm_to_f32,
+    bite::float::ibm_to_f64,
+    bite::float::vax_d_to_f64,
+    bite::float::vax_f_to_f32,
+    bite::float::vax_g_to_f64,
 };
 
 
@@ -18,7 +40,8 @@ pub type NetworkEndian = BigEndian;
 
 // a trait that defines basic io operations in some endian encoding.
 //
-// convenience extension traits exist for reading and writing bytes for `std::io::read` and `std::io::write` streams.
+// convenience extension traits exist for reading and writing bytes for `std::io::read` and `std::io::write` streams,
+// and for `SliceReader` and `SliceWriter` cursors when `std` is unavailable.
 pub trait Endianness: Clone + Copy + Debug + Eq + Hash + Ord + PartialEq + PartialOrd {
     fn read_u8           (stream: &[u8])               -> u8;
     fn read_u16          (stream: &[u8])               -> u16;
@@ -53,10 +76,13 @@ pub trait Endianness: Clone + Copy + Debug + Eq + Hash + Ord + PartialEq + Parti
     fn write_slice_u64   (stream: &mut [u8], values: &[u64]);
     fn write_slice_u128  (stream: &mut [u8], values: &[u128]);
 
+    // whether this encoding matches the host platform's endianness, in which case no conversion is ever applied.
+    fn is_native         ()                            -> bool;
+
 
 
     #[inline] fn read_u24          (stream: &[u8])               -> u32           { Self::read_uint(stream, 3) as u32                                       }
-    #[inline] fn read_i24          (stream: &[u8])               -> i32           { Self::read_uint(stream, 3) as i32                                       }
+    #[inline] fn read_i24          (stream: &[u8])               -> i32           { Self::read_int (stream, 3) as i32                                       }
 
     #[inline] fn read_i8           (stream: &[u8])               -> i8            { Self::read_u8  (stream) as i8                                           }
     #[inline] fn read_i16          (stream: &[u8])               -> i16           { Self::read_u16 (stream) as i16                                          }
@@ -65,8 +91,11 @@ pub trait Endianness: Clone + Copy + Debug + Eq + Hash + Ord + PartialEq + Parti
     #[inline] fn read_i128         (stream: &[u8])               -> i128          { Self::read_u128(stream) as i128                                         }
     #[inline] fn read_int          (stream: &[u8], bytes: usize) -> i64           { extend_sign   (Self::read_uint   (stream, bytes), bytes)                }
     #[inline] fn read_int128       (stream: &[u8], bytes: usize) -> i128          { extend_sign128(Self::read_uint128(stream, bytes), bytes)                }
-    #[inline] fn read_f32          (stream: &[u8])               -> f32           { unsafe { std::mem::transmute(Self::read_u32(stream)) }                  }
-    #[inline] fn read_f64          (stream: &[u8])               -> f64           { unsafe { std::mem::transmute(Self::read_u64(stream)) }                  }
+    #[inline] fn read_f32          (stream: &[u8])               -> f32           { unsafe { core::mem::transmute(Self::read_u32(stream)) }                  }
+    #[inline] fn read_f64          (stream: &[u8])               -> f64           { unsafe { core::mem::transmute(Self::read_u64(stream)) }                  }
+    #[inline] fn read_f16          (stream: &[u8])               -> f32           { f16_to_f32 (Self::read_u16(stream))                                     }
+    #[inline] fn read_bf16         (stream: &[u8])               -> f32           { bf16_to_f32(Self::read_u16(stream))                                     }
+    #[inline] fn read_f80          (stream: &[u8])               -> (f64, bool)   { f80_to_f64 (Self::read_uint128(stream, 10))                             }
 
     #[inline] fn write_u24         (stream: &mut [u8], value: u32)                { Self::write_uint   (stream, value as u64, 3)                            }
     #[inline] fn write_i24         (stream: &mut [u8], value: i32)                { Self::write_int    (stream, value as i64, 3)                            }
@@ -77,33 +106,269 @@ pub trait Endianness: Clone + Copy + Debug + Eq + Hash + Ord + PartialEq + Parti
     #[inline] fn write_i128        (stream: &mut [u8], value: i128)               { Self::write_u128   (stream, value as u128)                              }
     #[inline] fn write_int         (stream: &mut [u8], value: i64,  bytes: usize) { Self::write_uint   (stream, unextend_sign(value, bytes), bytes)         }
     #[inline] fn write_int128      (stream: &mut [u8], value: i128, bytes: usize) { Self::write_uint128(stream, unextend_sign128(value, bytes), bytes)      }
-    #[inline] fn write_f32         (stream: &mut [u8], value: f32)                { Self::write_u32    (stream, unsafe { std::mem::transmute(value) })      }
-    #[inline] fn write_f64         (stream: &mut [u8], value: f64)                { Self::write_u64    (stream, unsafe { std::mem::transmute(value) })      }
+    #[inline] fn write_f32         (stream: &mut [u8], value: f32)                { Self::write_u32    (stream, unsafe { core::mem::transmute(value) })      }
+    #[inline] fn write_f64         (stream: &mut [u8], value: f64)                { Self::write_u64    (stream, unsafe { core::mem::transmute(value) })      }
+    #[inline] fn write_f16         (stream: &mut [u8], value: f32)                { Self::write_u16    (stream, f32_to_f16(value))                          }
+    #[inline] fn write_bf16        (stream: &mut [u8], value: f32)                { Self::write_u16    (stream, f32_to_bf16(value))                         }
+    #[inline] fn write_f80         (stream: &mut [u8], value: f64)                { Self::write_uint128(stream, f64_to_f80(value), 10)                      }
+
+    #[inline] fn read_slice_i16    (stream: &[u8], values: &mut [i16])            { Self::read_slice_u16 (stream, unsafe { core::mem::transmute(values) });  }
+    #[inline] fn read_slice_i32    (stream: &[u8], values: &mut [i32])            { Self::read_slice_u32 (stream, unsafe { core::mem::transmute(values) });  }
+    #[inline] fn read_slice_i64    (stream: &[u8], values: &mut [i64])            { Self::read_slice_u64 (stream, unsafe { core::mem::transmute(values) });  }
+    #[inline] fn read_slice_i128   (stream: &[u8], values: &mut [i128])           { Self::read_slice_u128(stream, unsafe { core::mem::transmute(values) });  }
+
+    #[inline] fn read_slice_f32_unchecked(stream: &[u8], values: &mut [f32])      { Self::read_slice_u32(stream, unsafe { core::mem::transmute(values) });   }
+    #[inline] fn read_slice_f64_unchecked(stream: &[u8], values: &mut [f64])      { Self::read_slice_u64(stream, unsafe { core::mem::transmute(values) });   }
+
+    #[inline] fn write_slice_i16   (stream: &mut [u8], values: &[i16])            { Self::write_slice_u16 (stream, unsafe { core::mem::transmute(values) }); }
+    #[inline] fn write_slice_i32   (stream: &mut [u8], values: &[i32])            { Self::write_slice_u32 (stream, unsafe { core::mem::transmute(values) }); }
+    #[inline] fn write_slice_i64   (stream: &mut [u8], values: &[i64])            { Self::write_slice_u64 (stream, unsafe { core::mem::transmute(values) }); }
+    #[inline] fn write_slice_i128  (stream: &mut [u8], values: &[i128])           { Self::write_slice_u128(stream, unsafe { core::mem::transmute(values) }); }
+
+    #[inline] fn write_slice_f32   (stream: &mut [u8], values: &[f32])            { Self::write_slice_u32(stream, unsafe { core::mem::transmute(values) });  }
+    #[inline] fn write_slice_f64   (stream: &mut [u8], values: &[f64])            { Self::write_slice_u64(stream, unsafe { core::mem::transmute(values) });  }
+
+    #[inline] fn convert_slice_i16 (values: &mut [i16])                           { Self::convert_slice_u16 (unsafe { core::mem::transmute(values) });       }
+    #[inline] fn convert_slice_i32 (values: &mut [i32])                           { Self::convert_slice_u32 (unsafe { core::mem::transmute(values) });       }
+    #[inline] fn convert_slice_i64 (values: &mut [i64])                           { Self::convert_slice_u64 (unsafe { core::mem::transmute(values) });       }
+    #[inline] fn convert_slice_i128(values: &mut [i128])                          { Self::convert_slice_u128(unsafe { core::mem::transmute(values) });       }
+
+    // 16-bit floats are widened to `f32` as they are read, so they can't be converted in place like the other slices.
+    #[inline] fn read_slice_f16    (stream: &[u8], values: &mut [f32])            { read_slice_as (stream, values, 2, Self::read_f16)                       }
+    #[inline] fn read_slice_bf16   (stream: &[u8], values: &mut [f32])            { read_slice_as (stream, values, 2, Self::read_bf16)                      }
+    #[inline] fn write_slice_f16   (stream: &mut [u8], values: &[f32])            { write_slice_as(stream, values, 2, Self::write_f16)                      }
+    #[inline] fn write_slice_bf16  (stream: &mut [u8], values: &[f32])            { write_slice_as(stream, values, 2, Self::write_bf16)                     }
+
+    // `f80`s are ten bytes apiece. reading returns whether any of the values had to be rounded to fit in an `f64` -
+    // writing is always exact.
+    #[inline]
+    fn read_slice_f80(stream: &[u8], values: &mut [f64]) -> bool {
+        let stream      = &stream[..10 * values.len()];
+        let mut rounded = false;
+
+        for (x, data) in values.iter_mut().zip(stream.chunks(10)) {
+            let (value, lossy) = Self::read_f80(data);
+
+            *x       = value;
+            rounded |= lossy;
+        }
+
+        rounded
+    }
+
+    #[inline]
+    fn write_slice_f80(stream: &mut [u8], values: &[f64]) {
+        for (&x, data) in values.iter().zip(stream[..10 * values.len()].chunks_mut(10)) {
+            Self::write_f80(data, x);
+        }
+    }
+
+    // ibm system/360 hexadecimal floats, as used by seg-y, and vax f, d and g floats. vax floats are stored as 16-bit
+    // words, most significant first, with each word in this encoding - `LittleEndian` for data written by a vax.
+    //
+    // neither format has infinities or nans. reading rounds to nearest, giving an infinity for an ibm single beyond
+    // the range of an `f32`, and a nan for a vax reserved operand. writing rounds to nearest too - infinities and
+    // values too large for the format saturate to its largest magnitude, values too small underflow to zero, and nans
+    // saturate in ibm and become the reserved operand in vax.
+    #[inline] fn read_ibm_f32      (stream: &[u8])               -> f32           { ibm_to_f32  (Self::read_u32(stream))                                    }
+    #[inline] fn read_ibm_f64      (stream: &[u8])               -> f64           { ibm_to_f64  (Self::read_u64(stream))                                    }
+    #[inline] fn read_vax_f        (stream: &[u8])               -> f32           { vax_f_to_f32(read_words::<Self>(stream, 2) as u32)                      }
+    #[inline] fn read_vax_d        (stream: &[u8])               -> f64           { vax_d_to_f64(read_words::<Self>(stream, 4))                             }
+    #[inline] fn read_vax_g        (stream: &[u8])               -> f64           { vax_g_to_f64(read_words::<Self>(stream, 4))                             }
+
+    #[inline] fn write_ibm_f32     (stream: &mut [u8], value: f32)                { Self::write_u32(stream, f32_to_ibm(value))                              }
+    #[inline] fn write_ibm_f64     (stream: &mut [u8], value: f64)                { Self::write_u64(stream, f64_to_ibm(value))                              }
+    #[inline] fn write_vax_f       (stream: &mut [u8], value: f32)                { write_words::<Self>(stream, f32_to_vax_f(value) as u64, 2)              }
+    #[inline] fn write_vax_d       (stream: &mut [u8], value: f64)                { write_words::<Self>(stream, f64_to_vax_d(value), 4)                     }
+    #[inline] fn write_vax_g       (stream: &mut [u8], value: f64)                { write_words::<Self>(stream, f64_to_vax_g(value), 4)                     }
+
+    #[inline] fn read_slice_ibm_f32(stream: &[u8], values: &mut [f32])            { read_slice_as (stream, values, 4, Self::read_ibm_f32)                   }
+    #[inline] fn read_slice_ibm_f64(stream: &[u8], values: &mut [f64])            { read_slice_as (stream, values, 8, Self::read_ibm_f64)                   }
+    #[inline] fn read_slice_vax_f  (stream: &[u8], values: &mut [f32])            { read_slice_as (stream, values, 4, Self::read_vax_f)                     }
+    #[inline] fn read_slice_vax_d  (stream: &[u8], values: &mut [f64])            { read_slice_as (stream, values, 8, Self::read_vax_d)                     }
+    #[inline] fn read_slice_vax_g  (stream: &[u8], values: &mut [f64])            { read_slice_as (stream, values, 8, Self::read_vax_g)                     }
+
+    #[inline] fn write_slice_ibm_f32(stream: &mut [u8], values: &[f32])           { write_slice_as(stream, values, 4, Self::write_ibm_f32)                  }
+    #[inline] fn write_slice_ibm_f64(stream: &mut [u8], values: &[f64])           { write_slice_as(stream, values, 8, Self::write_ibm_f64)                  }
+    #[inline] fn write_slice_vax_f (stream: &mut [u8], values: &[f32])            { write_slice_as(stream, values, 4, Self::write_vax_f)                    }
+    #[inline] fn write_slice_vax_d (stream: &mut [u8], values: &[f64])            { write_slice_as(stream, values, 8, Self::write_vax_d)                    }
+    #[inline] fn write_slice_vax_g (stream: &mut [u8], values: &[f64])            { write_slice_as(stream, values, 8, Self::write_vax_g)                    }
+
+
+
+    // checked equivalents of the functions above, which return an error instead of panicking when `stream` is too
+    // short, when a variable width is outside of the supported range, or when a value doesn't fit in its width.
+    //
+    // slice functions only require `stream` to be at least as long as the values, and ignore any bytes past them.
+    #[inline] fn try_read_u8         (stream: &[u8])                      -> Result<  u8, Error> { Ok(Self::read_u8(check_length(stream, 1)?))                                                }
+    #[inline] fn try_read_i8         (stream: &[u8])                      -> Result<  i8, Error> { Ok(Self::read_i8(check_length(stream, 1)?))                                                }
+    #[inline] fn try_read_u16        (stream: &[u8])                      -> Result< u16, Error> { Ok(Self::read_u16(check_length(stream, 2)?))                                               }
+    #[inline] fn try_read_i16        (stream: &[u8])                      -> Result< i16, Error> { Ok(Self::read_i16(check_length(stream, 2)?))                                               }
+    #[inline] fn try_read_u24        (stream: &[u8])                      -> Result< u32, Error> { Ok(Self::read_u24(check_length(stream, 3)?))                                               }
+    #[inline] fn try_read_i24        (stream: &[u8])                      -> Result< i32, Error> { Ok(Self::read_i24(check_length(stream, 3)?))                                               }
+    #[inline] fn try_read_u32        (stream: &[u8])                      -> Result< u32, Error> { Ok(Self::read_u32(check_length(stream, 4)?))                                               }
+    #[inline] fn try_read_i32        (stream: &[u8])                      -> Result< i32, Error> { Ok(Self::read_i32(check_length(stream, 4)?))                                               }
+    #[inline] fn try_read_u64        (stream: &[u8])                      -> Result< u64, Error> { Ok(Self::read_u64(check_length(stream, 8)?))                                               }
+    #[inline] fn try_read_i64        (stream: &[u8])                      -> Result< i64, Error> { Ok(Self::read_i64(check_length(stream, 8)?))                                               }
+    #[inline] fn try_read_u128       (stream: &[u8])                      -> Result<u128, Error> { Ok(Self::read_u128(check_length(stream, 16)?))                                             }
+    #[inline] fn try_read_i128       (stream: &[u8])                      -> Result<i128, Error> { Ok(Self::read_i128(check_length(stream, 16)?))                                             }
+    #[inline] fn try_read_f32        (stream: &[u8])                      -> Result< f32, Error> { Ok(Self::read_f32(check_length(stream, 4)?))                                               }
+    #[inline] fn try_read_f64        (stream: &[u8])                      -> Result< f64, Error> { Ok(Self::read_f64(check_length(stream, 8)?))                                               }
+    #[inline] fn try_read_f16        (stream: &[u8])                      -> Result< f32, Error> { Ok(Self::read_f16(check_length(stream, 2)?))                                               }
+    #[inline] fn try_read_bf16       (stream: &[u8])                      -> Result< f32, Error> { Ok(Self::read_bf16(check_length(stream, 2)?))                                              }
+    #[inline] fn try_read_uint       (stream: &[u8], bytes: usize)        -> Result< u64, Error> { Ok(Self::read_uint(check_length(stream, check_width(bytes, 8)?)?, bytes))                  }
+    #[inline] fn try_read_int        (stream: &[u8], bytes: usize)        -> Result< i64, Error> { Ok(Self::read_int(check_length(stream, check_width(bytes, 8)?)?, bytes))                   }
+    #[inline] fn try_read_uint128    (stream: &[u8], bytes: usize)        -> Result<u128, Error> { Ok(Self::read_uint128(check_length(stream, check_width(bytes, 16)?)?, bytes))              }
+    #[inline] fn try_read_int128     (stream: &[u8], bytes: usize)        -> Result<i128, Error> { Ok(Self::read_int128(check_length(stream, check_width(bytes, 16)?)?, bytes))               }
+
+    #[inline] fn try_write_u8        (stream: &mut [u8], value: u8)       -> Result<  (), Error> { Self::write_u8(check_length_mut(stream, 1)?, value); Ok(())                                }
+    #[inline] fn try_write_u16       (stream: &mut [u8], value: u16)      -> Result<  (), Error> { Self::write_u16(check_length_mut(stream, 2)?, value); Ok(())                               }
+    #[inline] fn try_write_i16       (stream: &mut [u8], value: i16)      -> Result<  (), Error> { Self::write_i16(check_length_mut(stream, 2)?, value); Ok(())                               }
+    #[inline] fn try_write_u32       (stream: &mut [u8], value: u32)      -> Result<  (), Error> { Self::write_u32(check_length_mut(stream, 4)?, value); Ok(())                               }
+    #[inline] fn try_write_i32       (stream: &mut [u8], value: i32)      -> Result<  (), Error> { Self::write_i32(check_length_mut(stream, 4)?, value); Ok(())                               }
+    #[inline] fn try_write_u64       (stream: &mut [u8], value: u64)      -> Result<  (), Error> { Self::write_u64(check_length_mut(stream, 8)?, value); Ok(())                               }
+    #[inline] fn try_write_i64       (stream: &mut [u8], value: i64)      -> Result<  (), Error> { Self::write_i64(check_length_mut(stream, 8)?, value); Ok(())                               }
+    #[inline] fn try_write_u128      (stream: &mut [u8], value: u128)     -> Result<  (), Error> { Self::write_u128(check_length_mut(stream, 16)?, value); Ok(())                             }
+    #[inline] fn try_write_i128      (stream: &mut [u8], value: i128)     -> Result<  (), Error> { Self::write_i128(check_length_mut(stream, 16)?, value); Ok(())                             }
+    #[inline] fn try_write_f32       (stream: &mut [u8], value: f32)      -> Result<  (), Error> { Self::write_f32(check_length_mut(stream, 4)?, value); Ok(())                               }
+    #[inline] fn try_write_f64       (stream: &mut [u8], value: f64)      -> Result<  (), Error> { Self::write_f64(check_length_mut(stream, 8)?, value); Ok(())                               }
+    #[inline] fn try_write_f16       (stream: &mut [u8], value: f32)      -> Result<  (), Error> { Self::write_f16(check_length_mut(stream, 2)?, value); Ok(())                               }
+    #[inline] fn try_write_bf16      (stream: &mut [u8], value: f32)      -> Result<  (), Error> { Self::write_bf16(check_length_mut(stream, 2)?, value); Ok(())                              }
+    #[inline] fn try_write_f80       (stream: &mut [u8], value: f64)      -> Result<  (), Error> { Self::write_f80(check_length_mut(stream, 10)?, value); Ok(())                              }
+    #[inline] fn try_write_u24       (stream: &mut [u8], value: u32)      -> Result<  (), Error> { Self::try_write_uint(stream, value as u64, 3)                                              }
+    #[inline] fn try_write_i24       (stream: &mut [u8], value: i32)      -> Result<  (), Error> { Self::try_write_int (stream, value as i64, 3)                                              }
+
+    #[inline] fn try_read_slice_u16  (stream: &[u8], values: &mut [u16])  -> Result<  (), Error> { Self::read_slice_u16(check_length(stream, size_of_val(values))?, values); Ok(())           }
+    #[inline] fn try_read_slice_i16  (stream: &[u8], values: &mut [i16])  -> Result<  (), Error> { Self::read_slice_i16(check_length(stream, size_of_val(values))?, values); Ok(())           }
+    #[inline] fn try_read_slice_u32  (stream: &[u8], values: &mut [u32])  -> Result<  (), Error> { Self::read_slice_u32(check_length(stream, size_of_val(values))?, values); Ok(())           }
+    #[inline] fn try_read_slice_i32  (stream: &[u8], values: &mut [i32])  -> Result<  (), Error> { Self::read_slice_i32(check_length(stream, size_of_val(values))?, values); Ok(())           }
+    #[inline] fn try_read_slice_u64  (stream: &[u8], values: &mut [u64])  -> Result<  (), Error> { Self::read_slice_u64(check_length(stream, size_of_val(values))?, values); Ok(())           }
+    #[inline] fn try_read_slice_i64  (stream: &[u8], values: &mut [i64])  -> Result<  (), Error> { Self::read_slice_i64(check_length(stream, size_of_val(values))?, values); Ok(())           }
+    #[inline] fn try_read_slice_u128 (stream: &[u8], values: &mut [u128]) -> Result<  (), Error> { Self::read_slice_u128(check_length(stream, size_of_val(values))?, values); Ok(())          }
+    #[inline] fn try_read_slice_i128 (stream: &[u8], values: &mut [i128]) -> Result<  (), Error> { Self::read_slice_i128(check_length(stream, size_of_val(values))?, values); Ok(())          }
+    #[inline] fn try_read_slice_f32  (stream: &[u8], values: &mut [f32])  -> Result<  (), Error> { Self::read_slice_f32_unchecked(check_length(stream, size_of_val(values))?, values); Ok(()) }
+    #[inline] fn try_read_slice_f64  (stream: &[u8], values: &mut [f64])  -> Result<  (), Error> { Self::read_slice_f64_unchecked(check_length(stream, size_of_val(values))?, values); Ok(()) }
+    #[inline] fn try_read_slice_f16  (stream: &[u8], values: &mut [f32])  -> Result<  (), Error> { Self::read_slice_f16(check_length(stream, 2 * values.len())?, values); Ok(())              }
+    #[inline] fn try_read_slice_bf16 (stream: &[u8], values: &mut [f32])  -> Result<  (), Error> { Self::read_slice_bf16(check_length(stream, 2 * values.len())?, values); Ok(())             }
+
+    #[inline] fn try_write_slice_u16 (stream: &mut [u8], values: &[u16])  -> Result<  (), Error> { Self::write_slice_u16(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_i16 (stream: &mut [u8], values: &[i16])  -> Result<  (), Error> { Self::write_slice_i16(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_u32 (stream: &mut [u8], values: &[u32])  -> Result<  (), Error> { Self::write_slice_u32(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_i32 (stream: &mut [u8], values: &[i32])  -> Result<  (), Error> { Self::write_slice_i32(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_u64 (stream: &mut [u8], values: &[u64])  -> Result<  (), Error> { Self::write_slice_u64(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_i64 (stream: &mut [u8], values: &[i64])  -> Result<  (), Error> { Self::write_slice_i64(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_u128(stream: &mut [u8], values: &[u128]) -> Result<  (), Error> { Self::write_slice_u128(check_length_mut(stream, size_of_val(values))?, values); Ok(())     }
+    #[inline] fn try_write_slice_i128(stream: &mut [u8], values: &[i128]) -> Result<  (), Error> { Self::write_slice_i128(check_length_mut(stream, size_of_val(values))?, values); Ok(())     }
+    #[inline] fn try_write_slice_f32 (stream: &mut [u8], values: &[f32])  -> Result<  (), Error> { Self::write_slice_f32(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_f64 (stream: &mut [u8], values: &[f64])  -> Result<  (), Error> { Self::write_slice_f64(check_length_mut(stream, size_of_val(values))?, values); Ok(())      }
+    #[inline] fn try_write_slice_f16 (stream: &mut [u8], values: &[f32])  -> Result<  (), Error> { Self::write_slice_f16(check_length_mut(stream, 2 * values.len())?, values); Ok(())         }
+    #[inline] fn try_write_slice_bf16(stream: &mut [u8], values: &[f32])  -> Result<  (), Error> { Self::write_slice_bf16(check_length_mut(stream, 2 * values.len())?, values); Ok(())        }
+    #[inline] fn try_write_slice_f80 (stream: &mut [u8], values: &[f64])  -> Result<  (), Error> { Self::write_slice_f80(check_length_mut(stream, 10 * values.len())?, values); Ok(())        }
+
+    #[inline]
+    fn try_write_uint(stream: &mut [u8], value: u64, bytes: usize) -> Result<(), Error> {
+        check_width(bytes, 8)?;
+        fits_unsigned(value as u128, bytes)?;
+
+        Self::write_uint(check_length_mut(stream, bytes)?, value, bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_write_int(stream: &mut [u8], value: i64, bytes: usize) -> Result<(), Error> {
+        check_width(bytes, 8)?;
+        fits_signed(value as i128, bytes)?;
 
-    #[inline] fn read_slice_i16    (stream: &[u8], values: &mut [i16])            { Self::read_slice_u16 (stream, unsafe { std::mem::transmute(values) });  }
-    #[inline] fn read_slice_i32    (stream: &[u8], values: &mut [i32])            { Self::read_slice_u32 (stream, unsafe { std::mem::transmute(values) });  }
-    #[inline] fn read_slice_i64    (stream: &[u8], values: &mut [i64])            { Self::read_slice_u64 (stream, unsafe { std::mem::transmute(values) });  }
-    #[inline] fn read_slice_i128   (stream: &[u8], values: &mut [i128])           { Self::read_slice_u128(stream, unsafe { std::mem::transmute(values) });  }
+        Self::write_int(check_length_mut(stream, bytes)?, value, bytes);
+        Ok(())
+    }
 
-    #[inline] fn read_slice_f32_unchecked(stream: &[u8], values: &mut [f32])      { Self::read_slice_u32(stream, unsafe { std::mem::transmute(values) });   }
-    #[inline] fn read_slice_f64_unchecked(stream: &[u8], values: &mut [f64])      { Self::read_slice_u64(stream, unsafe { std::mem::transmute(values) });   }
+    #[inline]
+    fn try_write_uint128(stream: &mut [u8], value: u128, bytes: usize) -> Result<(), Error> {
+        check_width(bytes, 16)?;
+        fits_unsigned(value, bytes)?;
 
-    #[inline] fn write_slice_i16   (stream: &mut [u8], values: &[i16])            { Self::write_slice_u16 (stream, unsafe { std::mem::transmute(values) }); }
-    #[inline] fn write_slice_i32   (stream: &mut [u8], values: &[i32])            { Self::write_slice_u32 (stream, unsafe { std::mem::transmute(values) }); }
-    #[inline] fn write_slice_i64   (stream: &mut [u8], values: &[i64])            { Self::write_slice_u64 (stream, unsafe { std::mem::transmute(values) }); }
-    #[inline] fn write_slice_i128  (stream: &mut [u8], values: &[i128])           { Self::write_slice_u128(stream, unsafe { std::mem::transmute(values) }); }
+        Self::write_uint128(check_length_mut(stream, bytes)?, value, bytes);
+        Ok(())
+    }
 
-    #[inline] fn write_slice_f32   (stream: &mut [u8], values: &[f32])            { Self::write_slice_u32(stream, unsafe { std::mem::transmute(values) });  }
-    #[inline] fn write_slice_f64   (stream: &mut [u8], values: &[f64])            { Self::write_slice_u64(stream, unsafe { std::mem::transmute(values) });  }
+    #[inline]
+    fn try_write_int128(stream: &mut [u8], value: i128, bytes: usize) -> Result<(), Error> {
+        check_width(bytes, 16)?;
+        fits_signed(value, bytes)?;
 
-    #[inline] fn convert_slice_i16 (values: &mut [i16])                           { Self::convert_slice_u16 (unsafe { std::mem::transmute(values) });       }
-    #[inline] fn convert_slice_i32 (values: &mut [i32])                           { Self::convert_slice_u32 (unsafe { std::mem::transmute(values) });       }
-    #[inline] fn convert_slice_i64 (values: &mut [i64])                           { Self::convert_slice_u64 (unsafe { std::mem::transmute(values) });       }
-    #[inline] fn convert_slice_i128(values: &mut [i128])                          { Self::convert_slice_u128(unsafe { std::mem::transmute(values) });       }
+        Self::write_int128(check_length_mut(stream, bytes)?, value, bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_read_f80(stream: &[u8]) -> Result<(f64, bool), Error> {
+        Ok(Self::read_f80(check_length(stream, 10)?))
+    }
+
+    #[inline]
+    fn try_read_slice_f80(stream: &[u8], values: &mut [f64]) -> Result<bool, Error> {
+        Ok(Self::read_slice_f80(check_length(stream, 10 * values.len())?, values))
+    }
+
+    #[inline] fn try_read_ibm_f32       (stream: &[u8])                     -> Result<f32, Error> { Ok(Self::read_ibm_f32(check_length(stream, 4)?))                                       }
+    #[inline] fn try_read_ibm_f64       (stream: &[u8])                     -> Result<f64, Error> { Ok(Self::read_ibm_f64(check_length(stream, 8)?))                                       }
+    #[inline] fn try_read_vax_f         (stream: &[u8])                     -> Result<f32, Error> { Ok(Self::read_vax_f(check_length(stream, 4)?))                                         }
+    #[inline] fn try_read_vax_d         (stream: &[u8])                     -> Result<f64, Error> { Ok(Self::read_vax_d(check_length(stream, 8)?))                                         }
+    #[inline] fn try_read_vax_g         (stream: &[u8])                     -> Result<f64, Error> { Ok(Self::read_vax_g(check_length(stream, 8)?))                                         }
+
+    #[inline] fn try_write_ibm_f32      (stream: &mut [u8], value: f32)     -> Result< (), Error> { Self::write_ibm_f32(check_length_mut(stream, 4)?, value); Ok(())                       }
+    #[inline] fn try_write_ibm_f64      (stream: &mut [u8], value: f64)     -> Result< (), Error> { Self::write_ibm_f64(check_length_mut(stream, 8)?, value); Ok(())                       }
+    #[inline] fn try_write_vax_f        (stream: &mut [u8], value: f32)     -> Result< (), Error> { Self::write_vax_f(check_length_mut(stream, 4)?, value); Ok(())                         }
+    #[inline] fn try_write_vax_d        (stream: &mut [u8], value: f64)     -> Result< (), Error> { Self::write_vax_d(check_length_mut(stream, 8)?, value); Ok(())                         }
+    #[inline] fn try_write_vax_g        (stream: &mut [u8], value: f64)     -> Result< (), Error> { Self::write_vax_g(check_length_mut(stream, 8)?, value); Ok(())                         }
+
+    #[inline] fn try_read_slice_ibm_f32 (stream: &[u8], values: &mut [f32]) -> Result< (), Error> { Self::read_slice_ibm_f32(check_length(stream, 4 * values.len())?, values); Ok(())      }
+    #[inline] fn try_read_slice_ibm_f64 (stream: &[u8], values: &mut [f64]) -> Result< (), Error> { Self::read_slice_ibm_f64(check_length(stream, 8 * values.len())?, values); Ok(())      }
+    #[inline] fn try_read_slice_vax_f   (stream: &[u8], values: &mut [f32]) -> Result< (), Error> { Self::read_slice_vax_f(check_length(stream, 4 * values.len())?, values); Ok(())        }
+    #[inline] fn try_read_slice_vax_d   (stream: &[u8], values: &mut [f64]) -> Result< (), Error> { Self::read_slice_vax_d(check_length(stream, 8 * values.len())?, values); Ok(())        }
+    #[inline] fn try_read_slice_vax_g   (stream: &[u8], values: &mut [f64]) -> Result< (), Error> { Self::read_slice_vax_g(check_length(stream, 8 * values.len())?, values); Ok(())        }
+
+    #[inline] fn try_write_slice_ibm_f32(stream: &mut [u8], values: &[f32]) -> Result< (), Error> { Self::write_slice_ibm_f32(check_length_mut(stream, 4 * values.len())?, values); Ok(()) }
+    #[inline] fn try_write_slice_ibm_f64(stream: &mut [u8], values: &[f64]) -> Result< (), Error> { Self::write_slice_ibm_f64(check_length_mut(stream, 8 * values.len())?, values); Ok(()) }
+    #[inline] fn try_write_slice_vax_f  (stream: &mut [u8], values: &[f32]) -> Result< (), Error> { Self::write_slice_vax_f(check_length_mut(stream, 4 * values.len())?, values); Ok(())   }
+    #[inline] fn try_write_slice_vax_d  (stream: &mut [u8], values: &[f64]) -> Result< (), Error> { Self::write_slice_vax_d(check_length_mut(stream, 8 * values.len())?, values); Ok(())   }
+    #[inline] fn try_write_slice_vax_g  (stream: &mut [u8], values: &[f64]) -> Result< (), Error> { Self::write_slice_vax_g(check_length_mut(stream, 8 * values.len())?, values); Ok(())   }
 }
 
 
 
+// reads or writes a slice of values that are converted as they are copied, `width` bytes apiece. like the other slice
+// functions, these panic if `stream` is shorter than the values.
+#[inline]
+fn read_slice_as<P>(stream: &[u8], values: &mut [P], width: usize, read: fn(&[u8]) -> P) {
+    let stream = &stream[..width * values.len()];
+
+    for (x, data) in values.iter_mut().zip(stream.chunks(width)) {
+        *x = read(data);
+    }
+}
+
+#[inline]
+fn write_slice_as<P: Copy>(stream: &mut [u8], values: &[P], width: usize, write: fn(&mut [u8], P)) {
+    for (&x, data) in values.iter().zip(stream[..width * values.len()].chunks_mut(width)) {
+        write(data, x);
+    }
+}
+
+// reads or writes a value stored as `count` 16-bit words, most significant first.
+#[inline]
+fn read_words<E: Endianness>(stream: &[u8], count: usize) -> u64 {
+    (0..count).fold(0, |value, i| value << 16 | E::read_u16(&stream[2 * i..]) as u64)
+}
+
+#[inline]
+fn write_words<E: Endianness>(stream: &mut [u8], value: u64, count: usize) {
+    for i in 0..count {
+        E::write_u16(&mut stream[2 * i..], (value >> (16 * (count - 1 - i))) as u16);
+    }
+}
+
 #[inline]
 fn extend_sign(value: u64, bytes: usize) -> i64 {
     let shift = (8 - bytes) * 8;
@@ -127,3 +392,22 @@ fn unextend_sign128(value: i128, bytes: usize) -> u128 {
     let shift = (16 - bytes) * 8;
     (value << shift) as u128 >> shift
 }
+
+// checks that `value` can be stored in `bytes` bytes without losing any bits.
+#[inline]
+fn fits_unsigned(value: u128, bytes: usize) -> Result<(), Error> {
+    match bytes {
+        16                              => Ok(()),
+        _ if value >> (bytes * 8) == 0  => Ok(()),
+        _                               => Err(Error::ValueTooWide { bytes }),
+    }
+}
+
+// checks that `value` can be stored in `bytes` bytes as a two's complement number without losing any bits.
+#[inline]
+fn fits_signed(value: i128, bytes: usize) -> Result<(), Error> {
+    match extend_sign128(unextend_sign128(value, bytes), bytes) {
+        x if x == value => Ok(()),
+        _               => Err(Error::ValueTooWide { bytes }),
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    std,
+    core,
 
     bite::BigEndian,
     bite::Endianness,
@@ -17,13 +17,13 @@ use {
 //
 macro_rules! read_primitive {
     ($conversion: ident, $primitive: ty, $primitive_length: expr, $source: expr) => ({
-        assert!($primitive_length == std::mem::size_of::<$primitive>());
+        assert!($primitive_length == core::mem::size_of::<$primitive>());
         assert!($primitive_length <= $source.len());
 
         let mut data: $primitive = 0;
 
         unsafe {
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 $source.as_ptr(),
                 &mut data as *mut $primitive as *mut u8,
                 $primitive_length);
@@ -47,9 +47,9 @@ macro_rules! write_primitive {
 
         unsafe {
             let value = $value.$conversion();
-            let bytes = std::mem::transmute::<_, [u8; $primitive_length]>(value);
+            let bytes = core::mem::transmute::<_, [u8; $primitive_length]>(value);
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 (&bytes).as_ptr(),
                 $destination.as_mut_ptr(),
                 $primitive_length);
@@ -68,7 +68,7 @@ macro_rules! read_slice {
         assert!($source.len() == $primitive_length * $destination.len());
 
         unsafe {
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 $source.as_ptr(),
                 $destination.as_mut_ptr() as *mut u8,
                 $source.len());
@@ -91,12 +91,12 @@ macro_rules! read_slice {
 //
 macro_rules! write_slice {
     ($native_encoding: expr, $primitive: ty, $primitive_length: expr, $source: expr, $destination: expr, $write: expr) => {{
-        assert!($primitive_length == std::mem::size_of::<$primitive>());
+        assert!($primitive_length == core::mem::size_of::<$primitive>());
         assert!($primitive_length * $source.len() == $destination.len());
 
         if cfg!(target_endian = $native_encoding) {
             unsafe {
-                std::ptr::copy_nonoverlapping(
+                core::ptr::copy_nonoverlapping(
                     $source.as_ptr() as *const u8,
                     $destination.as_mut_ptr(),
                     $destination.len());
@@ -150,12 +150,12 @@ impl Endianness for BigEndian {
         unsafe {
             let offset = (8 - bytes) as isize;
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 stream.as_ptr(),
                 temp.offset(offset),
                 bytes);
 
-            let value = *(temp as *const u64);
+            let value = core::ptr::read_unaligned(temp as *const u64);
 
             value.to_be()
         }
@@ -175,12 +175,12 @@ impl Endianness for BigEndian {
         unsafe {
             let offset = (16 - bytes) as isize;
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 stream.as_ptr(),
                 temp.offset(offset),
                 bytes);
 
-            let value = *(temp as *const u128);
+            let value = core::ptr::read_unaligned(temp as *const u128);
 
             value.to_be()
         }
@@ -202,10 +202,10 @@ impl Endianness for BigEndian {
             && bytes <= stream.len());
 
         unsafe {
-            let source = std::mem::transmute::<_, [u8; 8]>(value.to_be());
+            let source = core::mem::transmute::<_, [u8; 8]>(value.to_be());
             let offset = (8 - bytes) as isize;
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 source.as_ptr().offset(offset),
                 stream.as_mut_ptr(),
                 bytes);
@@ -221,10 +221,10 @@ impl Endianness for BigEndian {
             && bytes <= stream.len());
 
         unsafe {
-            let source = std::mem::transmute::<_, [u8; 16]>(value.to_be());
+            let source = core::mem::transmute::<_, [u8; 16]>(value.to_be());
             let offset = (16 - bytes) as isize;
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 source.as_ptr().offset(offset),
                 stream.as_mut_ptr(),
                 bytes);
@@ -244,6 +244,9 @@ impl Endianness for BigEndian {
     #[inline] fn write_slice_u128(stream: &mut [u8], values: &[u128]) { write_slice!("big", u128, 16, values, stream, Self::write_u128); }
 
 
+    #[inline] fn is_native() -> bool { cfg!(target_endian = "big") }
+
+
     #[inline] fn convert_slice_u16 (values: &mut [u16])  { convert_slice!(not "little", to_be, values); }
     #[inline] fn convert_slice_u32 (values: &mut [u32])  { convert_slice!(not "little", to_be, values); }
     #[inline] fn convert_slice_u64 (values: &mut [u64])  { convert_slice!(not "little", to_be, values); }
@@ -254,9 +257,9 @@ impl Endianness for BigEndian {
         if cfg!(target_endian = "little") {
             for x in values {
                 unsafe {
-                    let v = std::mem::transmute::<_, u32>(*x).to_be();
+                    let v = core::mem::transmute::<_, u32>(*x).to_be();
 
-                    *x = std::mem::transmute(v);
+                    *x = core::mem::transmute(v);
                 }
             }
         }
@@ -267,9 +270,9 @@ impl Endianness for BigEndian {
         if cfg!(target_endian = "little") {
             for x in values {
                 unsafe {
-                    let v = std::mem::transmute::<_, u64>(*x).to_be();
+                    let v = core::mem::transmute::<_, u64>(*x).to_be();
 
-                    *x = std::mem::transmute(v);
+                    *x = core::mem::transmute(v);
                 }
             }
         }
@@ -297,12 +300,12 @@ impl Endianness for LittleEndian {
         let temp     = temp.as_mut_ptr();
 
         unsafe {
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 stream.as_ptr(),
                 temp,
                 bytes);
 
-            let value = *(temp as *const u64);
+            let value = core::ptr::read_unaligned(temp as *const u64);
 
             value.to_le()
         }
@@ -320,12 +323,12 @@ impl Endianness for LittleEndian {
         let temp     = temp.as_mut_ptr();
 
         unsafe {
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 stream.as_ptr(),
                 temp,
                 bytes);
 
-            let value = *(temp as *const u128);
+            let value = core::ptr::read_unaligned(temp as *const u128);
 
             value.to_le()
         }
@@ -347,9 +350,9 @@ impl Endianness for LittleEndian {
             && bytes <= stream.len());
 
         unsafe {
-            let source = std::mem::transmute::<_, [u8; 8]>(value.to_le());
+            let source = core::mem::transmute::<_, [u8; 8]>(value.to_le());
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 source.as_ptr(),
                 stream.as_mut_ptr(),
                 bytes);
@@ -365,9 +368,9 @@ impl Endianness for LittleEndian {
             && bytes <= stream.len());
 
         unsafe {
-            let source = std::mem::transmute::<_, [u8; 16]>(value.to_le());
+            let source = core::mem::transmute::<_, [u8; 16]>(value.to_le());
 
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 source.as_ptr(),
                 stream.as_mut_ptr(),
                 bytes);
@@ -387,6 +390,9 @@ impl Endianness for LittleEndian {
     #[inline] fn write_slice_u128(stream: &mut [u8], values: &[u128]) { write_slice!("little", u128, 16, values, stream, Self::write_u128); }
 
 
+    #[inline] fn is_native() -> bool { cfg!(target_endian = "little") }
+
+
     #[inline] fn convert_slice_u16 (values: &mut [u16])  { convert_slice!(not "big", to_le, values); }
     #[inline] fn convert_slice_u32 (values: &mut [u32])  { convert_slice!(not "big", to_le, values); }
     #[inline] fn convert_slice_u64 (values: &mut [u64])  { convert_slice!(not "big", to_le, values); }
@@ -397,9 +403,9 @@ impl Endianness for LittleEndian {
         if cfg!(target_endian = "big") {
             for x in values {
                 unsafe {
-                    let v = std::mem::transmute::<_, u32>(*x).to_le();
+                    let v = core::mem::transmute::<_, u32>(*x).to_le();
 
-                    *x = std::mem::transmute(v);
+                    *x = core::mem::transmute(v);
                 }
             }
         }
@@ -410,9 +416,9 @@ impl Endianness for LittleEndian {
         if cfg!(target_endian = "big") {
             for x in values {
                 unsafe {
-                    let v = std::mem::transmute::<_, u64>(*x).to_le();
+                    let v = core::mem::transmute::<_, u64>(*x).to_le();
 
-                    *x = std::mem::transmute(v);
+                    *x = core::mem::transmute(v);
                 }
             }
         }
@@ -0,0 +1,87 @@
+use {
+    core::fmt,
+};
+
+
+
+// an error from an operation on an in-memory buffer, usable without `std`.
+//
+// with the `std` feature, this converts into a `std::io::Error` of the closest kind, so that `?` works in functions
+// that return io results.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    // the buffer ended before `needed` bytes could be read or written - only `available` remained.
+    ShortBuffer { needed: usize, available: usize },
+
+    // a value doesn't fit in the `bytes` byte field that it's being written to.
+    ValueTooWide { bytes: usize },
+
+    // a variable width field was requested with an unsupported width, in bytes.
+    InvalidWidth(usize),
+
+    // the bytes don't describe a valid value.
+    InvalidData(&'static str),
+
+    // a value can't be written in the requested form.
+    InvalidInput(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::ShortBuffer { needed, available } => write!(f, "needed {} bytes, but only {} are available", needed, available),
+            Error::ValueTooWide { bytes }            => write!(f, "value doesn't fit in {} bytes", bytes),
+            Error::InvalidWidth(bytes)               => write!(f, "{} bytes is not a supported width", bytes),
+            Error::InvalidData(message)              => write!(f, "{}", message),
+            Error::InvalidInput(message)             => write!(f, "{}", message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Error { }
+
+#[cfg(feature = "std")]
+impl From<Error> for ::std::io::Error {
+    fn from(error: Error) -> ::std::io::Error {
+        let kind = match error {
+            Error::ShortBuffer { .. }  => ::std::io::ErrorKind::UnexpectedEof,
+            Error::ValueTooWide { .. } => ::std::io::ErrorKind::InvalidInput,
+            Error::InvalidWidth(_)     => ::std::io::ErrorKind::InvalidInput,
+            Error::InvalidData(_)      => ::std::io::ErrorKind::InvalidData,
+            Error::InvalidInput(_)     => ::std::io::ErrorKind::InvalidInput,
+        };
+
+        ::std::io::Error::new(kind, error)
+    }
+}
+
+
+
+// checks that `length` bytes are available in `stream`, returning them.
+#[inline]
+pub(crate) fn check_length(stream: &[u8], length: usize) -> Result<&[u8], Error> {
+    match stream.get(..length) {
+        Some(data) => Ok(data),
+        None       => Err(Error::ShortBuffer { needed: length, available: stream.len() }),
+    }
+}
+
+#[inline]
+pub(crate) fn check_length_mut(stream: &mut [u8], length: usize) -> Result<&mut [u8], Error> {
+    let available = stream.len();
+
+    match stream.get_mut(..length) {
+        Some(data) => Ok(data),
+        None       => Err(Error::ShortBuffer { needed: length, available }),
+    }
+}
+
+// checks that `bytes` is a supported width for a variable width field of at most `maximum` bytes.
+#[inline]
+pub(crate) fn check_width(bytes: usize, maximum: usize) -> Result<usize, Error> {
+    match bytes {
+        x if x >= 1 && x <= maximum => Ok(x),
+        x                           => Err(Error::InvalidWidth(x)),
+    }
+}
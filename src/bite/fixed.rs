@@ -0,0 +1,180 @@
+use {
+    core,
+
+    bite::Endianness,
+    bite::Error,
+    bite::error::check_length,
+    bite::error::check_length_mut,
+    bite::error::check_width,
+    bite::float::power_of_two,
+};
+
+
+
+// a fixed-point number in q format - an integer `I` whose low `FRAC` bits are the fraction, so that it stands for
+// `bits / 2^FRAC`. `Q15` is `Fixed<i16, 15>`, and a q8.8 value is `Fixed<i16, 8>`. `FRAC` must be less than 1023.
+//
+// values are read and written as `bytes` wide integers with `Endianness::read_int` and `Endianness::write_int` (their
+// `uint` counterparts for unsigned `I`), so a q23 coefficient packed into a 24-bit word is a `Fixed<i32, 23>` that's
+// three bytes wide. `bytes` must be no wider than `I` - like the `Endianness` functions, `read*` and `write*` panic if
+// it's wider or `stream` is too short, and `try_*` return an error instead.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fixed<I, const FRAC: u32> {
+    bits: I,
+}
+
+pub type Q15 = Fixed<i16, 15>;
+pub type Q31 = Fixed<i32, 31>;
+
+// an integer that can hold the bits of a `Fixed`.
+pub trait FixedBits: Copy {
+    // the width of the integer in bytes, and the range of values that it holds.
+    const BYTES:   usize;
+    const MINIMUM: i128;
+    const MAXIMUM: i128;
+
+    fn to_i128(self) -> i128;
+
+    // truncates `value` to the width of the integer.
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! fixed_bits {
+    ($($type: ident),*) => {
+        $(
+            impl FixedBits for $type {
+                const BYTES:   usize = core::mem::size_of::<$type>();
+                const MINIMUM: i128  = $type::MIN as i128;
+                const MAXIMUM: i128  = $type::MAX as i128;
+
+                #[inline] fn to_i128(self) -> i128          { self as i128   }
+                #[inline] fn from_i128(value: i128) -> Self { value as $type }
+            }
+        )*
+    }
+}
+
+fixed_bits!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<I: FixedBits, const FRAC: u32> Fixed<I, FRAC> {
+    #[inline] pub fn from_bits(bits: I) -> Self { Fixed { bits } }
+    #[inline] pub fn to_bits(self) -> I         { self.bits      }
+
+    #[inline] pub fn to_f32(self) -> f32        { self.bits.to_i128() as f32 * power_of_two(-(FRAC as i32)) as f32 }
+    #[inline] pub fn to_f64(self) -> f64        { self.bits.to_i128() as f64 * power_of_two(-(FRAC as i32))        }
+
+    #[inline] pub fn from_f32(value: f32) -> Self { Self::from_f64(value as f64) }
+
+    // the nearest value to `value`, with ties to even. values outside of the range of the format saturate to its
+    // smallest or largest value, and nan becomes zero.
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        let bits = round(value * power_of_two(FRAC as i32));
+        let bits = core::cmp::max(I::MINIMUM, core::cmp::min(I::MAXIMUM, bits));
+
+        Fixed { bits: I::from_i128(bits) }
+    }
+
+
+
+    #[inline]
+    pub fn read<E: Endianness>(stream: &[u8], bytes: usize) -> Self {
+        assert!(bytes <= I::BYTES);
+
+        let bits = match I::MINIMUM {
+            0 => E::read_uint(stream, bytes) as i128,
+            _ => E::read_int(stream, bytes) as i128,
+        };
+
+        Fixed { bits: I::from_i128(bits) }
+    }
+
+    #[inline]
+    pub fn write<E: Endianness>(self, stream: &mut [u8], bytes: usize) {
+        assert!(bytes <= I::BYTES);
+
+        match I::MINIMUM {
+            0 => E::write_uint(stream, self.bits.to_i128() as u64, bytes),
+            _ => E::write_int(stream, self.bits.to_i128() as i64, bytes),
+        }
+    }
+
+    #[inline]
+    pub fn read_slice<E: Endianness>(stream: &[u8], values: &mut [Self], bytes: usize) {
+        assert!(bytes <= I::BYTES);
+
+        let stream = &stream[..bytes * values.len()];
+
+        for (value, data) in values.iter_mut().zip(stream.chunks(bytes)) {
+            *value = Self::read::<E>(data, bytes);
+        }
+    }
+
+    #[inline]
+    pub fn write_slice<E: Endianness>(stream: &mut [u8], values: &[Self], bytes: usize) {
+        assert!(bytes <= I::BYTES);
+
+        let stream = &mut stream[..bytes * values.len()];
+
+        for (value, data) in values.iter().zip(stream.chunks_mut(bytes)) {
+            value.write::<E>(data, bytes);
+        }
+    }
+
+
+
+    #[inline]
+    pub fn try_read<E: Endianness>(stream: &[u8], bytes: usize) -> Result<Self, Error> {
+        Ok(Self::read::<E>(check_length(stream, check_width(bytes, I::BYTES)?)?, bytes))
+    }
+
+    // fails with `Error::ValueTooWide` if `bytes` is narrower than `I` and the value doesn't fit in it.
+    #[inline]
+    pub fn try_write<E: Endianness>(self, stream: &mut [u8], bytes: usize) -> Result<(), Error> {
+        check_width(bytes, I::BYTES)?;
+
+        match I::MINIMUM {
+            0 => E::try_write_uint(stream, self.bits.to_i128() as u64, bytes),
+            _ => E::try_write_int(stream, self.bits.to_i128() as i64, bytes),
+        }
+    }
+
+    #[inline]
+    pub fn try_read_slice<E: Endianness>(stream: &[u8], values: &mut [Self], bytes: usize) -> Result<(), Error> {
+        let length = check_width(bytes, I::BYTES)? * values.len();
+
+        Self::read_slice::<E>(check_length(stream, length)?, values, bytes);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn try_write_slice<E: Endianness>(stream: &mut [u8], values: &[Self], bytes: usize) -> Result<(), Error> {
+        let length = check_width(bytes, I::BYTES)? * values.len();
+        let stream = check_length_mut(stream, length)?;
+
+        for (value, data) in values.iter().zip(stream.chunks_mut(bytes)) {
+            value.try_write::<E>(data, bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+
+// rounds `value` to the nearest integer, ties to even. nan rounds to zero, and values beyond the range of an `i128`
+// saturate.
+#[inline]
+fn round(value: f64) -> i128 {
+    let whole    = value as i128;
+    let fraction = value - whole as f64;
+    let odd      = whole & 1 != 0;
+
+    if fraction > 0.5 || (fraction == 0.5 && odd) {
+        whole.saturating_add(1)
+    } else if fraction < -0.5 || (fraction == -0.5 && odd) {
+        whole.saturating_sub(1)
+    } else {
+        whole
+    }
+}
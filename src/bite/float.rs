@@ -0,0 +1,326 @@
+// conversions between `f32` and the 16-bit float formats - ieee 754 binary16 (`f16`) and bfloat16 (`bf16`) - between
+// `f64` and the x87 80-bit extended format (`f80`), and between the native floats and the non-ieee ibm hexadecimal and
+// vax formats.
+//
+// narrowing rounds to nearest, ties to even, overflowing to infinity and underflowing through the subnormals to zero.
+// nan payloads keep their most significant bits, and a nan whose remaining payload would be empty is made quiet so
+// that it doesn't turn into an infinity.
+
+
+
+#[inline]
+pub(crate) fn f16_to_f32(value: u16) -> f32 {
+    let sign     = ((value & 0x8000) as u32) << 16;
+    let exponent = ((value >> 10) & 0x1f) as u32;
+    let mantissa = (value & 0x3ff) as u32;
+
+    let bits = match (exponent, mantissa) {
+        (0, 0)    => sign,
+        (0, _)    => {
+            // subnormal - shift the mantissa up until its leading bit is the implicit bit of an f32.
+            let shift = mantissa.leading_zeros() - 21;
+            sign | ((113 - shift) << 23) | (((mantissa << shift) & 0x3ff) << 13)
+        },
+        (0x1f, _) => sign | 0x7f80_0000 | (mantissa << 13),
+        (_, _)    => sign | ((exponent + 112) << 23) | (mantissa << 13),
+    };
+
+    f32::from_bits(bits)
+}
+
+#[inline]
+pub(crate) fn f32_to_f16(value: f32) -> u16 {
+    let bits     = value.to_bits();
+    let sign     = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        return match mantissa >> 13 {
+            _ if mantissa == 0 => sign | 0x7c00,
+            0                  => sign | 0x7e00,
+            x                  => sign | 0x7c00 | x as u16,
+        };
+    }
+
+    // the exponent, rebiased for an f16.
+    let exponent = exponent - 127 + 15;
+
+    match exponent {
+        x if x >= 0x1f => sign | 0x7c00,
+        x if x > 0     => sign | round((((x as u32) << 23) | mantissa) as u128, 13) as u16,
+
+        // too small to be a normal f16 - keep the implicit bit and round into a subnormal. anything less than half of
+        // the smallest subnormal rounds to zero, including every f32 subnormal.
+        x if x > -11   => sign | round((mantissa | 0x80_0000) as u128, (14 - x) as u32) as u16,
+        _              => sign,
+    }
+}
+
+#[inline]
+pub(crate) fn bf16_to_f32(value: u16) -> f32 {
+    f32::from_bits((value as u32) << 16)
+}
+
+#[inline]
+pub(crate) fn f32_to_bf16(value: f32) -> u16 {
+    let bits = value.to_bits();
+
+    match bits & 0x7fff_ffff {
+        x if x > 0x7f80_0000 => (bits >> 16) as u16 | if x & 0x7f_0000 == 0 { 0x40 } else { 0 },
+        _                    => round(bits as u128, 16) as u16,
+    }
+}
+
+// an `f80` is held as the low 80 bits of a `u128` - the sign and a 15-bit exponent, then a 64-bit significand whose
+// top bit is the integer bit.
+//
+// returns the value and whether it had to be rounded. the integer bit is taken at face value, so unnormals (a clear
+// integer bit with a nonzero exponent) and pseudo-denormals (a set integer bit with a zero exponent) are decoded as
+// the numbers they describe, as the 8087 did. pseudo-infinities and pseudo-nans, which have the largest exponent and a
+// clear integer bit, have no meaning and are decoded as nan.
+#[inline]
+pub(crate) fn f80_to_f64(value: u128) -> (f64, bool) {
+    let sign        = ((value >> 79) as u64 & 1) << 63;
+    let exponent    = (value >> 64) as i32 & 0x7fff;
+    let significand = value as u64;
+
+    if exponent == 0x7fff {
+        let fraction = significand & 0x7fff_ffff_ffff_ffff;
+
+        return match fraction >> 11 {
+            _ if significand >> 63 == 0 => (f64::from_bits(sign | 0x7ff8_0000_0000_0000), true),
+            _ if fraction == 0          => (f64::from_bits(sign | 0x7ff0_0000_0000_0000), false),
+            0                           => (f64::from_bits(sign | 0x7ff8_0000_0000_0000), true),
+            x                           => (f64::from_bits(sign | 0x7ff0_0000_0000_0000 | x), fraction & 0x7ff != 0),
+        };
+    }
+
+    if significand == 0 {
+        return (f64::from_bits(sign), false);
+    }
+
+    // normalize the significand so that its top bit is set, and find the f64 exponent of that bit. a zero exponent
+    // has the same scale as an exponent of one - the integer bit is what tells denormals apart.
+    let shift    = significand.leading_zeros();
+    let exponent = exponent.max(1) - 16383 + 1023 - shift as i32;
+    let value    = (significand << shift) as u128;
+
+    // the bits above the rounded significand, and how many bits of the significand don't fit.
+    let (bits, shift) = match exponent {
+        x if x >= 0x7ff => return (f64::from_bits(sign | 0x7ff0_0000_0000_0000), true),
+        x if x > 0      => (((x - 1) as u64) << 52, 11),
+
+        // too small for a normal f64 - round into a subnormal, or to zero if the value is less than half of the
+        // smallest subnormal.
+        x if x > -53    => (0, (12 - x) as u32),
+        _               => return (f64::from_bits(sign), true),
+    };
+
+    (f64::from_bits(sign | (bits + round(value, shift) as u64)), value.trailing_zeros() < shift)
+}
+
+#[inline]
+pub(crate) fn f64_to_f80(value: f64) -> u128 {
+    let bits     = value.to_bits();
+    let sign     = ((bits >> 63) as u128) << 79;
+    let exponent = (bits >> 52) & 0x7ff;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+
+    let (exponent, significand) = match exponent {
+        0x7ff              => (0x7fff, 1 << 63 | fraction << 11),
+        0 if fraction == 0 => (0, 0),
+
+        // subnormal - every f64 subnormal is a normal f80, so shift the fraction up to the integer bit.
+        0                  => (15372 - fraction.leading_zeros() as u64, fraction << fraction.leading_zeros()),
+        x                  => (x + 15360, 1 << 63 | fraction << 11),
+    };
+
+    sign | (exponent as u128) << 64 | significand as u128
+}
+
+// ibm system/360 floats are a sign, a 7-bit base-16 exponent in excess 64 and a 24-bit (single) or 56-bit (double)
+// fraction, with no hidden bit, infinities or nans. the whole ibm single range is finite in an `f64`, so decoding an
+// ibm single rounds once, when it is narrowed to an `f32`.
+#[inline]
+pub(crate) fn ibm_to_f32(value: u32) -> f32 {
+    from_ibm(value as u64, 24) as f32
+}
+
+#[inline]
+pub(crate) fn ibm_to_f64(value: u64) -> f64 {
+    from_ibm(value, 56)
+}
+
+#[inline]
+pub(crate) fn f32_to_ibm(value: f32) -> u32 {
+    to_ibm(value as f64, 24) as u32
+}
+
+#[inline]
+pub(crate) fn f64_to_ibm(value: f64) -> u64 {
+    to_ibm(value, 56)
+}
+
+// vax floats are a sign, an exponent and a fraction with a hidden bit, laid out like ieee floats but with values of
+// `0.1f * 2^(e - bias)`. f has an 8-bit exponent in excess 128 and a 23-bit fraction, d an 8-bit exponent and a
+// 55-bit fraction, and g an 11-bit exponent in excess 1024 and a 52-bit fraction. a zero exponent is zero, or a
+// reserved operand when the sign is set - there are no subnormals, infinities or nans.
+#[inline] pub(crate) fn vax_f_to_f32(value: u32) -> f32 { from_vax(value as u64, 8, 23, 129) as f32 }
+#[inline] pub(crate) fn vax_d_to_f64(value: u64) -> f64 { from_vax(value, 8, 55, 129)            }
+#[inline] pub(crate) fn vax_g_to_f64(value: u64) -> f64 { from_vax(value, 11, 52, 1025)          }
+
+#[inline] pub(crate) fn f32_to_vax_f(value: f32) -> u32 { to_vax(value as f64, 8, 23, 129) as u32 }
+#[inline] pub(crate) fn f64_to_vax_d(value: f64) -> u64 { to_vax(value, 8, 55, 129)               }
+#[inline] pub(crate) fn f64_to_vax_g(value: f64) -> u64 { to_vax(value, 11, 52, 1025)             }
+
+#[inline]
+fn from_ibm(value: u64, fraction_bits: u32) -> f64 {
+    let exponent  = ((value >> fraction_bits) & 0x7f) as i32;
+    let fraction  = value & ((1 << fraction_bits) - 1);
+    let magnitude = fraction as f64 * power_of_two(4 * (exponent - 64) - fraction_bits as i32);
+
+    match value >> (fraction_bits + 7) {
+        0 => magnitude,
+        _ => -magnitude,
+    }
+}
+
+// encodes `value` as a normalized ibm float, rounding the fraction to nearest, ties to even. infinities, nans and
+// values too large for the format saturate to the largest magnitude of the same sign, and values too small underflow
+// to zero.
+#[inline]
+fn to_ibm(value: f64, fraction_bits: u32) -> u64 {
+    let sign    = (value.to_bits() >> 63) << (fraction_bits + 7);
+    let largest = sign | 0x7f << fraction_bits | ((1 << fraction_bits) - 1);
+
+    if !value.is_finite() {
+        return largest;
+    }
+
+    if value == 0.0 {
+        return sign;
+    }
+
+    // find the power of 16 that puts the leading hex digit of the value at the top of the fraction.
+    let (significand, exponent) = decompose(value);
+    let mut power               = (exponent + 56).div_euclid(4);
+
+    let mut fraction = match 4 * power - fraction_bits as i32 - exponent {
+        x if x <= 0  => significand << -x,
+        x if x < 128 => round(significand as u128, x as u32) as u64,
+        _            => 0,
+    };
+
+    if fraction >> fraction_bits != 0 {
+        fraction >>= 4;
+        power     += 1;
+    }
+
+    match power + 64 {
+        _ if fraction == 0 => sign,
+        x if x > 0x7f      => largest,
+        x if x < 0         => sign,
+        x                  => sign | (x as u64) << fraction_bits | fraction,
+    }
+}
+
+#[inline]
+fn from_vax(value: u64, exponent_bits: u32, fraction_bits: u32, bias: i32) -> f64 {
+    let sign     = (value >> (exponent_bits + fraction_bits)) & 1;
+    let exponent = ((value >> fraction_bits) & ((1 << exponent_bits) - 1)) as i32;
+    let fraction = value & ((1 << fraction_bits) - 1);
+
+    // the significand is rounded to 53 bits before it is scaled, which is exact for every format but g - and g's
+    // significand already fits.
+    let magnitude = || (fraction | 1 << fraction_bits) as f64 * power_of_two(-(fraction_bits as i32)) * power_of_two(exponent - bias);
+
+    match (sign, exponent) {
+        (0, 0) => 0.0,
+        (_, 0) => f64::NAN,
+        (0, _) => magnitude(),
+        (_, _) => -magnitude(),
+    }
+}
+
+// encodes `value` as a vax float, rounding the fraction to nearest, ties to even. nans are written as the reserved
+// operand, infinities and values too large for the format saturate to the largest magnitude of the same sign, and
+// values too small underflow to zero, as they do on a vax.
+#[inline]
+fn to_vax(value: f64, exponent_bits: u32, fraction_bits: u32, bias: i32) -> u64 {
+    let sign    = (value.to_bits() >> 63) << (exponent_bits + fraction_bits);
+    let maximum = (1 << exponent_bits) - 1;
+    let largest = sign | (maximum as u64) << fraction_bits | ((1 << fraction_bits) - 1);
+
+    if value.is_nan() {
+        return 1 << (exponent_bits + fraction_bits);
+    }
+
+    if value.is_infinite() {
+        return largest;
+    }
+
+    // there is no negative zero - its encoding is the reserved operand.
+    if value == 0.0 {
+        return 0;
+    }
+
+    let (significand, exponent) = decompose(value);
+    let mut exponent            = exponent + 52;
+
+    let mut significand = match fraction_bits {
+        x if x >= 52 => significand << (x - 52),
+        x            => round(significand as u128, 52 - x) as u64,
+    };
+
+    if significand >> (fraction_bits + 1) != 0 {
+        significand >>= 1;
+        exponent     += 1;
+    }
+
+    match exponent + bias {
+        x if x > maximum => largest,
+        x if x < 1       => 0,
+        x                => sign | (x as u64) << fraction_bits | (significand & ((1 << fraction_bits) - 1)),
+    }
+}
+
+// splits a finite, nonzero `value` into a 53-bit significand with its top bit set, and the power of two that it is
+// scaled by.
+#[inline]
+fn decompose(value: f64) -> (u64, i32) {
+    let bits     = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+
+    match exponent {
+        0 => {
+            let shift = fraction.leading_zeros() - 11;
+            (fraction << shift, -1074 - shift as i32)
+        },
+        x => (fraction | 1 << 52, x - 1075),
+    }
+}
+
+// 2 to the power of `exponent`, which must be within the range of `f64` - subnormals included.
+#[inline]
+pub(crate) fn power_of_two(exponent: i32) -> f64 {
+    match exponent {
+        x if x >= -1022 => f64::from_bits(((x + 1023) as u64) << 52),
+        x               => f64::from_bits(1 << (x + 1074)),
+    }
+}
+
+// drops the low `shift` bits of `value`, rounding to nearest, ties to even. a carry out of the mantissa moves into the
+// exponent, which rounds the largest finite values up to infinity and the largest subnormals up to normal numbers.
+#[inline]
+fn round(value: u128, shift: u32) -> u128 {
+    let half      = 1 << (shift - 1);
+    let remainder = value & ((half << 1) - 1);
+    let value     = value >> shift;
+
+    match remainder {
+        x if x > half || (x == half && value & 1 != 0) => value + 1,
+        _                                              => value,
+    }
+}
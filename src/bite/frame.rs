@@ -0,0 +1,166 @@
+use {
+    bite::Endianness,
+    bite::Error,
+    bite::Leb128,
+    bite::error::check_length,
+    bite::error::check_length_mut,
+};
+
+
+
+// the longest possible length prefix, in bytes - a u64 in leb128.
+pub(crate) const MAXIMUM_PREFIX_LENGTH: usize = 10;
+
+
+
+// how the length in front of a frame is encoded.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FramePrefix {
+    U8,
+    U16,
+    U24,
+    U32,
+    U64,
+    Leb128,
+}
+
+impl FramePrefix {
+    // the width of the prefix in bytes, or `None` for leb128, whose width depends on the length.
+    #[inline]
+    pub fn width(&self) -> Option<usize> {
+        match *self {
+            FramePrefix::U8     => Some(1),
+            FramePrefix::U16    => Some(2),
+            FramePrefix::U24    => Some(3),
+            FramePrefix::U32    => Some(4),
+            FramePrefix::U64    => Some(8),
+            FramePrefix::Leb128 => None,
+        }
+    }
+
+    // the largest length that the prefix can hold.
+    #[inline]
+    pub fn maximum(&self) -> u64 {
+        match *self {
+            FramePrefix::U8     => 0xff,
+            FramePrefix::U16    => 0xffff,
+            FramePrefix::U24    => 0xff_ffff,
+            FramePrefix::U32    => 0xffff_ffff,
+            FramePrefix::U64    => u64::MAX,
+            FramePrefix::Leb128 => u64::MAX,
+        }
+    }
+}
+
+
+
+// the layout of a frame - a length prefix, followed by a payload of that many bytes.
+//
+// the length in the prefix is the length of the payload, plus the length of the prefix itself when `includes_prefix`
+// is set, plus `adjustment`. `FrameFormat::U32` is the format used by `read_framed` and `write_framed`.
+//
+// writing a payload whose length can't be represented in the prefix fails with `Error::ValueTooWide`, and reading a
+// prefix that describes a negative or oversized payload fails with `Error::InvalidData`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FrameFormat {
+    prefix:          FramePrefix,
+    includes_prefix: bool,
+    adjustment:      i64,
+}
+
+impl FrameFormat {
+    pub const U8:     FrameFormat = FrameFormat::new(FramePrefix::U8);
+    pub const U16:    FrameFormat = FrameFormat::new(FramePrefix::U16);
+    pub const U24:    FrameFormat = FrameFormat::new(FramePrefix::U24);
+    pub const U32:    FrameFormat = FrameFormat::new(FramePrefix::U32);
+    pub const U64:    FrameFormat = FrameFormat::new(FramePrefix::U64);
+    pub const LEB128: FrameFormat = FrameFormat::new(FramePrefix::Leb128);
+
+    #[inline] pub const fn new                 (prefix: FramePrefix)          -> FrameFormat { FrameFormat { prefix, includes_prefix: false, adjustment: 0 } }
+    #[inline] pub const fn with_includes_prefix(self, includes_prefix: bool) -> FrameFormat { FrameFormat { includes_prefix, ..self }                     }
+    #[inline] pub const fn with_adjustment     (self, adjustment: i64)       -> FrameFormat { FrameFormat { adjustment, ..self }                          }
+
+    #[inline] pub fn prefix                    (&self)                        -> FramePrefix { self.prefix                                                   }
+    #[inline] pub fn includes_prefix           (&self)                        -> bool        { self.includes_prefix                                          }
+    #[inline] pub fn adjustment                (&self)                        -> i64         { self.adjustment                                               }
+
+    // the number of prefix bytes needed, given the first bytes of the prefix in `stream`. reading a prefix from a
+    // stream repeats this until `stream` holds that many bytes.
+    #[inline]
+    pub fn prefix_length(&self, stream: &[u8]) -> usize {
+        match (self.prefix.width(), stream.last()) {
+            (Some(width), _)                                                          => width,
+            (None, Some(&x)) if x & 0x80 != 0 && stream.len() < MAXIMUM_PREFIX_LENGTH => stream.len() + 1,
+            (None, Some(_))                                                           => stream.len(),
+            (None, None)                                                              => 1,
+        }
+    }
+
+    // decodes the prefix at the start of `stream`, returning the length of the payload and the length of the prefix.
+    pub fn read_prefix<E: Endianness>(&self, stream: &[u8], maximum: usize) -> Result<(usize, usize), Error> {
+        let (value, width) = match self.prefix.width() {
+            Some(width) => (E::read_uint(check_length(stream, width)?, width), width),
+            None        => Leb128::read_u64(stream)?,
+        };
+
+        let length = value as i128 - self.adjustment as i128 - self.prefix_bytes(width) as i128;
+
+        match length {
+            x if x < 0               => Err(Error::InvalidData("frame length is shorter than its prefix adjustment")),
+            x if x > maximum as i128 => Err(Error::InvalidData("frame exceeds the maximum length")),
+            x                        => Ok((x as usize, width)),
+        }
+    }
+
+    // encodes the prefix for a payload of `length` bytes at the start of `stream`, returning the length of the prefix.
+    pub fn write_prefix<E: Endianness>(&self, stream: &mut [u8], length: usize) -> Result<usize, Error> {
+        let (value, width) = self.encode_length(length)?;
+        let data           = check_length_mut(stream, width)?;
+
+        match self.prefix {
+            FramePrefix::Leb128 => { Leb128::write_u64(data, value); },
+            _                   => E::write_uint(data, value, width),
+        }
+
+        Ok(width)
+    }
+
+    // the value held in the prefix for a payload of `length` bytes, and the width of the prefix.
+    fn encode_length(&self, length: usize) -> Result<(u64, usize), Error> {
+        let value = |width: usize| length as i128 + self.prefix_bytes(width) as i128 + self.adjustment as i128;
+
+        let (value, width) = match self.prefix.width() {
+            Some(width) => (value(width), width),
+
+            // the width of a leb128 prefix that counts itself depends on its own value - find the width that encodes
+            // exactly that many bytes.
+            None => {
+                let width = (1..MAXIMUM_PREFIX_LENGTH + 1)
+                    .find(|&x| value(x) >= 0 && value(x) <= u64::MAX as i128 && Leb128::size_unsigned(value(x) as u128) == x)
+                    .unwrap_or(MAXIMUM_PREFIX_LENGTH);
+
+                (value(width), width)
+            },
+        };
+
+        match value {
+            x if x < 0 || x > self.prefix.maximum() as i128 => Err(Error::ValueTooWide { bytes: width }),
+            x                                                => Ok((x as u64, width)),
+        }
+    }
+
+    #[inline]
+    fn prefix_bytes(&self, width: usize) -> usize {
+        match self.includes_prefix {
+            true  => width,
+            false => 0,
+        }
+    }
+}
+
+impl Default for FrameFormat {
+    #[inline]
+    fn default() -> FrameFormat {
+        FrameFormat::U32
+    }
+}
@@ -0,0 +1,695 @@
+use {
+    std,
+    std::future::Future,
+    std::marker::PhantomData,
+    std::pin::Pin,
+    std::task::Context,
+    std::task::Poll,
+
+    bite::Endianness,
+    bite::Error,
+    bite::FrameFormat,
+    bite::LittleEndian,
+    bite::frame::MAXIMUM_PREFIX_LENGTH,
+    bite::leb128::MAXIMUM_LENGTH,
+    bite::read::as_mutable_data,
+    bite::write::STAGING_LENGTH,
+    bite::write::as_data,
+};
+
+
+
+// the futures returned by the async extension traits in `bite::tokio` and `bite::futures`.
+//
+// these are written by hand rather than with `async` blocks so that they can be named, and so that each one is
+// `Send` exactly when the stream that it borrows is. the runtime parameter `P` selects which runtime's io traits the
+// future polls - `bite::tokio::Tokio` or `bite::futures::Futures`.
+
+
+
+// polls a stream of type `S` for bytes, with the io traits of one async runtime.
+pub trait PollRead<S: ?Sized> {
+    fn poll_read(stream: Pin<&mut S>, context: &mut Context, buffer: &mut [u8]) -> Poll<Result<usize, std::io::Error>>;
+}
+
+// polls a stream of type `S` to accept bytes, with the io traits of one async runtime.
+pub trait PollWrite<S: ?Sized> {
+    fn poll_write(stream: Pin<&mut S>, context: &mut Context, buffer: &[u8]) -> Poll<Result<usize, std::io::Error>>;
+}
+
+
+
+// reads a fixed number of bytes and converts them into a value.
+#[derive(Debug)]
+pub struct ReadValue<'a, S: ?Sized + 'a, P, T> {
+    stream:  &'a mut S,
+    data:    [u8; MAXIMUM_LENGTH],
+    length:  usize,
+    filled:  usize,
+    convert: fn(&[u8]) -> T,
+    runtime: PhantomData<fn() -> P>,
+}
+
+impl<'a, S: ?Sized + 'a, P, T> ReadValue<'a, S, P, T> {
+    #[inline]
+    pub(crate) fn new(stream: &'a mut S, length: usize, convert: fn(&[u8]) -> T) -> ReadValue<'a, S, P, T> {
+        assert!(length <= MAXIMUM_LENGTH);
+        ReadValue { stream, data: [0; MAXIMUM_LENGTH], length, filled: 0, convert, runtime: PhantomData }
+    }
+}
+
+impl<'a, S, P, T> Future for ReadValue<'a, S, P, T> where S: Unpin + ?Sized, P: PollRead<S> {
+    type Output = Result<T, std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        match poll_fill::<S, P>(this.stream, context, &mut this.data[..this.length], &mut this.filled) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok((this.convert)(&this.data[..this.length]))),
+            Poll::Ready(Err(x)) => Poll::Ready(Err(x)),
+            Poll::Pending       => Poll::Pending,
+        }
+    }
+}
+
+
+
+// reads directly into the memory of a slice of values, then converts them into the native encoding in place.
+#[derive(Debug)]
+pub struct ReadSlice<'a, S: ?Sized + 'a, P, T: 'a> {
+    stream:  &'a mut S,
+    values:  &'a mut [T],
+    filled:  usize,
+    convert: fn(&mut [T]),
+    runtime: PhantomData<fn() -> P>,
+}
+
+impl<'a, S: ?Sized + 'a, P, T: 'a> ReadSlice<'a, S, P, T> {
+    #[inline]
+    pub(crate) fn new(stream: &'a mut S, values: &'a mut [T], convert: fn(&mut [T])) -> ReadSlice<'a, S, P, T> {
+        ReadSlice { stream, values, filled: 0, convert, runtime: PhantomData }
+    }
+}
+
+impl<'a, S, P, T> Future for ReadSlice<'a, S, P, T> where S: Unpin + ?Sized, P: PollRead<S>, T: Copy {
+    type Output = Result<(), std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let data = unsafe { as_mutable_data(this.values) };
+
+        match poll_fill::<S, P>(this.stream, context, data, &mut this.filled) {
+            Poll::Ready(Ok(())) => { (this.convert)(this.values); Poll::Ready(Ok(())) },
+            Poll::Ready(Err(x)) => Poll::Ready(Err(x)),
+            Poll::Pending       => Poll::Pending,
+        }
+    }
+}
+
+
+
+// decodes a leb128 value, returning it along with its length - one of the `Leb128::read_*` functions.
+type Decode<T> = fn(&[u8]) -> Result<(T, usize), Error>;
+
+// reads a leb128 value one byte at a time, so that no bytes past its end are consumed.
+#[derive(Debug)]
+pub struct ReadLeb128<'a, S: ?Sized + 'a, P, T> {
+    stream:  &'a mut S,
+    data:    [u8; MAXIMUM_LENGTH],
+    filled:  usize,
+    maximum: usize,
+    decode:  Decode<T>,
+    runtime: PhantomData<fn() -> P>,
+}
+
+impl<'a, S: ?Sized + 'a, P, T> ReadLeb128<'a, S, P, T> {
+    #[inline]
+    pub(crate) fn new(stream: &'a mut S, decode: Decode<T>) -> ReadLeb128<'a, S, P, T> {
+        let maximum = (std::mem::size_of::<T>() * 8).div_ceil(7);
+        ReadLeb128 { stream, data: [0; MAXIMUM_LENGTH], filled: 0, maximum, decode, runtime: PhantomData }
+    }
+}
+
+impl<'a, S, P, T> Future for ReadLeb128<'a, S, P, T> where S: Unpin + ?Sized, P: PollRead<S> {
+    type Output = Result<T, std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        // an encoding that runs past `maximum` bytes is left for `decode` to reject as overlong.
+        while this.filled == 0 || (this.data[this.filled - 1] & 0x80 != 0 && this.filled < this.maximum) {
+            let end = this.filled + 1;
+
+            match poll_fill::<S, P>(this.stream, context, &mut this.data[..end], &mut this.filled) {
+                Poll::Ready(Ok(())) => { },
+                Poll::Ready(Err(x)) => return Poll::Ready(Err(x)),
+                Poll::Pending       => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready((this.decode)(&this.data[..this.filled]).map(|x| x.0).map_err(|x| x.into()))
+    }
+}
+
+
+
+// decodes a length prefix in some endianness, as `FrameFormat::read_prefix` does.
+type ReadPrefix = fn(&FrameFormat, &[u8], usize) -> Result<(usize, usize), Error>;
+
+// reads a frame - a length prefix laid out as a `FrameFormat` describes, followed by that many bytes.
+//
+// this isn't cancellation safe: if the future is dropped part of the way through a frame, the bytes that it has
+// consumed are lost. use a `FrameReader` where reads may be cancelled, such as in a `select!` loop.
+#[derive(Debug)]
+pub struct ReadFramed<'a, S: ?Sized + 'a, P> {
+    stream:  &'a mut S,
+    format:  FrameFormat,
+    maximum: usize,
+    state:   FrameState,
+    read:    ReadPrefix,
+    runtime: PhantomData<fn() -> P>,
+}
+
+impl<'a, S: ?Sized + 'a, P> ReadFramed<'a, S, P> {
+    #[inline]
+    pub(crate) fn new(stream: &'a mut S, format: FrameFormat, maximum: usize, read: ReadPrefix) -> ReadFramed<'a, S, P> {
+        ReadFramed { stream, format, maximum, state: FrameState::new(), read, runtime: PhantomData }
+    }
+}
+
+impl<'a, S, P> Future for ReadFramed<'a, S, P> where S: Unpin + ?Sized, P: PollRead<S> {
+    type Output = Result<Vec<u8>, std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        this.state.poll::<S, P>(this.stream, context, &this.format, this.maximum, this.read)
+    }
+}
+
+
+
+// reads frames from a stream that it owns, keeping the progress of a partially read frame between reads.
+//
+// dropping the future returned by `read_frame` before it completes is safe - nothing that was read is lost, and the
+// next call to `read_frame` (or `poll_frame`) carries on from where it stopped. this makes it suitable for use in
+// `select!` loops and with timeouts. `into_inner` discards any partially read frame.
+//
+// the encoding of the length can't be inferred, so give it with the type - `let reader: FrameReader<_, BigEndian>`.
+#[derive(Debug)]
+pub struct FrameReader<S, P, E: Endianness = LittleEndian> {
+    stream:  S,
+    format:  FrameFormat,
+    maximum: usize,
+    state:   FrameState,
+    runtime: PhantomData<fn() -> (P, E)>,
+}
+
+impl<S, P, E: Endianness> FrameReader<S, P, E> {
+    #[inline] pub fn new         (stream: S)                                      -> FrameReader<S, P, E> { FrameReader::with_maximum(stream, usize::MAX)                                         }
+    #[inline] pub fn with_maximum(stream: S, maximum: usize)                      -> FrameReader<S, P, E> { FrameReader::with_format(stream, FrameFormat::U32, maximum)                           }
+    #[inline] pub fn with_format (stream: S, format: FrameFormat, maximum: usize) -> FrameReader<S, P, E> { FrameReader { stream, format, maximum, state: FrameState::new(), runtime: PhantomData } }
+
+    #[inline] pub fn format      (&self)                                          -> FrameFormat          { self.format                                                                           }
+    #[inline] pub fn maximum     (&self)                                          -> usize                { self.maximum                                                                          }
+    #[inline] pub fn get_ref     (&self)                                          -> &S                   { &self.stream                                                                          }
+    #[inline] pub fn get_mut     (&mut self)                                      -> &mut S               { &mut self.stream                                                                      }
+    #[inline] pub fn into_inner  (self)                                           -> S                    { self.stream                                                                           }
+
+    // whether part of a frame has been read, and is waiting on the rest.
+    #[inline]
+    pub fn is_partial(&self) -> bool {
+        self.state.filled != 0 || self.state.length.is_some()
+    }
+
+    #[inline]
+    pub fn read_frame(&mut self) -> ReadFrame<'_, S, P, E> where S: Unpin, P: PollRead<S> {
+        ReadFrame { reader: self }
+    }
+
+    #[inline]
+    pub fn poll_frame(&mut self, context: &mut Context) -> Poll<Result<Vec<u8>, std::io::Error>> where S: Unpin, P: PollRead<S> {
+        self.state.poll::<S, P>(&mut self.stream, context, &self.format, self.maximum, FrameFormat::read_prefix::<E>)
+    }
+}
+
+// reads the next frame from a `FrameReader`.
+#[derive(Debug)]
+pub struct ReadFrame<'a, S: 'a, P: 'a, E: Endianness + 'a> {
+    reader: &'a mut FrameReader<S, P, E>,
+}
+
+impl<'a, S, P, E> Future for ReadFrame<'a, S, P, E> where S: Unpin, P: PollRead<S>, E: Endianness {
+    type Output = Result<Vec<u8>, std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        self.get_mut().reader.poll_frame(context)
+    }
+}
+
+
+
+// the progress of reading one frame.
+//
+// the payload buffer grows as data arrives rather than being allocated up front, so a corrupt length can't force a
+// large allocation on its own.
+#[derive(Debug)]
+struct FrameState {
+    header: [u8; MAXIMUM_PREFIX_LENGTH],
+    filled: usize,
+    length: Option<usize>,
+    data:   Vec<u8>,
+}
+
+impl FrameState {
+    #[inline]
+    fn new() -> FrameState {
+        FrameState { header: [0; MAXIMUM_PREFIX_LENGTH], filled: 0, length: None, data: Vec::new() }
+    }
+
+    // polls for the rest of the frame, resetting for the next frame once this one completes or fails.
+    fn poll<S, P>(&mut self, stream: &mut S, context: &mut Context, format: &FrameFormat, maximum: usize, read: ReadPrefix) -> Poll<Result<Vec<u8>, std::io::Error>>
+        where S: Unpin + ?Sized, P: PollRead<S>
+    {
+        let length = match self.length {
+            Some(x) => x,
+            None    => {
+                loop {
+                    let needed = format.prefix_length(&self.header[..self.filled]);
+
+                    if self.filled == needed {
+                        break;
+                    }
+
+                    match poll_fill::<S, P>(stream, context, &mut self.header[..needed], &mut self.filled) {
+                        Poll::Ready(Ok(())) => { },
+                        Poll::Ready(Err(x)) => return Poll::Ready(Err(self.reset(x))),
+                        Poll::Pending       => return Poll::Pending,
+                    }
+                }
+
+                let length = match read(format, &self.header[..self.filled], maximum) {
+                    Ok((x, _)) => x,
+                    Err(x)     => return Poll::Ready(Err(self.reset(x.into()))),
+                };
+
+                self.length = Some(length);
+                self.filled = 0;
+                length
+            },
+        };
+
+        while self.filled < length {
+            if self.data.len() == self.filled {
+                let grown = std::cmp::min(length, std::cmp::max(self.filled * 2, STAGING_LENGTH));
+                self.data.resize(grown, 0);
+            }
+
+            let end = self.data.len();
+
+            match poll_fill::<S, P>(stream, context, &mut self.data[..end], &mut self.filled) {
+                Poll::Ready(Ok(())) => { },
+                Poll::Ready(Err(x)) => return Poll::Ready(Err(self.reset(x))),
+                Poll::Pending       => return Poll::Pending,
+            }
+        }
+
+        let data = std::mem::take(&mut self.data);
+
+        *self = FrameState::new();
+        Poll::Ready(Ok(data))
+    }
+
+    #[inline]
+    fn reset(&mut self, error: std::io::Error) -> std::io::Error {
+        *self = FrameState::new();
+        error
+    }
+}
+
+
+
+// writes a value encoded into a small inline buffer, followed by an optional borrowed payload.
+#[derive(Debug)]
+pub struct WriteAll<'a, S: ?Sized + 'a, P> {
+    stream:  &'a mut S,
+    data:    [u8; MAXIMUM_LENGTH],
+    length:  usize,
+    written: usize,
+    tail:    &'a [u8],
+    error:   Option<Error>,
+    runtime: PhantomData<fn() -> P>,
+}
+
+impl<'a, S: ?Sized + 'a, P> WriteAll<'a, S, P> {
+    // encodes `length` bytes with `encode`, to be written before `tail`.
+    #[inline]
+    pub(crate) fn new<F>(stream: &'a mut S, length: usize, encode: F, tail: &'a [u8]) -> WriteAll<'a, S, P> where F: FnOnce(&mut [u8]) {
+        let mut data = [0; MAXIMUM_LENGTH];
+        encode(&mut data[..length]);
+
+        WriteAll { stream, data, length, written: 0, tail, error: None, runtime: PhantomData }
+    }
+
+    // encodes a value of the length that `encode` returns. if encoding fails, the future fails without writing anything.
+    #[inline]
+    pub(crate) fn try_new<F>(stream: &'a mut S, encode: F, tail: &'a [u8]) -> WriteAll<'a, S, P> where F: FnOnce(&mut [u8]) -> Result<usize, Error> {
+        let mut data = [0; MAXIMUM_LENGTH];
+
+        match encode(&mut data) {
+            Ok(length) => WriteAll { stream, data, length, written: 0, tail, error: None, runtime: PhantomData },
+            Err(x)     => WriteAll { stream, data, length: 0, written: 0, tail: &[], error: Some(x), runtime: PhantomData },
+        }
+    }
+}
+
+impl<'a, S, P> Future for WriteAll<'a, S, P> where S: Unpin + ?Sized, P: PollWrite<S> {
+    type Output = Result<(), std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if let Some(x) = this.error.take() {
+            return Poll::Ready(Err(x.into()));
+        }
+
+        while this.written < this.length || !this.tail.is_empty() {
+            let data = match this.written < this.length {
+                true  => &this.data[this.written..this.length],
+                false => this.tail,
+            };
+
+            match poll_write::<S, P>(this.stream, context, data) {
+                Poll::Ready(Ok(n))  if this.written < this.length => this.written += n,
+                Poll::Ready(Ok(n))                                => this.tail = &this.tail[n..],
+                Poll::Ready(Err(x))                               => return Poll::Ready(Err(x)),
+                Poll::Pending                                     => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+
+
+// writes a slice of values, converting them through a staging buffer when they aren't already in the requested
+// encoding.
+#[derive(Debug)]
+pub struct WriteSlice<'a, S: ?Sized + 'a, P, T: 'a> {
+    stream:  &'a mut S,
+    values:  &'a [T],
+    write:   fn(&mut [u8], &[T]),
+    direct:  &'a [u8],
+    staging: Vec<u8>,
+    written: usize,
+    runtime: PhantomData<fn() -> P>,
+}
+
+impl<'a, S: ?Sized + 'a, P, T: Copy + 'a> WriteSlice<'a, S, P, T> {
+    // when `native` is set, the memory of `values` is written as is. otherwise, it's converted with `write`.
+    #[inline]
+    pub(crate) fn new(stream: &'a mut S, values: &'a [T], native: bool, write: fn(&mut [u8], &[T])) -> WriteSlice<'a, S, P, T> {
+        let (values, direct) = match native {
+            true  => (&[][..], unsafe { as_data(values) }),
+            false => (values, &[][..]),
+        };
+
+        WriteSlice { stream, values, write, direct, staging: Vec::new(), written: 0, runtime: PhantomData }
+    }
+}
+
+impl<'a, S, P, T> Future for WriteSlice<'a, S, P, T> where S: Unpin + ?Sized, P: PollWrite<S>, T: Copy {
+    type Output = Result<(), std::io::Error>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        loop {
+            if this.written == this.staging.len() && this.direct.is_empty() {
+                if this.values.is_empty() {
+                    return Poll::Ready(Ok(()));
+                }
+
+                let count = std::cmp::min(this.values.len(), STAGING_LENGTH / std::mem::size_of::<T>());
+                let chunk = &this.values[..count];
+
+                this.staging.resize(std::mem::size_of_val(chunk), 0);
+                this.written = 0;
+                this.values  = &this.values[count..];
+
+                (this.write)(&mut this.staging, chunk);
+            }
+
+            let data = match this.direct.is_empty() {
+                true  => &this.staging[this.written..],
+                false => this.direct,
+            };
+
+            match poll_write::<S, P>(this.stream, context, data) {
+                Poll::Ready(Ok(n))  if this.direct.is_empty() => this.written += n,
+                Poll::Ready(Ok(n))                            => this.direct = &this.direct[n..],
+                Poll::Ready(Err(x))                           => return Poll::Ready(Err(x)),
+                Poll::Pending                                 => return Poll::Pending,
+            }
+        }
+    }
+}
+
+
+
+// writes the leb128 encoding returned by `leb128::encode_*`.
+#[inline]
+pub(crate) fn write_leb128<S: ?Sized, P>(stream: &mut S, (data, length): ([u8; MAXIMUM_LENGTH], usize)) -> WriteAll<'_, S, P> {
+    WriteAll::new(stream, length, |x| x.copy_from_slice(&data[..length]), &[])
+}
+
+// reads into `buffer[*filled..]` until it's full, treating the end of the stream as an error.
+#[inline]
+fn poll_fill<S, P>(stream: &mut S, context: &mut Context, buffer: &mut [u8], filled: &mut usize) -> Poll<Result<(), std::io::Error>>
+    where S: Unpin + ?Sized, P: PollRead<S>
+{
+    while *filled < buffer.len() {
+        match P::poll_read(Pin::new(&mut *stream), context, &mut buffer[*filled..]) {
+            Poll::Ready(Ok(0))  => return Poll::Ready(Err(std::io::ErrorKind::UnexpectedEof.into())),
+            Poll::Ready(Ok(n))  => *filled += n,
+            Poll::Ready(Err(x)) => return Poll::Ready(Err(x)),
+            Poll::Pending       => return Poll::Pending,
+        }
+    }
+
+    Poll::Ready(Ok(()))
+}
+
+// writes some of `buffer`, treating a write of zero bytes as an error.
+#[inline]
+fn poll_write<S, P>(stream: &mut S, context: &mut Context, buffer: &[u8]) -> Poll<Result<usize, std::io::Error>>
+    where S: Unpin + ?Sized, P: PollWrite<S>
+{
+    match P::poll_write(Pin::new(stream), context, buffer) {
+        Poll::Ready(Ok(0)) => Poll::Ready(Err(std::io::ErrorKind::WriteZero.into())),
+        x                  => x,
+    }
+}
+
+
+
+// defines the async extension traits for one runtime's `AsyncRead` and `AsyncWrite` traits, mirroring the blocking
+// `BiteReadExpandedExt`, `BiteWriteExpandedExt`, `BiteReadExt` and `BiteWriteExt`.
+macro_rules! async_extension_traits {
+    ($read: path, $write: path, $runtime: ty) => {
+        pub trait AsyncBiteReadExpandedExt: $read + Unpin {
+            #[inline] fn read_u8                                   (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u8>       { ::bite::future::ReadValue::new(self, 1, |x| x[0])                            }
+            #[inline] fn read_i8                                   (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i8>       { ::bite::future::ReadValue::new(self, 1, |x| x[0] as i8)                      }
+            #[inline] fn read_u16<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u16>      { ::bite::future::ReadValue::new(self, 2, E::read_u16)                         }
+            #[inline] fn read_i16<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i16>      { ::bite::future::ReadValue::new(self, 2, E::read_i16)                         }
+            #[inline] fn read_u24<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u32>      { ::bite::future::ReadValue::new(self, 3, E::read_u24)                         }
+            #[inline] fn read_i24<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i32>      { ::bite::future::ReadValue::new(self, 3, E::read_i24)                         }
+            #[inline] fn read_u32<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u32>      { ::bite::future::ReadValue::new(self, 4, E::read_u32)                         }
+            #[inline] fn read_i32<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i32>      { ::bite::future::ReadValue::new(self, 4, E::read_i32)                         }
+            #[inline] fn read_u64<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u64>      { ::bite::future::ReadValue::new(self, 8, E::read_u64)                         }
+            #[inline] fn read_i64<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i64>      { ::bite::future::ReadValue::new(self, 8, E::read_i64)                         }
+            #[inline] fn read_u128<E: ::bite::Endianness>          (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u128>     { ::bite::future::ReadValue::new(self, 16, E::read_u128)                       }
+            #[inline] fn read_i128<E: ::bite::Endianness>          (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i128>     { ::bite::future::ReadValue::new(self, 16, E::read_i128)                       }
+            #[inline] fn read_f32<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, f32>      { ::bite::future::ReadValue::new(self, 4, E::read_f32)                         }
+            #[inline] fn read_f64<E: ::bite::Endianness>           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, f64>      { ::bite::future::ReadValue::new(self, 8, E::read_f64)                         }
+            #[inline] fn read_uint<E: ::bite::Endianness>          (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, u64>      { ::bite::future::ReadValue::new(self, bytes, |x| E::read_uint(x, x.len()))    }
+            #[inline] fn read_int<E: ::bite::Endianness>           (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, i64>      { ::bite::future::ReadValue::new(self, bytes, |x| E::read_int(x, x.len()))     }
+            #[inline] fn read_uint128<E: ::bite::Endianness>       (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, u128>     { ::bite::future::ReadValue::new(self, bytes, |x| E::read_uint128(x, x.len())) }
+            #[inline] fn read_int128<E: ::bite::Endianness>        (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, i128>     { ::bite::future::ReadValue::new(self, bytes, |x| E::read_int128(x, x.len()))  }
+
+            #[inline] fn read_slice_u16<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [u16])  -> ::bite::future::ReadSlice<'a, Self, $runtime, u16>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_u16)           }
+            #[inline] fn read_slice_i16<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [i16])  -> ::bite::future::ReadSlice<'a, Self, $runtime, i16>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_i16)           }
+            #[inline] fn read_slice_u32<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [u32])  -> ::bite::future::ReadSlice<'a, Self, $runtime, u32>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_u32)           }
+            #[inline] fn read_slice_i32<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [i32])  -> ::bite::future::ReadSlice<'a, Self, $runtime, i32>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_i32)           }
+            #[inline] fn read_slice_u64<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [u64])  -> ::bite::future::ReadSlice<'a, Self, $runtime, u64>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_u64)           }
+            #[inline] fn read_slice_i64<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [i64])  -> ::bite::future::ReadSlice<'a, Self, $runtime, i64>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_i64)           }
+            #[inline] fn read_slice_u128<'a, E: ::bite::Endianness>(&'a mut self, values: &'a mut [u128]) -> ::bite::future::ReadSlice<'a, Self, $runtime, u128> { ::bite::future::ReadSlice::new(self, values, E::convert_slice_u128)          }
+            #[inline] fn read_slice_i128<'a, E: ::bite::Endianness>(&'a mut self, values: &'a mut [i128]) -> ::bite::future::ReadSlice<'a, Self, $runtime, i128> { ::bite::future::ReadSlice::new(self, values, E::convert_slice_i128)          }
+            #[inline] fn read_slice_f32<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [f32])  -> ::bite::future::ReadSlice<'a, Self, $runtime, f32>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_f32)           }
+            #[inline] fn read_slice_f64<'a, E: ::bite::Endianness> (&'a mut self, values: &'a mut [f64])  -> ::bite::future::ReadSlice<'a, Self, $runtime, f64>  { ::bite::future::ReadSlice::new(self, values, E::convert_slice_f64)           }
+
+            #[inline] fn read_framed<E: ::bite::Endianness>        (&mut self)                            -> ::bite::future::ReadFramed<'_, Self, $runtime>          { ::bite::future::ReadFramed::new(self, ::bite::FrameFormat::U32, usize::MAX, ::bite::FrameFormat::read_prefix::<E>) }
+            #[inline] fn read_framed_max<E: ::bite::Endianness>    (&mut self, maximum: usize)            -> ::bite::future::ReadFramed<'_, Self, $runtime>          { ::bite::future::ReadFramed::new(self, ::bite::FrameFormat::U32, maximum, ::bite::FrameFormat::read_prefix::<E>)    }
+
+            #[inline]
+            fn read_framed_format<E: ::bite::Endianness>(&mut self, format: ::bite::FrameFormat, maximum: usize) -> ::bite::future::ReadFramed<'_, Self, $runtime> {
+                ::bite::future::ReadFramed::new(self, format, maximum, ::bite::FrameFormat::read_prefix::<E>)
+            }
+
+            #[inline] fn read_leb128_u16                           (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, u16>     { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_u16)              }
+            #[inline] fn read_leb128_i16                           (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, i16>     { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_i16)              }
+            #[inline] fn read_leb128_u32                           (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, u32>     { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_u32)              }
+            #[inline] fn read_leb128_i32                           (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, i32>     { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_i32)              }
+            #[inline] fn read_leb128_u64                           (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, u64>     { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_u64)              }
+            #[inline] fn read_leb128_i64                           (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, i64>     { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_i64)              }
+            #[inline] fn read_leb128_u128                          (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, u128>    { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_u128)             }
+            #[inline] fn read_leb128_i128                          (&mut self)                            -> ::bite::future::ReadLeb128<'_, Self, $runtime, i128>    { ::bite::future::ReadLeb128::new(self, ::bite::Leb128::read_i128)             }
+        }
+
+        impl<T> AsyncBiteReadExpandedExt for T where T: $read + Unpin + ?Sized { }
+
+
+
+        pub trait AsyncBiteWriteExpandedExt: $write + Unpin {
+            #[inline] fn write_u8                                   (&mut self, value: u8)                 -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 1, |x| x[0] = value, &[])                          }
+            #[inline] fn write_i8                                   (&mut self, value: i8)                 -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 1, |x| x[0] = value as u8, &[])                    }
+            #[inline] fn write_u16<E: ::bite::Endianness>           (&mut self, value: u16)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 2, |x| E::write_u16(x, value), &[])                }
+            #[inline] fn write_i16<E: ::bite::Endianness>           (&mut self, value: i16)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 2, |x| E::write_i16(x, value), &[])                }
+            #[inline] fn write_u24<E: ::bite::Endianness>           (&mut self, value: u32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 3, |x| E::write_u24(x, value), &[])                }
+            #[inline] fn write_i24<E: ::bite::Endianness>           (&mut self, value: i32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 3, |x| E::write_i24(x, value), &[])                }
+            #[inline] fn write_u32<E: ::bite::Endianness>           (&mut self, value: u32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 4, |x| E::write_u32(x, value), &[])                }
+            #[inline] fn write_i32<E: ::bite::Endianness>           (&mut self, value: i32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 4, |x| E::write_i32(x, value), &[])                }
+            #[inline] fn write_u64<E: ::bite::Endianness>           (&mut self, value: u64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 8, |x| E::write_u64(x, value), &[])                }
+            #[inline] fn write_i64<E: ::bite::Endianness>           (&mut self, value: i64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 8, |x| E::write_i64(x, value), &[])                }
+            #[inline] fn write_u128<E: ::bite::Endianness>          (&mut self, value: u128)               -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 16, |x| E::write_u128(x, value), &[])              }
+            #[inline] fn write_i128<E: ::bite::Endianness>          (&mut self, value: i128)               -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 16, |x| E::write_i128(x, value), &[])              }
+            #[inline] fn write_f32<E: ::bite::Endianness>           (&mut self, value: f32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 4, |x| E::write_f32(x, value), &[])                }
+            #[inline] fn write_f64<E: ::bite::Endianness>           (&mut self, value: f64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, 8, |x| E::write_f64(x, value), &[])                }
+            #[inline] fn write_uint<E: ::bite::Endianness>          (&mut self, value: u64, bytes: usize)  -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, bytes, |x| E::write_uint(x, value, bytes), &[])    }
+            #[inline] fn write_int<E: ::bite::Endianness>           (&mut self, value: i64, bytes: usize)  -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, bytes, |x| E::write_int(x, value, bytes), &[])     }
+            #[inline] fn write_uint128<E: ::bite::Endianness>       (&mut self, value: u128, bytes: usize) -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, bytes, |x| E::write_uint128(x, value, bytes), &[]) }
+            #[inline] fn write_int128<E: ::bite::Endianness>        (&mut self, value: i128, bytes: usize) -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::WriteAll::new(self, bytes, |x| E::write_int128(x, value, bytes), &[])  }
+
+            #[inline] fn write_slice_u16<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [u16])      -> ::bite::future::WriteSlice<'a, Self, $runtime, u16>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_u16)      }
+            #[inline] fn write_slice_i16<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [i16])      -> ::bite::future::WriteSlice<'a, Self, $runtime, i16>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_i16)      }
+            #[inline] fn write_slice_u32<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [u32])      -> ::bite::future::WriteSlice<'a, Self, $runtime, u32>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_u32)      }
+            #[inline] fn write_slice_i32<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [i32])      -> ::bite::future::WriteSlice<'a, Self, $runtime, i32>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_i32)      }
+            #[inline] fn write_slice_u64<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [u64])      -> ::bite::future::WriteSlice<'a, Self, $runtime, u64>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_u64)      }
+            #[inline] fn write_slice_i64<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [i64])      -> ::bite::future::WriteSlice<'a, Self, $runtime, i64>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_i64)      }
+            #[inline] fn write_slice_u128<'a, E: ::bite::Endianness>(&'a mut self, values: &'a [u128])     -> ::bite::future::WriteSlice<'a, Self, $runtime, u128> { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_u128)     }
+            #[inline] fn write_slice_i128<'a, E: ::bite::Endianness>(&'a mut self, values: &'a [i128])     -> ::bite::future::WriteSlice<'a, Self, $runtime, i128> { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_i128)     }
+            #[inline] fn write_slice_f32<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [f32])      -> ::bite::future::WriteSlice<'a, Self, $runtime, f32>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_f32)      }
+            #[inline] fn write_slice_f64<'a, E: ::bite::Endianness> (&'a mut self, values: &'a [f64])      -> ::bite::future::WriteSlice<'a, Self, $runtime, f64>  { ::bite::future::WriteSlice::new(self, values, E::is_native(), E::write_slice_f64)      }
+
+            #[inline] fn write_framed<'a, E: ::bite::Endianness>    (&'a mut self, value: &'a [u8])        -> ::bite::future::WriteAll<'a, Self, $runtime>         { AsyncBiteWriteExpandedExt::write_framed_format::<E>(self, ::bite::FrameFormat::U32, value) }
+
+            #[inline]
+            fn write_framed_format<'a, E: ::bite::Endianness>(&'a mut self, format: ::bite::FrameFormat, value: &'a [u8]) -> ::bite::future::WriteAll<'a, Self, $runtime> {
+                ::bite::future::WriteAll::try_new(self, |x| format.write_prefix::<E>(x, value.len()), value)
+            }
+
+            #[inline] fn write_leb128_u16                           (&mut self, value: u16)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_unsigned(value as u128))     }
+            #[inline] fn write_leb128_i16                           (&mut self, value: i16)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_signed(value as i128))       }
+            #[inline] fn write_leb128_u32                           (&mut self, value: u32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_unsigned(value as u128))     }
+            #[inline] fn write_leb128_i32                           (&mut self, value: i32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_signed(value as i128))       }
+            #[inline] fn write_leb128_u64                           (&mut self, value: u64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_unsigned(value as u128))     }
+            #[inline] fn write_leb128_i64                           (&mut self, value: i64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_signed(value as i128))       }
+            #[inline] fn write_leb128_u128                          (&mut self, value: u128)               -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_unsigned(value))             }
+            #[inline] fn write_leb128_i128                          (&mut self, value: i128)               -> ::bite::future::WriteAll<'_, Self, $runtime>             { ::bite::future::write_leb128(self, ::bite::leb128::encode_signed(value))               }
+        }
+
+        impl<T> AsyncBiteWriteExpandedExt for T where T: $write + Unpin + ?Sized { }
+
+
+
+        pub trait AsyncBiteReadExt: $read + Unpin {
+            #[inline] fn read_u8            (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u8>       { AsyncBiteReadExpandedExt::read_u8(self)                                          }
+            #[inline] fn read_i8            (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i8>       { AsyncBiteReadExpandedExt::read_i8(self)                                          }
+            #[inline] fn read_u16           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u16>      { AsyncBiteReadExpandedExt::read_u16::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_i16           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i16>      { AsyncBiteReadExpandedExt::read_i16::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_u24           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u32>      { AsyncBiteReadExpandedExt::read_u24::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_i24           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i32>      { AsyncBiteReadExpandedExt::read_i24::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_u32           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u32>      { AsyncBiteReadExpandedExt::read_u32::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_i32           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i32>      { AsyncBiteReadExpandedExt::read_i32::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_u64           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u64>      { AsyncBiteReadExpandedExt::read_u64::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_i64           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i64>      { AsyncBiteReadExpandedExt::read_i64::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_u128          (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, u128>     { AsyncBiteReadExpandedExt::read_u128::<::bite::LittleEndian>(self)                }
+            #[inline] fn read_i128          (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, i128>     { AsyncBiteReadExpandedExt::read_i128::<::bite::LittleEndian>(self)                }
+            #[inline] fn read_f32           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, f32>      { AsyncBiteReadExpandedExt::read_f32::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_f64           (&mut self)                            -> ::bite::future::ReadValue<'_, Self, $runtime, f64>      { AsyncBiteReadExpandedExt::read_f64::<::bite::LittleEndian>(self)                 }
+            #[inline] fn read_uint          (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, u64>      { AsyncBiteReadExpandedExt::read_uint::<::bite::LittleEndian>(self, bytes)         }
+            #[inline] fn read_int           (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, i64>      { AsyncBiteReadExpandedExt::read_int::<::bite::LittleEndian>(self, bytes)          }
+            #[inline] fn read_uint128       (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, u128>     { AsyncBiteReadExpandedExt::read_uint128::<::bite::LittleEndian>(self, bytes)      }
+            #[inline] fn read_int128        (&mut self, bytes: usize)              -> ::bite::future::ReadValue<'_, Self, $runtime, i128>     { AsyncBiteReadExpandedExt::read_int128::<::bite::LittleEndian>(self, bytes)       }
+
+            #[inline] fn read_slice_u16<'a> (&'a mut self, values: &'a mut [u16])  -> ::bite::future::ReadSlice<'a, Self, $runtime, u16>  { AsyncBiteReadExpandedExt::read_slice_u16::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_i16<'a> (&'a mut self, values: &'a mut [i16])  -> ::bite::future::ReadSlice<'a, Self, $runtime, i16>  { AsyncBiteReadExpandedExt::read_slice_i16::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_u32<'a> (&'a mut self, values: &'a mut [u32])  -> ::bite::future::ReadSlice<'a, Self, $runtime, u32>  { AsyncBiteReadExpandedExt::read_slice_u32::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_i32<'a> (&'a mut self, values: &'a mut [i32])  -> ::bite::future::ReadSlice<'a, Self, $runtime, i32>  { AsyncBiteReadExpandedExt::read_slice_i32::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_u64<'a> (&'a mut self, values: &'a mut [u64])  -> ::bite::future::ReadSlice<'a, Self, $runtime, u64>  { AsyncBiteReadExpandedExt::read_slice_u64::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_i64<'a> (&'a mut self, values: &'a mut [i64])  -> ::bite::future::ReadSlice<'a, Self, $runtime, i64>  { AsyncBiteReadExpandedExt::read_slice_i64::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_u128<'a>(&'a mut self, values: &'a mut [u128]) -> ::bite::future::ReadSlice<'a, Self, $runtime, u128> { AsyncBiteReadExpandedExt::read_slice_u128::<::bite::LittleEndian>(self, values)  }
+            #[inline] fn read_slice_i128<'a>(&'a mut self, values: &'a mut [i128]) -> ::bite::future::ReadSlice<'a, Self, $runtime, i128> { AsyncBiteReadExpandedExt::read_slice_i128::<::bite::LittleEndian>(self, values)  }
+            #[inline] fn read_slice_f32<'a> (&'a mut self, values: &'a mut [f32])  -> ::bite::future::ReadSlice<'a, Self, $runtime, f32>  { AsyncBiteReadExpandedExt::read_slice_f32::<::bite::LittleEndian>(self, values)   }
+            #[inline] fn read_slice_f64<'a> (&'a mut self, values: &'a mut [f64])  -> ::bite::future::ReadSlice<'a, Self, $runtime, f64>  { AsyncBiteReadExpandedExt::read_slice_f64::<::bite::LittleEndian>(self, values)   }
+
+            #[inline] fn read_framed        (&mut self)                            -> ::bite::future::ReadFramed<'_, Self, $runtime>          { AsyncBiteReadExpandedExt::read_framed::<::bite::LittleEndian>(self)              }
+            #[inline] fn read_framed_max    (&mut self, maximum: usize)            -> ::bite::future::ReadFramed<'_, Self, $runtime>          { AsyncBiteReadExpandedExt::read_framed_max::<::bite::LittleEndian>(self, maximum) }
+
+            #[inline]
+            fn read_framed_format(&mut self, format: ::bite::FrameFormat, maximum: usize) -> ::bite::future::ReadFramed<'_, Self, $runtime> {
+                AsyncBiteReadExpandedExt::read_framed_format::<::bite::LittleEndian>(self, format, maximum)
+            }
+        }
+
+        impl<T> AsyncBiteReadExt for T where T: $read + Unpin + ?Sized { }
+
+
+
+        pub trait AsyncBiteWriteExt: $write + Unpin {
+            #[inline] fn write_u8            (&mut self, value: u8)                 -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_u8(self, value)                                     }
+            #[inline] fn write_i8            (&mut self, value: i8)                 -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_i8(self, value)                                     }
+            #[inline] fn write_u16           (&mut self, value: u16)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_u16::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_i16           (&mut self, value: i16)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_i16::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_u24           (&mut self, value: u32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_u24::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_i24           (&mut self, value: i32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_i24::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_u32           (&mut self, value: u32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_u32::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_i32           (&mut self, value: i32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_i32::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_u64           (&mut self, value: u64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_u64::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_i64           (&mut self, value: i64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_i64::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_u128          (&mut self, value: u128)               -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_u128::<::bite::LittleEndian>(self, value)           }
+            #[inline] fn write_i128          (&mut self, value: i128)               -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_i128::<::bite::LittleEndian>(self, value)           }
+            #[inline] fn write_f32           (&mut self, value: f32)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_f32::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_f64           (&mut self, value: f64)                -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_f64::<::bite::LittleEndian>(self, value)            }
+            #[inline] fn write_uint          (&mut self, value: u64, bytes: usize)  -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_uint::<::bite::LittleEndian>(self, value, bytes)    }
+            #[inline] fn write_int           (&mut self, value: i64, bytes: usize)  -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_int::<::bite::LittleEndian>(self, value, bytes)     }
+            #[inline] fn write_uint128       (&mut self, value: u128, bytes: usize) -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_uint128::<::bite::LittleEndian>(self, value, bytes) }
+            #[inline] fn write_int128        (&mut self, value: i128, bytes: usize) -> ::bite::future::WriteAll<'_, Self, $runtime>             { AsyncBiteWriteExpandedExt::write_int128::<::bite::LittleEndian>(self, value, bytes)  }
+
+            #[inline] fn write_slice_u16<'a> (&'a mut self, values: &'a [u16])      -> ::bite::future::WriteSlice<'a, Self, $runtime, u16>  { AsyncBiteWriteExpandedExt::write_slice_u16::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_i16<'a> (&'a mut self, values: &'a [i16])      -> ::bite::future::WriteSlice<'a, Self, $runtime, i16>  { AsyncBiteWriteExpandedExt::write_slice_i16::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_u32<'a> (&'a mut self, values: &'a [u32])      -> ::bite::future::WriteSlice<'a, Self, $runtime, u32>  { AsyncBiteWriteExpandedExt::write_slice_u32::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_i32<'a> (&'a mut self, values: &'a [i32])      -> ::bite::future::WriteSlice<'a, Self, $runtime, i32>  { AsyncBiteWriteExpandedExt::write_slice_i32::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_u64<'a> (&'a mut self, values: &'a [u64])      -> ::bite::future::WriteSlice<'a, Self, $runtime, u64>  { AsyncBiteWriteExpandedExt::write_slice_u64::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_i64<'a> (&'a mut self, values: &'a [i64])      -> ::bite::future::WriteSlice<'a, Self, $runtime, i64>  { AsyncBiteWriteExpandedExt::write_slice_i64::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_u128<'a>(&'a mut self, values: &'a [u128])     -> ::bite::future::WriteSlice<'a, Self, $runtime, u128> { AsyncBiteWriteExpandedExt::write_slice_u128::<::bite::LittleEndian>(self, values)    }
+            #[inline] fn write_slice_i128<'a>(&'a mut self, values: &'a [i128])     -> ::bite::future::WriteSlice<'a, Self, $runtime, i128> { AsyncBiteWriteExpandedExt::write_slice_i128::<::bite::LittleEndian>(self, values)    }
+            #[inline] fn write_slice_f32<'a> (&'a mut self, values: &'a [f32])      -> ::bite::future::WriteSlice<'a, Self, $runtime, f32>  { AsyncBiteWriteExpandedExt::write_slice_f32::<::bite::LittleEndian>(self, values)     }
+            #[inline] fn write_slice_f64<'a> (&'a mut self, values: &'a [f64])      -> ::bite::future::WriteSlice<'a, Self, $runtime, f64>  { AsyncBiteWriteExpandedExt::write_slice_f64::<::bite::LittleEndian>(self, values)     }
+
+            #[inline] fn write_framed<'a>    (&'a mut self, value: &'a [u8])        -> ::bite::future::WriteAll<'a, Self, $runtime>         { AsyncBiteWriteExpandedExt::write_framed::<::bite::LittleEndian>(self, value)         }
+
+            #[inline]
+            fn write_framed_format<'a>(&'a mut self, format: ::bite::FrameFormat, value: &'a [u8]) -> ::bite::future::WriteAll<'a, Self, $runtime> {
+                AsyncBiteWriteExpandedExt::write_framed_format::<::bite::LittleEndian>(self, format, value)
+            }
+        }
+
+        impl<T> AsyncBiteWriteExt for T where T: $write + Unpin + ?Sized { }
+
+
+
+        // a cancellation safe reader of frames from a stream - see `bite::future::FrameReader`.
+        pub type FrameReader<S, E = ::bite::LittleEndian> = ::bite::future::FrameReader<S, $runtime, E>;
+    };
+}
@@ -0,0 +1,45 @@
+use {
+    std,
+    std::pin::Pin,
+    std::task::Context,
+    std::task::Poll,
+
+    futures_io::AsyncRead,
+    futures_io::AsyncWrite,
+
+    bite::future::PollRead,
+    bite::future::PollWrite,
+};
+
+
+
+// async equivalents of the blocking extension traits, for the runtime-agnostic `futures-io` `AsyncRead` and
+// `AsyncWrite` (as used by async-std and smol).
+//
+// every method returns a future from `bite::future` that borrows the stream, and encodes exactly as its blocking
+// counterpart does. `read_framed` and `read_framed_max` lose data if their future is dropped part of the way through
+// a frame - `FrameReader` doesn't, and should be used wherever reads may be cancelled.
+
+
+
+// selects the `futures-io` traits for the futures in `bite::future`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Futures { }
+
+impl<S: AsyncRead + Unpin + ?Sized> PollRead<S> for Futures {
+    #[inline]
+    fn poll_read(stream: Pin<&mut S>, context: &mut Context, buffer: &mut [u8]) -> Poll<Result<usize, std::io::Error>> {
+        stream.poll_read(context, buffer)
+    }
+}
+
+impl<S: AsyncWrite + Unpin + ?Sized> PollWrite<S> for Futures {
+    #[inline]
+    fn poll_write(stream: Pin<&mut S>, context: &mut Context, buffer: &[u8]) -> Poll<Result<usize, std::io::Error>> {
+        stream.poll_write(context, buffer)
+    }
+}
+
+
+
+async_extension_traits!(AsyncRead, AsyncWrite, Futures);
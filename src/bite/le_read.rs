@@ -3,44 +3,293 @@ use {
     std::io::Read,
 
     bite::BiteReadExpandedExt,
+    bite::FrameFormat,
     bite::LittleEndian,
+    bite::Padding,
 };
 
 
 
 pub trait BiteReadExt: Read {
-    #[inline] fn read_u8        (&mut self)                      -> Result<    u8,  std::io::Error> { BiteReadExpandedExt::read_u8                        (self)          }
-    #[inline] fn read_i8        (&mut self)                      -> Result<    i8,  std::io::Error> { BiteReadExpandedExt::read_i8                        (self)          }
-    #[inline] fn read_u16       (&mut self)                      -> Result<   u16,  std::io::Error> { BiteReadExpandedExt::read_u16       ::<LittleEndian>(self)          }
-    #[inline] fn read_i16       (&mut self)                      -> Result<   i16,  std::io::Error> { BiteReadExpandedExt::read_i16       ::<LittleEndian>(self)          }
-    #[inline] fn read_u24       (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u24       ::<LittleEndian>(self)          }
-    #[inline] fn read_i24       (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i24       ::<LittleEndian>(self)          }
-    #[inline] fn read_u32       (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u32       ::<LittleEndian>(self)          }
-    #[inline] fn read_i32       (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i32       ::<LittleEndian>(self)          }
-    #[inline] fn read_u64       (&mut self)                      -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_u64       ::<LittleEndian>(self)          }
-    #[inline] fn read_i64       (&mut self)                      -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_i64       ::<LittleEndian>(self)          }
-    #[inline] fn read_u128      (&mut self)                      -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_u128      ::<LittleEndian>(self)          }
-    #[inline] fn read_i128      (&mut self)                      -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_i128      ::<LittleEndian>(self)          }
-    #[inline] fn read_uint      (&mut self, bytes: usize)        -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_uint      ::<LittleEndian>(self, bytes)   }
-    #[inline] fn read_int       (&mut self, bytes: usize)        -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_int       ::<LittleEndian>(self, bytes)   }
-    #[inline] fn read_uint128   (&mut self, bytes: usize)        -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_uint128   ::<LittleEndian>(self, bytes)   }
-    #[inline] fn read_int128    (&mut self, bytes: usize)        -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_int128    ::<LittleEndian>(self, bytes)   }
-    #[inline] fn read_f32       (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f32       ::<LittleEndian>(self)          }
-    #[inline] fn read_f64       (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_f64       ::<LittleEndian>(self)          }
-
-    #[inline] fn read_slice_u16 (&mut self, values: &mut [u16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u16 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_i16 (&mut self, values: &mut [i16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i16 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_u32 (&mut self, values: &mut [u32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u32 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_i32 (&mut self, values: &mut [i32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i32 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_u64 (&mut self, values: &mut [u64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u64 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_i64 (&mut self, values: &mut [i64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i64 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_u128(&mut self, values: &mut [u128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u128::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_i128(&mut self, values: &mut [i128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i128::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_f32 (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f32 ::<LittleEndian>(self, values)  }
-    #[inline] fn read_slice_f64 (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f64 ::<LittleEndian>(self, values)  }
-
-    #[inline] fn read_framed    (&mut self)                      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed    ::<LittleEndian>(self)          }
-    #[inline] fn read_framed_max(&mut self, maximum: usize)      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed_max::<LittleEndian>(self, maximum) }
+    #[inline] fn read_u8           (&mut self)                      -> Result<    u8,  std::io::Error> { BiteReadExpandedExt::read_u8                           (self)          }
+    #[inline] fn read_i8           (&mut self)                      -> Result<    i8,  std::io::Error> { BiteReadExpandedExt::read_i8                           (self)          }
+    #[inline] fn read_u16          (&mut self)                      -> Result<   u16,  std::io::Error> { BiteReadExpandedExt::read_u16          ::<LittleEndian>(self)          }
+    #[inline] fn read_i16          (&mut self)                      -> Result<   i16,  std::io::Error> { BiteReadExpandedExt::read_i16          ::<LittleEndian>(self)          }
+    #[inline] fn read_u24          (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u24          ::<LittleEndian>(self)          }
+    #[inline] fn read_i24          (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i24          ::<LittleEndian>(self)          }
+    #[inline] fn read_u32          (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u32          ::<LittleEndian>(self)          }
+    #[inline] fn read_i32          (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i32          ::<LittleEndian>(self)          }
+    #[inline] fn read_u64          (&mut self)                      -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_u64          ::<LittleEndian>(self)          }
+    #[inline] fn read_i64          (&mut self)                      -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_i64          ::<LittleEndian>(self)          }
+    #[inline] fn read_u128         (&mut self)                      -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_u128         ::<LittleEndian>(self)          }
+    #[inline] fn read_i128         (&mut self)                      -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_i128         ::<LittleEndian>(self)          }
+    #[inline] fn read_uint         (&mut self, bytes: usize)        -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_uint         ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_int          (&mut self, bytes: usize)        -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_int          ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_uint128      (&mut self, bytes: usize)        -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_uint128      ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_int128       (&mut self, bytes: usize)        -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_int128       ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_f32          (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f32          ::<LittleEndian>(self)          }
+    #[inline] fn read_f64          (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_f64          ::<LittleEndian>(self)          }
+    #[inline] fn read_f16          (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f16          ::<LittleEndian>(self)          }
+    #[inline] fn read_bf16         (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_bf16         ::<LittleEndian>(self)          }
+    #[inline] fn read_ibm_f32      (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_ibm_f32      ::<LittleEndian>(self)          }
+    #[inline] fn read_ibm_f64      (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_ibm_f64      ::<LittleEndian>(self)          }
+    #[inline] fn read_vax_f        (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_vax_f        ::<LittleEndian>(self)          }
+    #[inline] fn read_vax_d        (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_vax_d        ::<LittleEndian>(self)          }
+    #[inline] fn read_vax_g        (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_vax_g        ::<LittleEndian>(self)          }
+
+    #[inline] fn read_slice_u16    (&mut self, values: &mut [u16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u16    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i16    (&mut self, values: &mut [i16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i16    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_u32    (&mut self, values: &mut [u32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u32    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i32    (&mut self, values: &mut [i32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i32    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_u64    (&mut self, values: &mut [u64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u64    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i64    (&mut self, values: &mut [i64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i64    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_u128   (&mut self, values: &mut [u128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u128   ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i128   (&mut self, values: &mut [i128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i128   ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_f32    (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f32    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_f64    (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f64    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_f16    (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f16    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_bf16   (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_bf16   ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_ibm_f32(&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_ibm_f32::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_ibm_f64(&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_ibm_f64::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_vax_f  (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_f  ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_vax_d  (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_d  ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_vax_g  (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_g  ::<LittleEndian>(self, values)  }
+
+    #[inline] fn read_framed       (&mut self)                      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed       ::<LittleEndian>(self)          }
+    #[inline] fn read_framed_max   (&mut self, maximum: usize)      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed_max   ::<LittleEndian>(self, maximum) }
+
+    #[inline]
+    fn read_framed_format(&mut self, format: FrameFormat, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+        BiteReadExpandedExt::read_framed_format::<LittleEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_framed_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_into::<LittleEndian>(self, buffer)
+    }
+
+    #[inline]
+    fn read_framed_max_into(&mut self, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_max_into::<LittleEndian>(self, maximum, buffer)
+    }
+
+    #[inline]
+    fn read_framed_format_into(&mut self, format: FrameFormat, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_format_into::<LittleEndian>(self, format, maximum, buffer)
+    }
+
+    #[inline]
+    fn read_string(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_string::<LittleEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_string_lossy(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_string_lossy::<LittleEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_cstring(&mut self, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_cstring(self, maximum)
+    }
+
+    #[inline]
+    fn read_cstring_lossy(&mut self, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_cstring_lossy(self, maximum)
+    }
+
+    #[inline]
+    fn read_fixed_string(&mut self, width: usize, padding: Padding) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_fixed_string(self, width, padding)
+    }
+
+    #[inline]
+    fn read_fixed_string_lossy(&mut self, width: usize, padding: Padding) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_fixed_string_lossy(self, width, padding)
+    }
+
+    #[inline]
+    fn read_utf16(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf16_nul(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_nul::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf16_bom(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_bom::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf16_nul_bom(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_nul_bom::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf32(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf32_nul(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_nul::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf32_bom(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_bom::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf32_nul_bom(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_nul_bom::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_f80(&mut self) -> Result<(f64, bool), std::io::Error> {
+        BiteReadExpandedExt::read_f80::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_slice_f80(&mut self, values: &mut [f64]) -> Result<bool, std::io::Error> {
+        BiteReadExpandedExt::read_slice_f80::<LittleEndian>(self, values)
+    }
 }
 
 impl<T> BiteReadExt for T where T: Read + ?Sized { }
+
+
+
+pub trait BiteReadLeExt: Read {
+    #[inline] fn read_u8_le           (&mut self)                      -> Result<    u8,  std::io::Error> { BiteReadExpandedExt::read_u8                           (self)          }
+    #[inline] fn read_i8_le           (&mut self)                      -> Result<    i8,  std::io::Error> { BiteReadExpandedExt::read_i8                           (self)          }
+    #[inline] fn read_u16_le          (&mut self)                      -> Result<   u16,  std::io::Error> { BiteReadExpandedExt::read_u16          ::<LittleEndian>(self)          }
+    #[inline] fn read_i16_le          (&mut self)                      -> Result<   i16,  std::io::Error> { BiteReadExpandedExt::read_i16          ::<LittleEndian>(self)          }
+    #[inline] fn read_u24_le          (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u24          ::<LittleEndian>(self)          }
+    #[inline] fn read_i24_le          (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i24          ::<LittleEndian>(self)          }
+    #[inline] fn read_u32_le          (&mut self)                      -> Result<   u32,  std::io::Error> { BiteReadExpandedExt::read_u32          ::<LittleEndian>(self)          }
+    #[inline] fn read_i32_le          (&mut self)                      -> Result<   i32,  std::io::Error> { BiteReadExpandedExt::read_i32          ::<LittleEndian>(self)          }
+    #[inline] fn read_u64_le          (&mut self)                      -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_u64          ::<LittleEndian>(self)          }
+    #[inline] fn read_i64_le          (&mut self)                      -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_i64          ::<LittleEndian>(self)          }
+    #[inline] fn read_u128_le         (&mut self)                      -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_u128         ::<LittleEndian>(self)          }
+    #[inline] fn read_i128_le         (&mut self)                      -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_i128         ::<LittleEndian>(self)          }
+    #[inline] fn read_uint_le         (&mut self, bytes: usize)        -> Result<   u64,  std::io::Error> { BiteReadExpandedExt::read_uint         ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_int_le          (&mut self, bytes: usize)        -> Result<   i64,  std::io::Error> { BiteReadExpandedExt::read_int          ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_uint128_le      (&mut self, bytes: usize)        -> Result<  u128,  std::io::Error> { BiteReadExpandedExt::read_uint128      ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_int128_le       (&mut self, bytes: usize)        -> Result<  i128,  std::io::Error> { BiteReadExpandedExt::read_int128       ::<LittleEndian>(self, bytes)   }
+    #[inline] fn read_f32_le          (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f32          ::<LittleEndian>(self)          }
+    #[inline] fn read_f64_le          (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_f64          ::<LittleEndian>(self)          }
+    #[inline] fn read_f16_le          (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_f16          ::<LittleEndian>(self)          }
+    #[inline] fn read_bf16_le         (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_bf16         ::<LittleEndian>(self)          }
+    #[inline] fn read_ibm_f32_le      (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_ibm_f32      ::<LittleEndian>(self)          }
+    #[inline] fn read_ibm_f64_le      (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_ibm_f64      ::<LittleEndian>(self)          }
+    #[inline] fn read_vax_f_le        (&mut self)                      -> Result<   f32,  std::io::Error> { BiteReadExpandedExt::read_vax_f        ::<LittleEndian>(self)          }
+    #[inline] fn read_vax_d_le        (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_vax_d        ::<LittleEndian>(self)          }
+    #[inline] fn read_vax_g_le        (&mut self)                      -> Result<   f64,  std::io::Error> { BiteReadExpandedExt::read_vax_g        ::<LittleEndian>(self)          }
+
+    #[inline] fn read_slice_u16_le    (&mut self, values: &mut [u16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u16    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i16_le    (&mut self, values: &mut [i16])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i16    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_u32_le    (&mut self, values: &mut [u32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u32    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i32_le    (&mut self, values: &mut [i32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i32    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_u64_le    (&mut self, values: &mut [u64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u64    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i64_le    (&mut self, values: &mut [i64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i64    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_u128_le   (&mut self, values: &mut [u128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_u128   ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_i128_le   (&mut self, values: &mut [i128]) -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_i128   ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_f32_le    (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f32    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_f64_le    (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f64    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_f16_le    (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_f16    ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_bf16_le   (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_bf16   ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_ibm_f32_le(&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_ibm_f32::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_ibm_f64_le(&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_ibm_f64::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_vax_f_le  (&mut self, values: &mut [f32])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_f  ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_vax_d_le  (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_d  ::<LittleEndian>(self, values)  }
+    #[inline] fn read_slice_vax_g_le  (&mut self, values: &mut [f64])  -> Result<     (), std::io::Error> { BiteReadExpandedExt::read_slice_vax_g  ::<LittleEndian>(self, values)  }
+
+    #[inline] fn read_framed_le       (&mut self)                      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed       ::<LittleEndian>(self)          }
+    #[inline] fn read_framed_max_le   (&mut self, maximum: usize)      -> Result<Vec<u8>, std::io::Error> { BiteReadExpandedExt::read_framed_max   ::<LittleEndian>(self, maximum) }
+
+    #[inline]
+    fn read_framed_format_le(&mut self, format: FrameFormat, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+        BiteReadExpandedExt::read_framed_format::<LittleEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_framed_into_le(&mut self, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_into::<LittleEndian>(self, buffer)
+    }
+
+    #[inline]
+    fn read_framed_max_into_le(&mut self, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_max_into::<LittleEndian>(self, maximum, buffer)
+    }
+
+    #[inline]
+    fn read_framed_format_into_le(&mut self, format: FrameFormat, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        BiteReadExpandedExt::read_framed_format_into::<LittleEndian>(self, format, maximum, buffer)
+    }
+
+    #[inline]
+    fn read_string_le(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_string::<LittleEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_string_lossy_le(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_string_lossy::<LittleEndian>(self, format, maximum)
+    }
+
+    #[inline]
+    fn read_utf16_le(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf16_nul_le(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_nul::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf16_bom_le(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_bom::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf16_nul_bom_le(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf16_nul_bom::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf32_le(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf32_nul_le(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_nul::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_utf32_bom_le(&mut self, length: usize) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_bom::<LittleEndian>(self, length)
+    }
+
+    #[inline]
+    fn read_utf32_nul_bom_le(&mut self) -> Result<String, std::io::Error> {
+        BiteReadExpandedExt::read_utf32_nul_bom::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_f80_le(&mut self) -> Result<(f64, bool), std::io::Error> {
+        BiteReadExpandedExt::read_f80::<LittleEndian>(self)
+    }
+
+    #[inline]
+    fn read_slice_f80_le(&mut self, values: &mut [f64]) -> Result<bool, std::io::Error> {
+        BiteReadExpandedExt::read_slice_f80::<LittleEndian>(self, values)
+    }
+}
+
+impl<T> BiteReadLeExt for T where T: Read + ?Sized { }
@@ -3,43 +3,225 @@ use {
     std::io::Write,
 
     bite::BiteWriteExpandedExt,
+    bite::FrameFormat,
     bite::LittleEndian,
+    bite::Padding,
 };
 
 
 
 pub trait BiteWriteExt: Write {
-    #[inline] fn write_u8        (&mut self, value: u8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u8                        (self, value)        }
-    #[inline] fn write_i8        (&mut self, value: i8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i8                        (self, value)        }
-    #[inline] fn write_u16       (&mut self, value: u16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u16       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_i16       (&mut self, value: i16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i16       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_u32       (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u32       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_i32       (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i32       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_u24       (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u24       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_i24       (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i24       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_u64       (&mut self, value: u64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u64       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_i64       (&mut self, value: i64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i64       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_u128      (&mut self, value: u128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u128      ::<LittleEndian>(self, value)        }
-    #[inline] fn write_i128      (&mut self, value: i128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i128      ::<LittleEndian>(self, value)        }
-    #[inline] fn write_uint      (&mut self, value: u64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint      ::<LittleEndian>(self, value, bytes) }
-    #[inline] fn write_int       (&mut self, value: i64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int       ::<LittleEndian>(self, value, bytes) }
-    #[inline] fn write_uint128   (&mut self, value: u128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint128   ::<LittleEndian>(self, value, bytes) }
-    #[inline] fn write_int128    (&mut self, value: i128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int128    ::<LittleEndian>(self, value, bytes) }
-    #[inline] fn write_f32       (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f32       ::<LittleEndian>(self, value)        }
-    #[inline] fn write_f64       (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f64       ::<LittleEndian>(self, value)        }
-
-    // #[inline] fn write_slice_u16 (&mut self, values: &[u16])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u16 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_i16 (&mut self, values: &[i16])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i16 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_u32 (&mut self, values: &[u32])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u32 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_i32 (&mut self, values: &[i32])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i32 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_u64 (&mut self, values: &[u64])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u64 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_i64 (&mut self, values: &[i64])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i64 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_u128(&mut self, values: &[u128])        -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u128::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_i128(&mut self, values: &[i128])        -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i128::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_f32 (&mut self, values: &[f32])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f32 ::<LittleEndian>(self, values)       }
-    // #[inline] fn write_slice_f64 (&mut self, values: &[f64])         -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f64 ::<LittleEndian>(self, values)       }
-
-    #[inline] fn write_framed    (&mut self, value: &[u8])              -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_framed    ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u8           (&mut self, value: u8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u8                           (self, value)        }
+    #[inline] fn write_i8           (&mut self, value: i8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i8                           (self, value)        }
+    #[inline] fn write_u16          (&mut self, value: u16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u16          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i16          (&mut self, value: i16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i16          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u32          (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u32          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i32          (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i32          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u24          (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u24          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i24          (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i24          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u64          (&mut self, value: u64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u64          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i64          (&mut self, value: i64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i64          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u128         (&mut self, value: u128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u128         ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i128         (&mut self, value: i128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i128         ::<LittleEndian>(self, value)        }
+    #[inline] fn write_uint         (&mut self, value: u64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint         ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_int          (&mut self, value: i64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int          ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_uint128      (&mut self, value: u128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint128      ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_int128       (&mut self, value: i128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int128       ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_f32          (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f32          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_f64          (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f64          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_f16          (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f16          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_bf16         (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_bf16         ::<LittleEndian>(self, value)        }
+    #[inline] fn write_f80          (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f80          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_ibm_f32      (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_ibm_f32      ::<LittleEndian>(self, value)        }
+    #[inline] fn write_ibm_f64      (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_ibm_f64      ::<LittleEndian>(self, value)        }
+    #[inline] fn write_vax_f        (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_f        ::<LittleEndian>(self, value)        }
+    #[inline] fn write_vax_d        (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_d        ::<LittleEndian>(self, value)        }
+    #[inline] fn write_vax_g        (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_g        ::<LittleEndian>(self, value)        }
+
+    #[inline] fn write_slice_u16    (&mut self, values: &[u16])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u16    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i16    (&mut self, values: &[i16])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i16    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_u32    (&mut self, values: &[u32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u32    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i32    (&mut self, values: &[i32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i32    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_u64    (&mut self, values: &[u64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u64    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i64    (&mut self, values: &[i64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i64    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_u128   (&mut self, values: &[u128])           -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u128   ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i128   (&mut self, values: &[i128])           -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i128   ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f32    (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f32    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f64    (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f64    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f16    (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f16    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_bf16   (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_bf16   ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f80    (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f80    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_ibm_f32(&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_ibm_f32::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_ibm_f64(&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_ibm_f64::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_vax_f  (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_f  ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_vax_d  (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_d  ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_vax_g  (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_g  ::<LittleEndian>(self, values)       }
+
+    #[inline] fn write_framed       (&mut self, value: &[u8])              -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_framed       ::<LittleEndian>(self, value)        }
+
+    #[inline]
+    fn write_framed_format(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_framed_format::<LittleEndian>(self, format, value)
+    }
+
+    #[inline]
+    fn write_framed_vectored(&mut self, value: &[u8]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_framed_vectored::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_frames(&mut self, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_frames::<LittleEndian>(self, values)
+    }
+
+    #[inline]
+    fn write_string(&mut self, format: FrameFormat, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_string::<LittleEndian>(self, format, value)
+    }
+
+    #[inline]
+    fn write_cstring(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_cstring(self, value)
+    }
+
+    #[inline]
+    fn write_fixed_string(&mut self, value: &str, width: usize, padding: Padding) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_fixed_string(self, value, width, padding)
+    }
+
+    #[inline]
+    fn write_utf16(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf16_nul(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16_nul::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf16_bom(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16_bom::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_nul(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32_nul::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_bom(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32_bom::<LittleEndian>(self, value)
+    }
 }
 
 impl<T> BiteWriteExt for T where T: Write + ?Sized { }
+
+
+
+pub trait BiteWriteLeExt: Write {
+    #[inline] fn write_u8_le           (&mut self, value: u8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u8                           (self, value)        }
+    #[inline] fn write_i8_le           (&mut self, value: i8)                 -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i8                           (self, value)        }
+    #[inline] fn write_u16_le          (&mut self, value: u16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u16          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i16_le          (&mut self, value: i16)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i16          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u32_le          (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u32          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i32_le          (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i32          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u24_le          (&mut self, value: u32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u24          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i24_le          (&mut self, value: i32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i24          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u64_le          (&mut self, value: u64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u64          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i64_le          (&mut self, value: i64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i64          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_u128_le         (&mut self, value: u128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_u128         ::<LittleEndian>(self, value)        }
+    #[inline] fn write_i128_le         (&mut self, value: i128)               -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_i128         ::<LittleEndian>(self, value)        }
+    #[inline] fn write_uint_le         (&mut self, value: u64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint         ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_int_le          (&mut self, value: i64,  bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int          ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_uint128_le      (&mut self, value: u128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_uint128      ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_int128_le       (&mut self, value: i128, bytes: usize) -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_int128       ::<LittleEndian>(self, value, bytes) }
+    #[inline] fn write_f32_le          (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f32          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_f64_le          (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f64          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_f16_le          (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f16          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_bf16_le         (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_bf16         ::<LittleEndian>(self, value)        }
+    #[inline] fn write_f80_le          (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_f80          ::<LittleEndian>(self, value)        }
+    #[inline] fn write_ibm_f32_le      (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_ibm_f32      ::<LittleEndian>(self, value)        }
+    #[inline] fn write_ibm_f64_le      (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_ibm_f64      ::<LittleEndian>(self, value)        }
+    #[inline] fn write_vax_f_le        (&mut self, value: f32)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_f        ::<LittleEndian>(self, value)        }
+    #[inline] fn write_vax_d_le        (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_d        ::<LittleEndian>(self, value)        }
+    #[inline] fn write_vax_g_le        (&mut self, value: f64)                -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_vax_g        ::<LittleEndian>(self, value)        }
+
+    #[inline] fn write_slice_u16_le    (&mut self, values: &[u16])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u16    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i16_le    (&mut self, values: &[i16])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i16    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_u32_le    (&mut self, values: &[u32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u32    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i32_le    (&mut self, values: &[i32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i32    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_u64_le    (&mut self, values: &[u64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u64    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i64_le    (&mut self, values: &[i64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i64    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_u128_le   (&mut self, values: &[u128])           -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_u128   ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_i128_le   (&mut self, values: &[i128])           -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_i128   ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f32_le    (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f32    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f64_le    (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f64    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f16_le    (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f16    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_bf16_le   (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_bf16   ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_f80_le    (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_f80    ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_ibm_f32_le(&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_ibm_f32::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_ibm_f64_le(&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_ibm_f64::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_vax_f_le  (&mut self, values: &[f32])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_f  ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_vax_d_le  (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_d  ::<LittleEndian>(self, values)       }
+    #[inline] fn write_slice_vax_g_le  (&mut self, values: &[f64])            -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_slice_vax_g  ::<LittleEndian>(self, values)       }
+
+    #[inline] fn write_framed_le       (&mut self, value: &[u8])              -> Result<(), std::io::Error> { BiteWriteExpandedExt::write_framed       ::<LittleEndian>(self, value)        }
+
+    #[inline]
+    fn write_framed_format_le(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_framed_format::<LittleEndian>(self, format, value)
+    }
+
+    #[inline]
+    fn write_framed_vectored_le(&mut self, value: &[u8]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_framed_vectored::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_frames_le(&mut self, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_frames::<LittleEndian>(self, values)
+    }
+
+    #[inline]
+    fn write_string_le(&mut self, format: FrameFormat, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_string::<LittleEndian>(self, format, value)
+    }
+
+    #[inline]
+    fn write_utf16_le(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf16_nul_le(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16_nul::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf16_bom_le(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf16_bom::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_le(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_nul_le(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32_nul::<LittleEndian>(self, value)
+    }
+
+    #[inline]
+    fn write_utf32_bom_le(&mut self, value: &str) -> Result<(), std::io::Error> {
+        BiteWriteExpandedExt::write_utf32_bom::<LittleEndian>(self, value)
+    }
+}
+
+impl<T> BiteWriteLeExt for T where T: Write + ?Sized { }
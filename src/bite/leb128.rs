@@ -0,0 +1,243 @@
+use {
+    core,
+
+    bite::Error,
+};
+
+
+
+// the longest possible encoding of a 128-bit value, in bytes.
+pub(crate) const MAXIMUM_LENGTH: usize = 19;
+
+
+
+// reads and writes little endian base 128 variable-length integers (as used by webassembly, dwarf and protobuf).
+//
+// `read_*` functions return the decoded value and the number of bytes that it occupied in `stream`. encodings that
+// are longer than `ceil(bits / 7)` bytes, or whose final byte sets bits that don't fit in the requested type, are
+// rejected with `Error::InvalidData` (`std::io::ErrorKind::InvalidData` from the stream traits). non-minimal
+// encodings padded with continuation bytes are accepted as long as they stay within that length, matching the
+// webassembly and dwarf specifications.
+//
+// `write_*` functions emit the shortest encoding and return the number of bytes written. like the `Endianness`
+// functions, they panic if `stream` is too short - `size_unsigned` and `size_signed` give the required length.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Leb128 { }
+
+macro_rules! leb128_functions {
+    ($(($unsigned: ident, $signed: ident, $read_unsigned: ident, $read_signed: ident, $write_unsigned: ident,
+        $write_signed: ident, $read_slice_unsigned: ident, $read_slice_signed: ident, $write_slice_unsigned: ident,
+        $write_slice_signed: ident)),*) => {
+        $(
+            #[inline]
+            pub fn $read_unsigned(stream: &[u8]) -> Result<($unsigned, usize), Error> {
+                let mut bytes = SliceBytes::new(stream);
+                let value     = decode_unsigned(bits::<$unsigned>(), || bytes.next())?;
+
+                Ok((value as $unsigned, bytes.position))
+            }
+
+            #[inline]
+            pub fn $read_signed(stream: &[u8]) -> Result<($signed, usize), Error> {
+                let mut bytes = SliceBytes::new(stream);
+                let value     = decode_signed(bits::<$signed>(), || bytes.next())?;
+
+                Ok((value as $signed, bytes.position))
+            }
+
+            #[inline]
+            pub fn $write_unsigned(stream: &mut [u8], value: $unsigned) -> usize {
+                let (data, length) = encode_unsigned(value as u128);
+
+                stream[..length].copy_from_slice(&data[..length]);
+                length
+            }
+
+            #[inline]
+            pub fn $write_signed(stream: &mut [u8], value: $signed) -> usize {
+                let (data, length) = encode_signed(value as i128);
+
+                stream[..length].copy_from_slice(&data[..length]);
+                length
+            }
+
+            #[inline]
+            pub fn $read_slice_unsigned(stream: &[u8], values: &mut [$unsigned]) -> Result<usize, Error> {
+                let mut position = 0;
+
+                for x in values.iter_mut() {
+                    let (value, length) = Self::$read_unsigned(&stream[position..])?;
+
+                    *x        = value;
+                    position += length;
+                }
+
+                Ok(position)
+            }
+
+            #[inline]
+            pub fn $read_slice_signed(stream: &[u8], values: &mut [$signed]) -> Result<usize, Error> {
+                let mut position = 0;
+
+                for x in values.iter_mut() {
+                    let (value, length) = Self::$read_signed(&stream[position..])?;
+
+                    *x        = value;
+                    position += length;
+                }
+
+                Ok(position)
+            }
+
+            #[inline]
+            pub fn $write_slice_unsigned(stream: &mut [u8], values: &[$unsigned]) -> usize {
+                values.iter().fold(0, |position, &x| position + Self::$write_unsigned(&mut stream[position..], x))
+            }
+
+            #[inline]
+            pub fn $write_slice_signed(stream: &mut [u8], values: &[$signed]) -> usize {
+                values.iter().fold(0, |position, &x| position + Self::$write_signed(&mut stream[position..], x))
+            }
+        )*
+    };
+}
+
+impl Leb128 {
+    leb128_functions!(
+        (u16,  i16,  read_u16,  read_i16,  write_u16,  write_i16,  read_slice_u16,  read_slice_i16,  write_slice_u16,  write_slice_i16),
+        (u32,  i32,  read_u32,  read_i32,  write_u32,  write_i32,  read_slice_u32,  read_slice_i32,  write_slice_u32,  write_slice_i32),
+        (u64,  i64,  read_u64,  read_i64,  write_u64,  write_i64,  read_slice_u64,  read_slice_i64,  write_slice_u64,  write_slice_i64),
+        (u128, i128, read_u128, read_i128, write_u128, write_i128, read_slice_u128, read_slice_i128, write_slice_u128, write_slice_i128));
+
+    // the number of bytes that the shortest encoding of `value` occupies.
+    #[inline] pub fn size_unsigned(value: u128) -> usize { encode_unsigned(value).1 }
+    #[inline] pub fn size_signed  (value: i128) -> usize { encode_signed(value).1   }
+}
+
+
+
+// a cursor over a byte slice that reports running out of bytes as a short buffer.
+struct SliceBytes<'a> {
+    stream:   &'a [u8],
+    position: usize,
+}
+
+impl<'a> SliceBytes<'a> {
+    fn new(stream: &'a [u8]) -> SliceBytes<'a> {
+        SliceBytes { stream, position: 0 }
+    }
+
+    fn next(&mut self) -> Result<u8, Error> {
+        match self.stream.get(self.position) {
+            Some(&x) => { self.position += 1; Ok(x) },
+            None     => Err(Error::ShortBuffer { needed: self.position + 1, available: self.stream.len() }),
+        }
+    }
+}
+
+
+
+#[inline]
+fn bits<T>() -> u32 {
+    (core::mem::size_of::<T>() * 8) as u32
+}
+
+// decodes an unsigned value of at most `bits` bits, pulling bytes from `next` until the final byte is seen.
+#[inline]
+pub(crate) fn decode_unsigned<F, E>(bits: u32, mut next: F) -> Result<u128, E>
+    where F: FnMut() -> Result<u8, E>, E: From<Error>
+{
+    let maximum   = bits.div_ceil(7);
+    let mut value = 0u128;
+
+    for i in 0..maximum {
+        let byte  = next()?;
+        let shift = i * 7;
+
+        value |= ((byte & 0x7f) as u128) << shift;
+
+        if byte & 0x80 == 0 {
+            // the final byte may only use as many bits as remain in the type.
+            if bits - shift < 7 && (byte & 0x7f) >> (bits - shift) != 0 {
+                return Err(Error::InvalidData("leb128 value overflows its type").into());
+            }
+
+            return Ok(value);
+        }
+    }
+
+    Err(Error::InvalidData("leb128 encoding is overlong").into())
+}
+
+// decodes a signed value of at most `bits` bits, pulling bytes from `next` until the final byte is seen.
+#[inline]
+pub(crate) fn decode_signed<F, E>(bits: u32, mut next: F) -> Result<i128, E>
+    where F: FnMut() -> Result<u8, E>, E: From<Error>
+{
+    let maximum   = bits.div_ceil(7);
+    let mut value = 0u128;
+
+    for i in 0..maximum {
+        let byte  = next()?;
+        let shift = i * 7;
+
+        value |= ((byte & 0x7f) as u128) << shift;
+
+        if byte & 0x80 == 0 {
+            // the bits of the final byte past the type's sign bit must all be copies of that sign bit.
+            if bits - shift <= 7 {
+                let unused = (byte & 0x7f) >> (bits - shift - 1);
+
+                if unused != 0 && unused != 0x7f >> (bits - shift - 1) {
+                    return Err(Error::InvalidData("leb128 value overflows its type").into());
+                }
+            }
+
+            if shift + 7 < 128 && byte & 0x40 != 0 {
+                value |= !0 << (shift + 7);
+            }
+
+            return Ok(value as i128);
+        }
+    }
+
+    Err(Error::InvalidData("leb128 encoding is overlong").into())
+}
+
+#[inline]
+pub(crate) fn encode_unsigned(mut value: u128) -> ([u8; MAXIMUM_LENGTH], usize) {
+    let mut data   = [0; MAXIMUM_LENGTH];
+    let mut length = 0;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            data[length] = byte;
+            return (data, length + 1);
+        }
+
+        data[length] = byte | 0x80;
+        length      += 1;
+    }
+}
+
+#[inline]
+pub(crate) fn encode_signed(mut value: i128) -> ([u8; MAXIMUM_LENGTH], usize) {
+    let mut data   = [0; MAXIMUM_LENGTH];
+    let mut length = 0;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            data[length] = byte;
+            return (data, length + 1);
+        }
+
+        data[length] = byte | 0x80;
+        length      += 1;
+    }
+}
@@ -1,24 +1,99 @@
 mod endian;
 mod endian2;
 
-mod read;
-mod write;
+#[macro_use]
+mod runtime;
 
-mod le_read;
-mod le_write;
+mod decimal;
+mod error;
+mod fixed;
+mod float;
+mod frame;
+mod leb128;
+mod slice;
+mod string;
+mod byte_reader;
 
+#[cfg(feature = "std")] mod read;
+#[cfg(feature = "std")] mod write;
 
+#[cfg(feature = "std")] mod le_read;
+#[cfg(feature = "std")] mod le_write;
 
+#[cfg(feature = "std")] mod be_read;
+#[cfg(feature = "std")] mod be_write;
+
+#[cfg(feature = "std")] mod bits;
+#[cfg(feature = "std")] mod byte_writer;
+#[cfg(feature = "std")] mod value;
+
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+#[macro_use]
+pub mod future;
+
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+#[cfg(feature = "futures-io")]
+pub mod futures;
+
+#[cfg(feature = "tokio-util")]
+mod codec;
+
+
+
+pub use bite::{
+    endian     ::BigEndian,
+    endian     ::Endianness,
+    endian     ::LittleEndian,
+    endian     ::NativeEndian,
+    endian     ::NetworkEndian,
+
+    runtime    ::Endian,
+
+    decimal    ::Decimal,
+    error      ::Error,
+    fixed      ::Fixed,
+    fixed      ::FixedBits,
+    fixed      ::Q15,
+    fixed      ::Q31,
+    frame      ::FrameFormat,
+    frame      ::FramePrefix,
+    leb128     ::Leb128,
+
+    slice      ::SliceReader,
+    slice      ::SliceWriter,
+
+    string     ::Padding,
+
+    byte_reader::ByteReader,
+};
+
+#[cfg(feature = "std")]
 pub use bite::{
-    endian  ::BigEndian,
-    endian  ::Endianness,
-    endian  ::LittleEndian,
-    endian  ::NativeEndian,
-    endian  ::NetworkEndian,
+    read       ::BiteReadExpandedExt,
+    write      ::BiteWriteExpandedExt,
+
+    le_read    ::BiteReadExt,
+    le_read    ::BiteReadLeExt,
+    le_write   ::BiteWriteExt,
+    le_write   ::BiteWriteLeExt,
 
-    read    ::BiteReadExpandedExt,
-    write   ::BiteWriteExpandedExt,
+    be_read    ::BiteReadBeExt,
+    be_write   ::BiteWriteBeExt,
 
-    le_read ::BiteReadExt,
-    le_write::BiteWriteExt,
+    bits       ::BitOrder,
+    bits       ::BitReader,
+    bits       ::BitWriter,
+
+    byte_writer::ByteWriter,
+    byte_writer::Slot,
+
+    value      ::BiteRead,
+    value      ::BiteWrite,
+};
+
+#[cfg(feature = "tokio-util")]
+pub use bite::{
+    codec      ::BiteFrameCodec,
 };
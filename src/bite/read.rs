@@ -2,7 +2,23 @@ use {
     std,
     std::io::Read,
 
+    bite::BigEndian,
+    bite::Decimal,
+    bite::Endian,
     bite::Endianness,
+    bite::Error,
+    bite::Fixed,
+    bite::FixedBits,
+    bite::FrameFormat,
+    bite::LittleEndian,
+    bite::Padding,
+    bite::decimal,
+    bite::decimal::MAXIMUM_PACKED_LENGTH,
+    bite::decimal::MAXIMUM_ZONED_LENGTH,
+    bite::error::check_width,
+    bite::frame::MAXIMUM_PREFIX_LENGTH,
+    bite::leb128,
+    bite::write::STAGING_LENGTH,
 };
 
 
@@ -46,7 +62,7 @@ pub trait BiteReadExpandedExt: Read {
 
     #[inline]
     fn read_u24<T: Endianness>(&mut self) -> Result<u32, std::io::Error> {
-        let mut data = [0; 4];
+        let mut data = [0; 3];
         self.read_exact(&mut data)?;
 
         let v = T::read_u24(&data);
@@ -55,7 +71,7 @@ pub trait BiteReadExpandedExt: Read {
 
     #[inline]
     fn read_i24<T: Endianness>(&mut self) -> Result<i32, std::io::Error> {
-        let mut data = [0; 4];
+        let mut data = [0; 3];
         self.read_exact(&mut data)?;
 
         let v = T::read_i24(&data);
@@ -170,6 +186,80 @@ pub trait BiteReadExpandedExt: Read {
         Ok(v)
     }
 
+    #[inline]
+    fn read_f16<T: Endianness>(&mut self) -> Result<f32, std::io::Error> {
+        let mut data = [0; 2];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_f16(&data);
+        Ok(v)
+    }
+
+    #[inline]
+    fn read_bf16<T: Endianness>(&mut self) -> Result<f32, std::io::Error> {
+        let mut data = [0; 2];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_bf16(&data);
+        Ok(v)
+    }
+
+    // reads an x87 80-bit extended float, returning it rounded to an `f64` and whether that lost any precision.
+    #[inline]
+    fn read_f80<T: Endianness>(&mut self) -> Result<(f64, bool), std::io::Error> {
+        let mut data = [0; 10];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_f80(&data);
+        Ok(v)
+    }
+
+    // reads an ibm hexadecimal or vax float, as described by `Endianness::read_ibm_f32`.
+    #[inline]
+    fn read_ibm_f32<T: Endianness>(&mut self) -> Result<f32, std::io::Error> {
+        let mut data = [0; 4];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_ibm_f32(&data);
+        Ok(v)
+    }
+
+    #[inline]
+    fn read_ibm_f64<T: Endianness>(&mut self) -> Result<f64, std::io::Error> {
+        let mut data = [0; 8];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_ibm_f64(&data);
+        Ok(v)
+    }
+
+    #[inline]
+    fn read_vax_f<T: Endianness>(&mut self) -> Result<f32, std::io::Error> {
+        let mut data = [0; 4];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_vax_f(&data);
+        Ok(v)
+    }
+
+    #[inline]
+    fn read_vax_d<T: Endianness>(&mut self) -> Result<f64, std::io::Error> {
+        let mut data = [0; 8];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_vax_d(&data);
+        Ok(v)
+    }
+
+    #[inline]
+    fn read_vax_g<T: Endianness>(&mut self) -> Result<f64, std::io::Error> {
+        let mut data = [0; 8];
+        self.read_exact(&mut data)?;
+
+        let v = T::read_vax_g(&data);
+        Ok(v)
+    }
+
 
 
     #[inline]
@@ -252,6 +342,156 @@ pub trait BiteReadExpandedExt: Read {
         Ok(())
     }
 
+    #[inline]
+    fn read_slice_f16<T: Endianness>(&mut self, values: &mut [f32]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 2, T::read_slice_f16)
+    }
+
+    #[inline]
+    fn read_slice_bf16<T: Endianness>(&mut self, values: &mut [f32]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 2, T::read_slice_bf16)
+    }
+
+    // as `read_f80`, returning whether any of the values lost precision.
+    #[inline]
+    fn read_slice_f80<T: Endianness>(&mut self, values: &mut [f64]) -> Result<bool, std::io::Error> {
+        let mut stage   = [0u8; STAGING_LENGTH];
+        let mut rounded = false;
+
+        for chunk in values.chunks_mut(STAGING_LENGTH / 10) {
+            let data = &mut stage[..10 * chunk.len()];
+
+            self.read_exact(data)?;
+            rounded |= T::read_slice_f80(data, chunk);
+        }
+
+        Ok(rounded)
+    }
+
+    #[inline]
+    fn read_slice_ibm_f32<T: Endianness>(&mut self, values: &mut [f32]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 4, T::read_slice_ibm_f32)
+    }
+
+    #[inline]
+    fn read_slice_ibm_f64<T: Endianness>(&mut self, values: &mut [f64]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 8, T::read_slice_ibm_f64)
+    }
+
+    #[inline]
+    fn read_slice_vax_f<T: Endianness>(&mut self, values: &mut [f32]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 4, T::read_slice_vax_f)
+    }
+
+    #[inline]
+    fn read_slice_vax_d<T: Endianness>(&mut self, values: &mut [f64]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 8, T::read_slice_vax_d)
+    }
+
+    #[inline]
+    fn read_slice_vax_g<T: Endianness>(&mut self, values: &mut [f64]) -> Result<(), std::io::Error> {
+        read_slice_as(self, values, 8, T::read_slice_vax_g)
+    }
+
+    // reads a q format number from a `bytes` wide integer - see `Fixed`.
+    #[inline]
+    fn read_fixed_point<T, I, const FRAC: u32>(&mut self, bytes: usize) -> Result<Fixed<I, FRAC>, std::io::Error>
+        where T: Endianness, I: FixedBits
+    {
+        let mut data = [0; 8];
+        self.read_exact(&mut data[..check_width(bytes, I::BYTES)?])?;
+
+        let v = Fixed::read::<T>(&data[..bytes], bytes);
+        Ok(v)
+    }
+
+    #[inline]
+    fn read_slice_fixed_point<T, I, const FRAC: u32>(&mut self, values: &mut [Fixed<I, FRAC>], bytes: usize) -> Result<(), std::io::Error>
+        where T: Endianness, I: FixedBits
+    {
+        let mut stage = [0u8; STAGING_LENGTH];
+
+        for chunk in values.chunks_mut(STAGING_LENGTH / check_width(bytes, I::BYTES)?) {
+            let data = &mut stage[..bytes * chunk.len()];
+
+            self.read_exact(data)?;
+            Fixed::read_slice::<T>(data, chunk, bytes);
+        }
+
+        Ok(())
+    }
+
+
+
+    #[inline]
+    fn read_leb128_u16(&mut self) -> Result<u16, std::io::Error> {
+        let v = leb128::decode_unsigned(16, || self.read_u8())?;
+        Ok(v as u16)
+    }
+
+    #[inline]
+    fn read_leb128_i16(&mut self) -> Result<i16, std::io::Error> {
+        let v = leb128::decode_signed(16, || self.read_u8())?;
+        Ok(v as i16)
+    }
+
+    #[inline]
+    fn read_leb128_u32(&mut self) -> Result<u32, std::io::Error> {
+        let v = leb128::decode_unsigned(32, || self.read_u8())?;
+        Ok(v as u32)
+    }
+
+    #[inline]
+    fn read_leb128_i32(&mut self) -> Result<i32, std::io::Error> {
+        let v = leb128::decode_signed(32, || self.read_u8())?;
+        Ok(v as i32)
+    }
+
+    #[inline]
+    fn read_leb128_u64(&mut self) -> Result<u64, std::io::Error> {
+        let v = leb128::decode_unsigned(64, || self.read_u8())?;
+        Ok(v as u64)
+    }
+
+    #[inline]
+    fn read_leb128_i64(&mut self) -> Result<i64, std::io::Error> {
+        let v = leb128::decode_signed(64, || self.read_u8())?;
+        Ok(v as i64)
+    }
+
+    #[inline]
+    fn read_leb128_u128(&mut self) -> Result<u128, std::io::Error> {
+        leb128::decode_unsigned(128, || self.read_u8())
+    }
+
+    #[inline]
+    fn read_leb128_i128(&mut self) -> Result<i128, std::io::Error> {
+        leb128::decode_signed(128, || self.read_u8())
+    }
+
+    // reads a packed bcd field of `bytes` bytes, at most 16, whose last `scale` digits follow the decimal point -
+    // which isn't recorded in the field, and comes from the record layout. fails with `ErrorKind::InvalidInput` if
+    // `scale` is more than the `2 * bytes - 1` digits that the field holds, and `ErrorKind::InvalidData` if a nibble
+    // isn't a digit, or the last isn't a sign.
+    #[inline]
+    fn read_packed_bcd(&mut self, bytes: usize, scale: u32) -> Result<Decimal, std::io::Error> {
+        let mut data = [0; MAXIMUM_PACKED_LENGTH];
+        self.read_exact(&mut data[..decimal::check_packed(bytes, scale)?])?;
+
+        let value = decimal::decode_packed(&data[..bytes])?;
+        Ok(Decimal::new(value, scale))
+    }
+
+    // as `read_packed_bcd`, for an ebcdic zoned decimal field of at most 31 bytes - a digit apiece.
+    #[inline]
+    fn read_zoned_decimal(&mut self, bytes: usize, scale: u32) -> Result<Decimal, std::io::Error> {
+        let mut data = [0; MAXIMUM_ZONED_LENGTH];
+        self.read_exact(&mut data[..decimal::check_zoned(bytes, scale)?])?;
+
+        let value = decimal::decode_zoned(&data[..bytes])?;
+        Ok(Decimal::new(value, scale))
+    }
+
 
 
     #[inline]
@@ -261,28 +501,620 @@ pub trait BiteReadExpandedExt: Read {
 
     #[inline]
     fn read_framed_max<T: Endianness>(&mut self, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
-        let length = match self.read_u32::<T>()? as usize {
-            x if x <= maximum => x,
-            _                 => return Err(std::io::ErrorKind::InvalidData.into()),
-        };
+        self.read_framed_format::<T>(FrameFormat::U32, maximum)
+    }
+
+    // reads a frame whose length prefix is laid out as `format` describes.
+    #[inline]
+    fn read_framed_format<T: Endianness>(&mut self, format: FrameFormat, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+        let mut data = Vec::new();
+
+        self.read_framed_format_into::<T>(format, maximum, &mut data)?;
+        Ok(data)
+    }
+
+    // reads a frame into `buffer`, replacing its contents, so that one allocation can be reused across frames.
+    #[inline]
+    fn read_framed_into<T: Endianness>(&mut self, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        self.read_framed_max_into::<T>(usize::MAX, buffer)
+    }
+
+    #[inline]
+    fn read_framed_max_into<T: Endianness>(&mut self, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        self.read_framed_format_into::<T>(FrameFormat::U32, maximum, buffer)
+    }
+
+    // the payload is read in chunks that start small and double, so the buffer only grows as data actually arrives -
+    // a length prefix on its own can't force a large allocation. `buffer` is left empty if any part of the read fails,
+    // the prefix included.
+    #[inline]
+    fn read_framed_format_into<T: Endianness>(&mut self, format: FrameFormat, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        let mut prefix = [0; MAXIMUM_PREFIX_LENGTH];
+        let mut filled = 0;
+
+        buffer.clear();
+
+        // a leb128 prefix is read a byte at a time, until its final byte - fixed width prefixes are read at once.
+        loop {
+            let needed = format.prefix_length(&prefix[..filled]);
+
+            if filled == needed {
+                break;
+            }
+
+            self.read_exact(&mut prefix[filled..needed])?;
+            filled = needed;
+        }
+
+        let (length, _) = format.read_prefix::<T>(&prefix[..filled], maximum)?;
+
+        while buffer.len() < length {
+            let filled = buffer.len();
+            let grown  = std::cmp::min(length, std::cmp::max(filled * 2, STAGING_LENGTH));
+
+            buffer.resize(grown, 0);
+
+            if let Err(x) = self.read_exact(&mut buffer[filled..]) {
+                buffer.clear();
+                return Err(x);
+            }
+        }
+
+        Ok(())
+    }
+
+
+
+    // reads a utf-8 string with a length prefix laid out as `format` describes. invalid utf-8 is rejected with
+    // `InvalidData`.
+    #[inline]
+    fn read_string<T: Endianness>(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        into_string(self.read_framed_format::<T>(format, maximum)?)
+    }
+
+    // as `read_string`, but invalid utf-8 is replaced with U+FFFD.
+    #[inline]
+    fn read_string_lossy<T: Endianness>(&mut self, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        Ok(into_string_lossy(self.read_framed_format::<T>(format, maximum)?))
+    }
+
+    // reads a nul-terminated string of at most `maximum` bytes, not counting the nul, which is consumed. the string is
+    // read a byte at a time, so wrap unbuffered streams in a `BufReader`.
+    #[inline]
+    fn read_cstring(&mut self, maximum: usize) -> Result<String, std::io::Error> {
+        into_string(read_cstring_data(self, maximum)?)
+    }
+
+    #[inline]
+    fn read_cstring_lossy(&mut self, maximum: usize) -> Result<String, std::io::Error> {
+        Ok(into_string_lossy(read_cstring_data(self, maximum)?))
+    }
+
+    // reads a string from a field of exactly `width` bytes, without its padding.
+    #[inline]
+    fn read_fixed_string(&mut self, width: usize, padding: Padding) -> Result<String, std::io::Error> {
+        into_string(read_fixed_data(self, width, padding)?)
+    }
+
+    #[inline]
+    fn read_fixed_string_lossy(&mut self, width: usize, padding: Padding) -> Result<String, std::io::Error> {
+        Ok(into_string_lossy(read_fixed_data(self, width, padding)?))
+    }
+
+
+
+    // reads `length` utf-16 code units. unpaired surrogates are rejected with `InvalidData`.
+    #[inline]
+    fn read_utf16<T: Endianness>(&mut self, length: usize) -> Result<String, std::io::Error> {
+        let mut units = vec![0; length];
+
+        self.read_slice_u16::<T>(&mut units)?;
+        from_utf16(&units)
+    }
+
+    // reads utf-16 code units up to a nul unit, which is consumed.
+    #[inline]
+    fn read_utf16_nul<T: Endianness>(&mut self) -> Result<String, std::io::Error> {
+        let mut units = Vec::new();
+
+        read_units_nul(|| self.read_u16::<T>(), &mut units)?;
+        from_utf16(&units)
+    }
+
+    // as `read_utf16`, but a byte order mark in the first unit overrides `T` and is left out of the string. `length`
+    // counts the mark.
+    #[inline]
+    fn read_utf16_bom<T: Endianness>(&mut self, length: usize) -> Result<String, std::io::Error> {
+        if length == 0 {
+            return Ok(String::new());
+        }
+
+        let mut first = [0; 2];
+        self.read_exact(&mut first)?;
+
+        match first {
+            [0xfe, 0xff] => self.read_utf16::<BigEndian>(length - 1),
+            [0xff, 0xfe] => self.read_utf16::<LittleEndian>(length - 1),
+            _            => {
+                let mut units = vec![0; length];
+
+                units[0] = T::read_u16(&first);
+                self.read_slice_u16::<T>(&mut units[1..])?;
+                from_utf16(&units)
+            },
+        }
+    }
+
+    // as `read_utf16_nul`, but a byte order mark in the first unit overrides `T` and is left out of the string.
+    #[inline]
+    fn read_utf16_nul_bom<T: Endianness>(&mut self) -> Result<String, std::io::Error> {
+        let mut first = [0; 2];
+        self.read_exact(&mut first)?;
+
+        match first {
+            [0xfe, 0xff] => self.read_utf16_nul::<BigEndian>(),
+            [0xff, 0xfe] => self.read_utf16_nul::<LittleEndian>(),
+            _            => {
+                let mut units = vec![T::read_u16(&first)];
+
+                if units[0] != 0 {
+                    read_units_nul(|| self.read_u16::<T>(), &mut units)?;
+                } else {
+                    units.clear();
+                }
+
+                from_utf16(&units)
+            },
+        }
+    }
+
+    // reads `length` utf-32 code units. units that aren't unicode scalar values are rejected with `InvalidData`.
+    #[inline]
+    fn read_utf32<T: Endianness>(&mut self, length: usize) -> Result<String, std::io::Error> {
+        let mut units = vec![0; length];
+
+        self.read_slice_u32::<T>(&mut units)?;
+        from_utf32(&units)
+    }
+
+    // reads utf-32 code units up to a nul unit, which is consumed.
+    #[inline]
+    fn read_utf32_nul<T: Endianness>(&mut self) -> Result<String, std::io::Error> {
+        let mut units = Vec::new();
+
+        read_units_nul(|| self.read_u32::<T>(), &mut units)?;
+        from_utf32(&units)
+    }
+
+    // as `read_utf32`, but a byte order mark in the first unit overrides `T` and is left out of the string. `length`
+    // counts the mark.
+    #[inline]
+    fn read_utf32_bom<T: Endianness>(&mut self, length: usize) -> Result<String, std::io::Error> {
+        if length == 0 {
+            return Ok(String::new());
+        }
+
+        let mut first = [0; 4];
+        self.read_exact(&mut first)?;
 
-        unsafe {
-            let mut data = Vec::with_capacity(length);
-            let slice    = std::slice::from_raw_parts_mut(data.as_mut_ptr(), length);
+        match first {
+            [0x00, 0x00, 0xfe, 0xff] => self.read_utf32::<BigEndian>(length - 1),
+            [0xff, 0xfe, 0x00, 0x00] => self.read_utf32::<LittleEndian>(length - 1),
+            _                        => {
+                let mut units = vec![0; length];
 
-            self.read_exact(slice)?;
-            data.set_len(length);
+                units[0] = T::read_u32(&first);
+                self.read_slice_u32::<T>(&mut units[1..])?;
+                from_utf32(&units)
+            },
+        }
+    }
 
-            Ok(data)
+    // as `read_utf32_nul`, but a byte order mark in the first unit overrides `T` and is left out of the string.
+    #[inline]
+    fn read_utf32_nul_bom<T: Endianness>(&mut self) -> Result<String, std::io::Error> {
+        let mut first = [0; 4];
+        self.read_exact(&mut first)?;
+
+        match first {
+            [0x00, 0x00, 0xfe, 0xff] => self.read_utf32_nul::<BigEndian>(),
+            [0xff, 0xfe, 0x00, 0x00] => self.read_utf32_nul::<LittleEndian>(),
+            _                        => {
+                let mut units = vec![T::read_u32(&first)];
+
+                if units[0] != 0 {
+                    read_units_nul(|| self.read_u32::<T>(), &mut units)?;
+                } else {
+                    units.clear();
+                }
+
+                from_utf32(&units)
+            },
         }
     }
+
+
+
+    #[inline]
+    fn read_u16_with(&mut self, endian: Endian) -> Result<u16, std::io::Error> {
+        with_endian!(endian, self.read_u16())
+    }
+
+    #[inline]
+    fn read_i16_with(&mut self, endian: Endian) -> Result<i16, std::io::Error> {
+        with_endian!(endian, self.read_i16())
+    }
+
+    #[inline]
+    fn read_u24_with(&mut self, endian: Endian) -> Result<u32, std::io::Error> {
+        with_endian!(endian, self.read_u24())
+    }
+
+    #[inline]
+    fn read_i24_with(&mut self, endian: Endian) -> Result<i32, std::io::Error> {
+        with_endian!(endian, self.read_i24())
+    }
+
+    #[inline]
+    fn read_u32_with(&mut self, endian: Endian) -> Result<u32, std::io::Error> {
+        with_endian!(endian, self.read_u32())
+    }
+
+    #[inline]
+    fn read_i32_with(&mut self, endian: Endian) -> Result<i32, std::io::Error> {
+        with_endian!(endian, self.read_i32())
+    }
+
+    #[inline]
+    fn read_u64_with(&mut self, endian: Endian) -> Result<u64, std::io::Error> {
+        with_endian!(endian, self.read_u64())
+    }
+
+    #[inline]
+    fn read_i64_with(&mut self, endian: Endian) -> Result<i64, std::io::Error> {
+        with_endian!(endian, self.read_i64())
+    }
+
+    #[inline]
+    fn read_u128_with(&mut self, endian: Endian) -> Result<u128, std::io::Error> {
+        with_endian!(endian, self.read_u128())
+    }
+
+    #[inline]
+    fn read_i128_with(&mut self, endian: Endian) -> Result<i128, std::io::Error> {
+        with_endian!(endian, self.read_i128())
+    }
+
+    #[inline]
+    fn read_uint_with(&mut self, endian: Endian, bytes: usize) -> Result<u64, std::io::Error> {
+        with_endian!(endian, self.read_uint(bytes))
+    }
+
+    #[inline]
+    fn read_int_with(&mut self, endian: Endian, bytes: usize) -> Result<i64, std::io::Error> {
+        with_endian!(endian, self.read_int(bytes))
+    }
+
+    #[inline]
+    fn read_uint128_with(&mut self, endian: Endian, bytes: usize) -> Result<u128, std::io::Error> {
+        with_endian!(endian, self.read_uint128(bytes))
+    }
+
+    #[inline]
+    fn read_int128_with(&mut self, endian: Endian, bytes: usize) -> Result<i128, std::io::Error> {
+        with_endian!(endian, self.read_int128(bytes))
+    }
+
+    #[inline]
+    fn read_f32_with(&mut self, endian: Endian) -> Result<f32, std::io::Error> {
+        with_endian!(endian, self.read_f32())
+    }
+
+    #[inline]
+    fn read_f64_with(&mut self, endian: Endian) -> Result<f64, std::io::Error> {
+        with_endian!(endian, self.read_f64())
+    }
+
+    #[inline]
+    fn read_f16_with(&mut self, endian: Endian) -> Result<f32, std::io::Error> {
+        with_endian!(endian, self.read_f16())
+    }
+
+    #[inline]
+    fn read_bf16_with(&mut self, endian: Endian) -> Result<f32, std::io::Error> {
+        with_endian!(endian, self.read_bf16())
+    }
+
+    #[inline]
+    fn read_f80_with(&mut self, endian: Endian) -> Result<(f64, bool), std::io::Error> {
+        with_endian!(endian, self.read_f80())
+    }
+
+    #[inline]
+    fn read_ibm_f32_with(&mut self, endian: Endian) -> Result<f32, std::io::Error> {
+        with_endian!(endian, self.read_ibm_f32())
+    }
+
+    #[inline]
+    fn read_ibm_f64_with(&mut self, endian: Endian) -> Result<f64, std::io::Error> {
+        with_endian!(endian, self.read_ibm_f64())
+    }
+
+    #[inline]
+    fn read_vax_f_with(&mut self, endian: Endian) -> Result<f32, std::io::Error> {
+        with_endian!(endian, self.read_vax_f())
+    }
+
+    #[inline]
+    fn read_vax_d_with(&mut self, endian: Endian) -> Result<f64, std::io::Error> {
+        with_endian!(endian, self.read_vax_d())
+    }
+
+    #[inline]
+    fn read_vax_g_with(&mut self, endian: Endian) -> Result<f64, std::io::Error> {
+        with_endian!(endian, self.read_vax_g())
+    }
+
+
+
+    #[inline]
+    fn read_slice_u16_with(&mut self, endian: Endian, values: &mut [u16]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_u16(values))
+    }
+
+    #[inline]
+    fn read_slice_i16_with(&mut self, endian: Endian, values: &mut [i16]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_i16(values))
+    }
+
+    #[inline]
+    fn read_slice_u32_with(&mut self, endian: Endian, values: &mut [u32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_u32(values))
+    }
+
+    #[inline]
+    fn read_slice_i32_with(&mut self, endian: Endian, values: &mut [i32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_i32(values))
+    }
+
+    #[inline]
+    fn read_slice_u64_with(&mut self, endian: Endian, values: &mut [u64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_u64(values))
+    }
+
+    #[inline]
+    fn read_slice_i64_with(&mut self, endian: Endian, values: &mut [i64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_i64(values))
+    }
+
+    #[inline]
+    fn read_slice_u128_with(&mut self, endian: Endian, values: &mut [u128]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_u128(values))
+    }
+
+    #[inline]
+    fn read_slice_i128_with(&mut self, endian: Endian, values: &mut [i128]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_i128(values))
+    }
+
+    #[inline]
+    fn read_slice_f32_with(&mut self, endian: Endian, values: &mut [f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_f32(values))
+    }
+
+    #[inline]
+    fn read_slice_f64_with(&mut self, endian: Endian, values: &mut [f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_f64(values))
+    }
+
+    #[inline]
+    fn read_slice_f16_with(&mut self, endian: Endian, values: &mut [f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_f16(values))
+    }
+
+    #[inline]
+    fn read_slice_bf16_with(&mut self, endian: Endian, values: &mut [f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_bf16(values))
+    }
+
+    #[inline]
+    fn read_slice_f80_with(&mut self, endian: Endian, values: &mut [f64]) -> Result<bool, std::io::Error> {
+        with_endian!(endian, self.read_slice_f80(values))
+    }
+
+    #[inline]
+    fn read_slice_ibm_f32_with(&mut self, endian: Endian, values: &mut [f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_ibm_f32(values))
+    }
+
+    #[inline]
+    fn read_slice_ibm_f64_with(&mut self, endian: Endian, values: &mut [f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_ibm_f64(values))
+    }
+
+    #[inline]
+    fn read_slice_vax_f_with(&mut self, endian: Endian, values: &mut [f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_vax_f(values))
+    }
+
+    #[inline]
+    fn read_slice_vax_d_with(&mut self, endian: Endian, values: &mut [f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_vax_d(values))
+    }
+
+    #[inline]
+    fn read_slice_vax_g_with(&mut self, endian: Endian, values: &mut [f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_slice_vax_g(values))
+    }
+
+
+
+    #[inline]
+    fn read_framed_with(&mut self, endian: Endian) -> Result<Vec<u8>, std::io::Error> {
+        with_endian!(endian, self.read_framed())
+    }
+
+    #[inline]
+    fn read_framed_max_with(&mut self, endian: Endian, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+        with_endian!(endian, self.read_framed_max(maximum))
+    }
+
+    #[inline]
+    fn read_framed_format_with(&mut self, endian: Endian, format: FrameFormat, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+        with_endian!(endian, self.read_framed_format(format, maximum))
+    }
+
+    #[inline]
+    fn read_framed_into_with(&mut self, endian: Endian, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_framed_into(buffer))
+    }
+
+    #[inline]
+    fn read_framed_max_into_with(&mut self, endian: Endian, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_framed_max_into(maximum, buffer))
+    }
+
+    #[inline]
+    fn read_framed_format_into_with(&mut self, endian: Endian, format: FrameFormat, maximum: usize, buffer: &mut Vec<u8>) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.read_framed_format_into(format, maximum, buffer))
+    }
+
+    #[inline]
+    fn read_string_with(&mut self, endian: Endian, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_string(format, maximum))
+    }
+
+    #[inline]
+    fn read_string_lossy_with(&mut self, endian: Endian, format: FrameFormat, maximum: usize) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_string_lossy(format, maximum))
+    }
+
+    #[inline]
+    fn read_utf16_with(&mut self, endian: Endian, length: usize) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf16(length))
+    }
+
+    #[inline]
+    fn read_utf16_nul_with(&mut self, endian: Endian) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf16_nul())
+    }
+
+    #[inline]
+    fn read_utf16_bom_with(&mut self, endian: Endian, length: usize) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf16_bom(length))
+    }
+
+    #[inline]
+    fn read_utf16_nul_bom_with(&mut self, endian: Endian) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf16_nul_bom())
+    }
+
+    #[inline]
+    fn read_utf32_with(&mut self, endian: Endian, length: usize) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf32(length))
+    }
+
+    #[inline]
+    fn read_utf32_nul_with(&mut self, endian: Endian) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf32_nul())
+    }
+
+    #[inline]
+    fn read_utf32_bom_with(&mut self, endian: Endian, length: usize) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf32_bom(length))
+    }
+
+    #[inline]
+    fn read_utf32_nul_bom_with(&mut self, endian: Endian) -> Result<String, std::io::Error> {
+        with_endian!(endian, self.read_utf32_nul_bom())
+    }
 }
 
 impl<T> BiteReadExpandedExt for T where T: std::io::Read + ?Sized { }
 
 
 
-unsafe fn as_mutable_data<T: Copy>(data: &mut [T]) -> &mut [u8] {
+#[inline]
+fn into_string(data: Vec<u8>) -> Result<String, std::io::Error> {
+    String::from_utf8(data).map_err(|_| Error::InvalidData("string is not valid utf-8").into())
+}
+
+#[inline]
+fn into_string_lossy(data: Vec<u8>) -> String {
+    match String::from_utf8(data) {
+        Ok(x)  => x,
+        Err(x) => String::from_utf8_lossy(x.as_bytes()).into_owned(),
+    }
+}
+
+fn read_cstring_data<R: Read + ?Sized>(stream: &mut R, maximum: usize) -> Result<Vec<u8>, std::io::Error> {
+    let mut data = Vec::new();
+
+    loop {
+        match stream.read_u8()? {
+            0                          => return Ok(data),
+            _ if data.len() == maximum => return Err(Error::InvalidData("c string exceeds the maximum length").into()),
+            x                          => data.push(x),
+        }
+    }
+}
+
+// pushes units from `read` into `units` until a nul unit, which is left out.
+#[inline]
+fn read_units_nul<T, F>(mut read: F, units: &mut Vec<T>) -> Result<(), std::io::Error> where T: Default + PartialEq, F: FnMut() -> Result<T, std::io::Error> {
+    loop {
+        match read()? {
+            x if x == T::default() => return Ok(()),
+            x                      => units.push(x),
+        }
+    }
+}
+
+#[inline]
+fn from_utf16(units: &[u16]) -> Result<String, std::io::Error> {
+    std::char::decode_utf16(units.iter().cloned())
+        .collect::<Result<String, _>>()
+        .map_err(|_| Error::InvalidData("string has an unpaired utf-16 surrogate").into())
+}
+
+#[inline]
+fn from_utf32(units: &[u32]) -> Result<String, std::io::Error> {
+    units.iter()
+        .map(|&x| std::char::from_u32(x))
+        .collect::<Option<String>>()
+        .ok_or_else(|| Error::InvalidData("string has a utf-32 unit that isn't a unicode scalar value").into())
+}
+
+fn read_fixed_data<R: Read + ?Sized>(stream: &mut R, width: usize, padding: Padding) -> Result<Vec<u8>, std::io::Error> {
+    let mut data = vec![0; width];
+    stream.read_exact(&mut data)?;
+
+    let length = padding.trim(&data).len();
+
+    data.truncate(length);
+    Ok(data)
+}
+
+// reads floats that are encoded as `width` bytes apiece through a staging buffer, since their size in the stream
+// differs from their size in memory.
+fn read_slice_as<R, P>(stream: &mut R, values: &mut [P], width: usize, read: fn(&[u8], &mut [P])) -> Result<(), std::io::Error>
+    where R: Read + ?Sized
+{
+    let mut stage = [0u8; STAGING_LENGTH];
+
+    for chunk in values.chunks_mut(STAGING_LENGTH / width) {
+        let data = &mut stage[..width * chunk.len()];
+
+        stream.read_exact(data)?;
+        read(data, chunk);
+    }
+
+    Ok(())
+}
+
+
+
+pub(crate) unsafe fn as_mutable_data<T: Copy>(data: &mut [T]) -> &mut [u8] {
     let source = data.as_mut_ptr() as *mut u8;
     let length = std::mem::size_of::<T>() * data.len();
 
@@ -0,0 +1,277 @@
+use {
+    bite::BigEndian,
+    bite::Endianness,
+    bite::Error,
+    bite::LittleEndian,
+};
+
+
+
+// invokes an `Endianness` function on the encoding that `endian` selects.
+//
+// example usage:
+//
+//     dispatch!(self, read_u16(stream))
+//
+macro_rules! dispatch {
+    ($endian: expr, $function: ident ($($argument: expr),*)) => ({
+        match $endian {
+            Endian::Big    => BigEndian   ::$function($($argument),*),
+            Endian::Little => LittleEndian::$function($($argument),*),
+        }
+    });
+}
+
+// invokes a generic stream method with the type-level encoding that `endian` selects.
+//
+// example usage:
+//
+//     with_endian!(endian, self.read_u16())
+//     with_endian!(endian, self.write_u16(value))
+//
+#[cfg(feature = "std")]
+macro_rules! with_endian {
+    ($endian: expr, $stream: ident . $method: ident ($($argument: expr),*)) => ({
+        match $endian {
+            ::bite::Endian::Big    => $stream.$method::<::bite::BigEndian>   ($($argument),*),
+            ::bite::Endian::Little => $stream.$method::<::bite::LittleEndian>($($argument),*),
+        }
+    });
+}
+
+
+
+// a byte order that is selected at runtime, for formats that declare their encoding in a header (tiff, pcap, elf).
+//
+// this offers the same operations as `Endianness`, dispatching to `BigEndian` or `LittleEndian`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Endian {
+    Big,
+    Little,
+}
+
+impl Endian {
+    #[cfg(target_endian = "big")]    #[inline] pub fn native() -> Endian { Endian::Big    }
+    #[cfg(target_endian = "little")] #[inline] pub fn native() -> Endian { Endian::Little }
+
+    #[inline] pub fn network() -> Endian { Endian::Big }
+
+    // the runtime equivalent of the type-level encoding `T`.
+    #[inline]
+    pub fn of<T: Endianness>() -> Endian {
+        match (T::is_native(), Endian::native()) {
+            (true,  native)         => native,
+            (false, Endian::Big)    => Endian::Little,
+            (false, Endian::Little) => Endian::Big,
+        }
+    }
+
+    #[inline] pub fn is_native(self) -> bool { self == Endian::native() }
+
+
+
+    #[inline] pub fn read_u8                 (self, stream: &[u8])                                -> u8   { dispatch!(self, read_u8(stream))                          }
+    #[inline] pub fn read_i8                 (self, stream: &[u8])                                -> i8   { dispatch!(self, read_i8(stream))                          }
+    #[inline] pub fn read_u16                (self, stream: &[u8])                                -> u16  { dispatch!(self, read_u16(stream))                         }
+    #[inline] pub fn read_i16                (self, stream: &[u8])                                -> i16  { dispatch!(self, read_i16(stream))                         }
+    #[inline] pub fn read_u24                (self, stream: &[u8])                                -> u32  { dispatch!(self, read_u24(stream))                         }
+    #[inline] pub fn read_i24                (self, stream: &[u8])                                -> i32  { dispatch!(self, read_i24(stream))                         }
+    #[inline] pub fn read_u32                (self, stream: &[u8])                                -> u32  { dispatch!(self, read_u32(stream))                         }
+    #[inline] pub fn read_i32                (self, stream: &[u8])                                -> i32  { dispatch!(self, read_i32(stream))                         }
+    #[inline] pub fn read_u64                (self, stream: &[u8])                                -> u64  { dispatch!(self, read_u64(stream))                         }
+    #[inline] pub fn read_i64                (self, stream: &[u8])                                -> i64  { dispatch!(self, read_i64(stream))                         }
+    #[inline] pub fn read_u128               (self, stream: &[u8])                                -> u128 { dispatch!(self, read_u128(stream))                        }
+    #[inline] pub fn read_i128               (self, stream: &[u8])                                -> i128 { dispatch!(self, read_i128(stream))                        }
+    #[inline] pub fn read_uint               (self, stream: &[u8], bytes: usize)                  -> u64  { dispatch!(self, read_uint(stream, bytes))                 }
+    #[inline] pub fn read_int                (self, stream: &[u8], bytes: usize)                  -> i64  { dispatch!(self, read_int(stream, bytes))                  }
+    #[inline] pub fn read_uint128            (self, stream: &[u8], bytes: usize)                  -> u128 { dispatch!(self, read_uint128(stream, bytes))              }
+    #[inline] pub fn read_int128             (self, stream: &[u8], bytes: usize)                  -> i128 { dispatch!(self, read_int128(stream, bytes))               }
+    #[inline] pub fn read_f32                (self, stream: &[u8])                                -> f32  { dispatch!(self, read_f32(stream))                         }
+    #[inline] pub fn read_f64                (self, stream: &[u8])                                -> f64  { dispatch!(self, read_f64(stream))                         }
+    #[inline] pub fn read_f16                (self, stream: &[u8])                                -> f32  { dispatch!(self, read_f16(stream))                         }
+    #[inline] pub fn read_bf16               (self, stream: &[u8])                                -> f32  { dispatch!(self, read_bf16(stream))                        }
+
+    #[inline] pub fn write_u8                (self, stream: &mut [u8], value: u8)                         { dispatch!(self, write_u8(stream, value))                  }
+    #[inline] pub fn write_u16               (self, stream: &mut [u8], value: u16)                        { dispatch!(self, write_u16(stream, value))                 }
+    #[inline] pub fn write_i16               (self, stream: &mut [u8], value: i16)                        { dispatch!(self, write_i16(stream, value))                 }
+    #[inline] pub fn write_u24               (self, stream: &mut [u8], value: u32)                        { dispatch!(self, write_u24(stream, value))                 }
+    #[inline] pub fn write_i24               (self, stream: &mut [u8], value: i32)                        { dispatch!(self, write_i24(stream, value))                 }
+    #[inline] pub fn write_u32               (self, stream: &mut [u8], value: u32)                        { dispatch!(self, write_u32(stream, value))                 }
+    #[inline] pub fn write_i32               (self, stream: &mut [u8], value: i32)                        { dispatch!(self, write_i32(stream, value))                 }
+    #[inline] pub fn write_u64               (self, stream: &mut [u8], value: u64)                        { dispatch!(self, write_u64(stream, value))                 }
+    #[inline] pub fn write_i64               (self, stream: &mut [u8], value: i64)                        { dispatch!(self, write_i64(stream, value))                 }
+    #[inline] pub fn write_u128              (self, stream: &mut [u8], value: u128)                       { dispatch!(self, write_u128(stream, value))                }
+    #[inline] pub fn write_i128              (self, stream: &mut [u8], value: i128)                       { dispatch!(self, write_i128(stream, value))                }
+    #[inline] pub fn write_uint              (self, stream: &mut [u8], value: u64,  bytes: usize)         { dispatch!(self, write_uint(stream, value, bytes))         }
+    #[inline] pub fn write_int               (self, stream: &mut [u8], value: i64,  bytes: usize)         { dispatch!(self, write_int(stream, value, bytes))          }
+    #[inline] pub fn write_uint128           (self, stream: &mut [u8], value: u128, bytes: usize)         { dispatch!(self, write_uint128(stream, value, bytes))      }
+    #[inline] pub fn write_int128            (self, stream: &mut [u8], value: i128, bytes: usize)         { dispatch!(self, write_int128(stream, value, bytes))       }
+    #[inline] pub fn write_f32               (self, stream: &mut [u8], value: f32)                        { dispatch!(self, write_f32(stream, value))                 }
+    #[inline] pub fn write_f64               (self, stream: &mut [u8], value: f64)                        { dispatch!(self, write_f64(stream, value))                 }
+    #[inline] pub fn write_f16               (self, stream: &mut [u8], value: f32)                        { dispatch!(self, write_f16(stream, value))                 }
+    #[inline] pub fn write_bf16              (self, stream: &mut [u8], value: f32)                        { dispatch!(self, write_bf16(stream, value))                }
+    #[inline] pub fn write_f80               (self, stream: &mut [u8], value: f64)                        { dispatch!(self, write_f80(stream, value))                 }
+
+    #[inline] pub fn read_slice_u16          (self, stream: &[u8], values: &mut [u16])                    { dispatch!(self, read_slice_u16(stream, values))           }
+    #[inline] pub fn read_slice_i16          (self, stream: &[u8], values: &mut [i16])                    { dispatch!(self, read_slice_i16(stream, values))           }
+    #[inline] pub fn read_slice_u32          (self, stream: &[u8], values: &mut [u32])                    { dispatch!(self, read_slice_u32(stream, values))           }
+    #[inline] pub fn read_slice_i32          (self, stream: &[u8], values: &mut [i32])                    { dispatch!(self, read_slice_i32(stream, values))           }
+    #[inline] pub fn read_slice_u64          (self, stream: &[u8], values: &mut [u64])                    { dispatch!(self, read_slice_u64(stream, values))           }
+    #[inline] pub fn read_slice_i64          (self, stream: &[u8], values: &mut [i64])                    { dispatch!(self, read_slice_i64(stream, values))           }
+    #[inline] pub fn read_slice_u128         (self, stream: &[u8], values: &mut [u128])                   { dispatch!(self, read_slice_u128(stream, values))          }
+    #[inline] pub fn read_slice_i128         (self, stream: &[u8], values: &mut [i128])                   { dispatch!(self, read_slice_i128(stream, values))          }
+    #[inline] pub fn read_slice_f32_unchecked(self, stream: &[u8], values: &mut [f32])                    { dispatch!(self, read_slice_f32_unchecked(stream, values)) }
+    #[inline] pub fn read_slice_f64_unchecked(self, stream: &[u8], values: &mut [f64])                    { dispatch!(self, read_slice_f64_unchecked(stream, values)) }
+    #[inline] pub fn read_slice_f16          (self, stream: &[u8], values: &mut [f32])                    { dispatch!(self, read_slice_f16(stream, values))           }
+    #[inline] pub fn read_slice_bf16         (self, stream: &[u8], values: &mut [f32])                    { dispatch!(self, read_slice_bf16(stream, values))          }
+
+    #[inline] pub fn write_slice_u16         (self, stream: &mut [u8], values: &[u16])                    { dispatch!(self, write_slice_u16(stream, values))          }
+    #[inline] pub fn write_slice_i16         (self, stream: &mut [u8], values: &[i16])                    { dispatch!(self, write_slice_i16(stream, values))          }
+    #[inline] pub fn write_slice_u32         (self, stream: &mut [u8], values: &[u32])                    { dispatch!(self, write_slice_u32(stream, values))          }
+    #[inline] pub fn write_slice_i32         (self, stream: &mut [u8], values: &[i32])                    { dispatch!(self, write_slice_i32(stream, values))          }
+    #[inline] pub fn write_slice_u64         (self, stream: &mut [u8], values: &[u64])                    { dispatch!(self, write_slice_u64(stream, values))          }
+    #[inline] pub fn write_slice_i64         (self, stream: &mut [u8], values: &[i64])                    { dispatch!(self, write_slice_i64(stream, values))          }
+    #[inline] pub fn write_slice_u128        (self, stream: &mut [u8], values: &[u128])                   { dispatch!(self, write_slice_u128(stream, values))         }
+    #[inline] pub fn write_slice_i128        (self, stream: &mut [u8], values: &[i128])                   { dispatch!(self, write_slice_i128(stream, values))         }
+    #[inline] pub fn write_slice_f32         (self, stream: &mut [u8], values: &[f32])                    { dispatch!(self, write_slice_f32(stream, values))          }
+    #[inline] pub fn write_slice_f64         (self, stream: &mut [u8], values: &[f64])                    { dispatch!(self, write_slice_f64(stream, values))          }
+    #[inline] pub fn write_slice_f16         (self, stream: &mut [u8], values: &[f32])                    { dispatch!(self, write_slice_f16(stream, values))          }
+    #[inline] pub fn write_slice_bf16        (self, stream: &mut [u8], values: &[f32])                    { dispatch!(self, write_slice_bf16(stream, values))         }
+    #[inline] pub fn write_slice_f80         (self, stream: &mut [u8], values: &[f64])                    { dispatch!(self, write_slice_f80(stream, values))          }
+
+    #[inline] pub fn convert_slice_u16       (self, values: &mut [u16])                                   { dispatch!(self, convert_slice_u16(values))                }
+    #[inline] pub fn convert_slice_i16       (self, values: &mut [i16])                                   { dispatch!(self, convert_slice_i16(values))                }
+    #[inline] pub fn convert_slice_u32       (self, values: &mut [u32])                                   { dispatch!(self, convert_slice_u32(values))                }
+    #[inline] pub fn convert_slice_i32       (self, values: &mut [i32])                                   { dispatch!(self, convert_slice_i32(values))                }
+    #[inline] pub fn convert_slice_u64       (self, values: &mut [u64])                                   { dispatch!(self, convert_slice_u64(values))                }
+    #[inline] pub fn convert_slice_i64       (self, values: &mut [i64])                                   { dispatch!(self, convert_slice_i64(values))                }
+    #[inline] pub fn convert_slice_u128      (self, values: &mut [u128])                                  { dispatch!(self, convert_slice_u128(values))               }
+    #[inline] pub fn convert_slice_i128      (self, values: &mut [i128])                                  { dispatch!(self, convert_slice_i128(values))               }
+    #[inline] pub fn convert_slice_f32       (self, values: &mut [f32])                                   { dispatch!(self, convert_slice_f32(values))                }
+    #[inline] pub fn convert_slice_f64       (self, values: &mut [f64])                                   { dispatch!(self, convert_slice_f64(values))                }
+
+
+
+    #[inline] pub fn try_read_u8         (self, stream: &[u8])                                -> Result<  u8, Error> { dispatch!(self, try_read_u8(stream))                     }
+    #[inline] pub fn try_read_i8         (self, stream: &[u8])                                -> Result<  i8, Error> { dispatch!(self, try_read_i8(stream))                     }
+    #[inline] pub fn try_read_u16        (self, stream: &[u8])                                -> Result< u16, Error> { dispatch!(self, try_read_u16(stream))                    }
+    #[inline] pub fn try_read_i16        (self, stream: &[u8])                                -> Result< i16, Error> { dispatch!(self, try_read_i16(stream))                    }
+    #[inline] pub fn try_read_u24        (self, stream: &[u8])                                -> Result< u32, Error> { dispatch!(self, try_read_u24(stream))                    }
+    #[inline] pub fn try_read_i24        (self, stream: &[u8])                                -> Result< i32, Error> { dispatch!(self, try_read_i24(stream))                    }
+    #[inline] pub fn try_read_u32        (self, stream: &[u8])                                -> Result< u32, Error> { dispatch!(self, try_read_u32(stream))                    }
+    #[inline] pub fn try_read_i32        (self, stream: &[u8])                                -> Result< i32, Error> { dispatch!(self, try_read_i32(stream))                    }
+    #[inline] pub fn try_read_u64        (self, stream: &[u8])                                -> Result< u64, Error> { dispatch!(self, try_read_u64(stream))                    }
+    #[inline] pub fn try_read_i64        (self, stream: &[u8])                                -> Result< i64, Error> { dispatch!(self, try_read_i64(stream))                    }
+    #[inline] pub fn try_read_u128       (self, stream: &[u8])                                -> Result<u128, Error> { dispatch!(self, try_read_u128(stream))                   }
+    #[inline] pub fn try_read_i128       (self, stream: &[u8])                                -> Result<i128, Error> { dispatch!(self, try_read_i128(stream))                   }
+    #[inline] pub fn try_read_f32        (self, stream: &[u8])                                -> Result< f32, Error> { dispatch!(self, try_read_f32(stream))                    }
+    #[inline] pub fn try_read_f64        (self, stream: &[u8])                                -> Result< f64, Error> { dispatch!(self, try_read_f64(stream))                    }
+    #[inline] pub fn try_read_f16        (self, stream: &[u8])                                -> Result< f32, Error> { dispatch!(self, try_read_f16(stream))                    }
+    #[inline] pub fn try_read_bf16       (self, stream: &[u8])                                -> Result< f32, Error> { dispatch!(self, try_read_bf16(stream))                   }
+    #[inline] pub fn try_read_uint       (self, stream: &[u8], bytes: usize)                  -> Result< u64, Error> { dispatch!(self, try_read_uint(stream, bytes))            }
+    #[inline] pub fn try_read_int        (self, stream: &[u8], bytes: usize)                  -> Result< i64, Error> { dispatch!(self, try_read_int(stream, bytes))             }
+    #[inline] pub fn try_read_uint128    (self, stream: &[u8], bytes: usize)                  -> Result<u128, Error> { dispatch!(self, try_read_uint128(stream, bytes))         }
+    #[inline] pub fn try_read_int128     (self, stream: &[u8], bytes: usize)                  -> Result<i128, Error> { dispatch!(self, try_read_int128(stream, bytes))          }
+
+    #[inline] pub fn try_write_u8        (self, stream: &mut [u8], value: u8)                 -> Result<  (), Error> { dispatch!(self, try_write_u8(stream, value))             }
+    #[inline] pub fn try_write_u16       (self, stream: &mut [u8], value: u16)                -> Result<  (), Error> { dispatch!(self, try_write_u16(stream, value))            }
+    #[inline] pub fn try_write_i16       (self, stream: &mut [u8], value: i16)                -> Result<  (), Error> { dispatch!(self, try_write_i16(stream, value))            }
+    #[inline] pub fn try_write_u32       (self, stream: &mut [u8], value: u32)                -> Result<  (), Error> { dispatch!(self, try_write_u32(stream, value))            }
+    #[inline] pub fn try_write_i32       (self, stream: &mut [u8], value: i32)                -> Result<  (), Error> { dispatch!(self, try_write_i32(stream, value))            }
+    #[inline] pub fn try_write_u64       (self, stream: &mut [u8], value: u64)                -> Result<  (), Error> { dispatch!(self, try_write_u64(stream, value))            }
+    #[inline] pub fn try_write_i64       (self, stream: &mut [u8], value: i64)                -> Result<  (), Error> { dispatch!(self, try_write_i64(stream, value))            }
+    #[inline] pub fn try_write_u128      (self, stream: &mut [u8], value: u128)               -> Result<  (), Error> { dispatch!(self, try_write_u128(stream, value))           }
+    #[inline] pub fn try_write_i128      (self, stream: &mut [u8], value: i128)               -> Result<  (), Error> { dispatch!(self, try_write_i128(stream, value))           }
+    #[inline] pub fn try_write_f32       (self, stream: &mut [u8], value: f32)                -> Result<  (), Error> { dispatch!(self, try_write_f32(stream, value))            }
+    #[inline] pub fn try_write_f64       (self, stream: &mut [u8], value: f64)                -> Result<  (), Error> { dispatch!(self, try_write_f64(stream, value))            }
+    #[inline] pub fn try_write_f16       (self, stream: &mut [u8], value: f32)                -> Result<  (), Error> { dispatch!(self, try_write_f16(stream, value))            }
+    #[inline] pub fn try_write_bf16      (self, stream: &mut [u8], value: f32)                -> Result<  (), Error> { dispatch!(self, try_write_bf16(stream, value))           }
+    #[inline] pub fn try_write_f80       (self, stream: &mut [u8], value: f64)                -> Result<  (), Error> { dispatch!(self, try_write_f80(stream, value))            }
+    #[inline] pub fn try_write_u24       (self, stream: &mut [u8], value: u32)                -> Result<  (), Error> { dispatch!(self, try_write_u24(stream, value))            }
+    #[inline] pub fn try_write_i24       (self, stream: &mut [u8], value: i32)                -> Result<  (), Error> { dispatch!(self, try_write_i24(stream, value))            }
+
+    #[inline] pub fn try_read_slice_u16  (self, stream: &[u8], values: &mut [u16])            -> Result<  (), Error> { dispatch!(self, try_read_slice_u16(stream, values))      }
+    #[inline] pub fn try_read_slice_i16  (self, stream: &[u8], values: &mut [i16])            -> Result<  (), Error> { dispatch!(self, try_read_slice_i16(stream, values))      }
+    #[inline] pub fn try_read_slice_u32  (self, stream: &[u8], values: &mut [u32])            -> Result<  (), Error> { dispatch!(self, try_read_slice_u32(stream, values))      }
+    #[inline] pub fn try_read_slice_i32  (self, stream: &[u8], values: &mut [i32])            -> Result<  (), Error> { dispatch!(self, try_read_slice_i32(stream, values))      }
+    #[inline] pub fn try_read_slice_u64  (self, stream: &[u8], values: &mut [u64])            -> Result<  (), Error> { dispatch!(self, try_read_slice_u64(stream, values))      }
+    #[inline] pub fn try_read_slice_i64  (self, stream: &[u8], values: &mut [i64])            -> Result<  (), Error> { dispatch!(self, try_read_slice_i64(stream, values))      }
+    #[inline] pub fn try_read_slice_u128 (self, stream: &[u8], values: &mut [u128])           -> Result<  (), Error> { dispatch!(self, try_read_slice_u128(stream, values))     }
+    #[inline] pub fn try_read_slice_i128 (self, stream: &[u8], values: &mut [i128])           -> Result<  (), Error> { dispatch!(self, try_read_slice_i128(stream, values))     }
+    #[inline] pub fn try_read_slice_f32  (self, stream: &[u8], values: &mut [f32])            -> Result<  (), Error> { dispatch!(self, try_read_slice_f32(stream, values))      }
+    #[inline] pub fn try_read_slice_f64  (self, stream: &[u8], values: &mut [f64])            -> Result<  (), Error> { dispatch!(self, try_read_slice_f64(stream, values))      }
+    #[inline] pub fn try_read_slice_f16  (self, stream: &[u8], values: &mut [f32])            -> Result<  (), Error> { dispatch!(self, try_read_slice_f16(stream, values))      }
+    #[inline] pub fn try_read_slice_bf16 (self, stream: &[u8], values: &mut [f32])            -> Result<  (), Error> { dispatch!(self, try_read_slice_bf16(stream, values))     }
+
+    #[inline] pub fn try_write_slice_u16 (self, stream: &mut [u8], values: &[u16])            -> Result<  (), Error> { dispatch!(self, try_write_slice_u16(stream, values))     }
+    #[inline] pub fn try_write_slice_i16 (self, stream: &mut [u8], values: &[i16])            -> Result<  (), Error> { dispatch!(self, try_write_slice_i16(stream, values))     }
+    #[inline] pub fn try_write_slice_u32 (self, stream: &mut [u8], values: &[u32])            -> Result<  (), Error> { dispatch!(self, try_write_slice_u32(stream, values))     }
+    #[inline] pub fn try_write_slice_i32 (self, stream: &mut [u8], values: &[i32])            -> Result<  (), Error> { dispatch!(self, try_write_slice_i32(stream, values))     }
+    #[inline] pub fn try_write_slice_u64 (self, stream: &mut [u8], values: &[u64])            -> Result<  (), Error> { dispatch!(self, try_write_slice_u64(stream, values))     }
+    #[inline] pub fn try_write_slice_i64 (self, stream: &mut [u8], values: &[i64])            -> Result<  (), Error> { dispatch!(self, try_write_slice_i64(stream, values))     }
+    #[inline] pub fn try_write_slice_u128(self, stream: &mut [u8], values: &[u128])           -> Result<  (), Error> { dispatch!(self, try_write_slice_u128(stream, values))    }
+    #[inline] pub fn try_write_slice_i128(self, stream: &mut [u8], values: &[i128])           -> Result<  (), Error> { dispatch!(self, try_write_slice_i128(stream, values))    }
+    #[inline] pub fn try_write_slice_f32 (self, stream: &mut [u8], values: &[f32])            -> Result<  (), Error> { dispatch!(self, try_write_slice_f32(stream, values))     }
+    #[inline] pub fn try_write_slice_f64 (self, stream: &mut [u8], values: &[f64])            -> Result<  (), Error> { dispatch!(self, try_write_slice_f64(stream, values))     }
+    #[inline] pub fn try_write_slice_f16 (self, stream: &mut [u8], values: &[f32])            -> Result<  (), Error> { dispatch!(self, try_write_slice_f16(stream, values))     }
+    #[inline] pub fn try_write_slice_bf16(self, stream: &mut [u8], values: &[f32])            -> Result<  (), Error> { dispatch!(self, try_write_slice_bf16(stream, values))    }
+    #[inline] pub fn try_write_slice_f80 (self, stream: &mut [u8], values: &[f64])            -> Result<  (), Error> { dispatch!(self, try_write_slice_f80(stream, values))     }
+
+    #[inline] pub fn try_write_uint      (self, stream: &mut [u8], value: u64, bytes: usize)  -> Result<  (), Error> { dispatch!(self, try_write_uint(stream, value, bytes))    }
+    #[inline] pub fn try_write_int       (self, stream: &mut [u8], value: i64, bytes: usize)  -> Result<  (), Error> { dispatch!(self, try_write_int(stream, value, bytes))     }
+    #[inline] pub fn try_write_uint128   (self, stream: &mut [u8], value: u128, bytes: usize) -> Result<  (), Error> { dispatch!(self, try_write_uint128(stream, value, bytes)) }
+    #[inline] pub fn try_write_int128    (self, stream: &mut [u8], value: i128, bytes: usize) -> Result<  (), Error> { dispatch!(self, try_write_int128(stream, value, bytes))  }
+
+    #[inline] pub fn read_f80            (self, stream: &[u8])                                -> (f64, bool)                { dispatch!(self, read_f80(stream))                       }
+    #[inline] pub fn read_slice_f80      (self, stream: &[u8], values: &mut [f64])            -> bool                       { dispatch!(self, read_slice_f80(stream, values))         }
+    #[inline] pub fn try_read_f80        (self, stream: &[u8])                                -> Result<(f64, bool), Error> { dispatch!(self, try_read_f80(stream))                   }
+    #[inline] pub fn try_read_slice_f80  (self, stream: &[u8], values: &mut [f64])            -> Result<bool, Error>        { dispatch!(self, try_read_slice_f80(stream, values))     }
+
+    #[inline] pub fn read_ibm_f32           (self, stream: &[u8])                     -> f32                { dispatch!(self, read_ibm_f32(stream))                    }
+    #[inline] pub fn read_ibm_f64           (self, stream: &[u8])                     -> f64                { dispatch!(self, read_ibm_f64(stream))                    }
+    #[inline] pub fn read_vax_f             (self, stream: &[u8])                     -> f32                { dispatch!(self, read_vax_f(stream))                      }
+    #[inline] pub fn read_vax_d             (self, stream: &[u8])                     -> f64                { dispatch!(self, read_vax_d(stream))                      }
+    #[inline] pub fn read_vax_g             (self, stream: &[u8])                     -> f64                { dispatch!(self, read_vax_g(stream))                      }
+
+    #[inline] pub fn write_ibm_f32          (self, stream: &mut [u8], value: f32)                           { dispatch!(self, write_ibm_f32(stream, value))            }
+    #[inline] pub fn write_ibm_f64          (self, stream: &mut [u8], value: f64)                           { dispatch!(self, write_ibm_f64(stream, value))            }
+    #[inline] pub fn write_vax_f            (self, stream: &mut [u8], value: f32)                           { dispatch!(self, write_vax_f(stream, value))              }
+    #[inline] pub fn write_vax_d            (self, stream: &mut [u8], value: f64)                           { dispatch!(self, write_vax_d(stream, value))              }
+    #[inline] pub fn write_vax_g            (self, stream: &mut [u8], value: f64)                           { dispatch!(self, write_vax_g(stream, value))              }
+
+    #[inline] pub fn read_slice_ibm_f32     (self, stream: &[u8], values: &mut [f32])                       { dispatch!(self, read_slice_ibm_f32(stream, values))      }
+    #[inline] pub fn read_slice_ibm_f64     (self, stream: &[u8], values: &mut [f64])                       { dispatch!(self, read_slice_ibm_f64(stream, values))      }
+    #[inline] pub fn read_slice_vax_f       (self, stream: &[u8], values: &mut [f32])                       { dispatch!(self, read_slice_vax_f(stream, values))        }
+    #[inline] pub fn read_slice_vax_d       (self, stream: &[u8], values: &mut [f64])                       { dispatch!(self, read_slice_vax_d(stream, values))        }
+    #[inline] pub fn read_slice_vax_g       (self, stream: &[u8], values: &mut [f64])                       { dispatch!(self, read_slice_vax_g(stream, values))        }
+
+    #[inline] pub fn write_slice_ibm_f32    (self, stream: &mut [u8], values: &[f32])                       { dispatch!(self, write_slice_ibm_f32(stream, values))     }
+    #[inline] pub fn write_slice_ibm_f64    (self, stream: &mut [u8], values: &[f64])                       { dispatch!(self, write_slice_ibm_f64(stream, values))     }
+    #[inline] pub fn write_slice_vax_f      (self, stream: &mut [u8], values: &[f32])                       { dispatch!(self, write_slice_vax_f(stream, values))       }
+    #[inline] pub fn write_slice_vax_d      (self, stream: &mut [u8], values: &[f64])                       { dispatch!(self, write_slice_vax_d(stream, values))       }
+    #[inline] pub fn write_slice_vax_g      (self, stream: &mut [u8], values: &[f64])                       { dispatch!(self, write_slice_vax_g(stream, values))       }
+
+    #[inline] pub fn try_read_ibm_f32       (self, stream: &[u8])                     -> Result<f32, Error> { dispatch!(self, try_read_ibm_f32(stream))                }
+    #[inline] pub fn try_read_ibm_f64       (self, stream: &[u8])                     -> Result<f64, Error> { dispatch!(self, try_read_ibm_f64(stream))                }
+    #[inline] pub fn try_read_vax_f         (self, stream: &[u8])                     -> Result<f32, Error> { dispatch!(self, try_read_vax_f(stream))                  }
+    #[inline] pub fn try_read_vax_d         (self, stream: &[u8])                     -> Result<f64, Error> { dispatch!(self, try_read_vax_d(stream))                  }
+    #[inline] pub fn try_read_vax_g         (self, stream: &[u8])                     -> Result<f64, Error> { dispatch!(self, try_read_vax_g(stream))                  }
+
+    #[inline] pub fn try_write_ibm_f32      (self, stream: &mut [u8], value: f32)     -> Result< (), Error> { dispatch!(self, try_write_ibm_f32(stream, value))        }
+    #[inline] pub fn try_write_ibm_f64      (self, stream: &mut [u8], value: f64)     -> Result< (), Error> { dispatch!(self, try_write_ibm_f64(stream, value))        }
+    #[inline] pub fn try_write_vax_f        (self, stream: &mut [u8], value: f32)     -> Result< (), Error> { dispatch!(self, try_write_vax_f(stream, value))          }
+    #[inline] pub fn try_write_vax_d        (self, stream: &mut [u8], value: f64)     -> Result< (), Error> { dispatch!(self, try_write_vax_d(stream, value))          }
+    #[inline] pub fn try_write_vax_g        (self, stream: &mut [u8], value: f64)     -> Result< (), Error> { dispatch!(self, try_write_vax_g(stream, value))          }
+
+    #[inline] pub fn try_read_slice_ibm_f32 (self, stream: &[u8], values: &mut [f32]) -> Result< (), Error> { dispatch!(self, try_read_slice_ibm_f32(stream, values))  }
+    #[inline] pub fn try_read_slice_ibm_f64 (self, stream: &[u8], values: &mut [f64]) -> Result< (), Error> { dispatch!(self, try_read_slice_ibm_f64(stream, values))  }
+    #[inline] pub fn try_read_slice_vax_f   (self, stream: &[u8], values: &mut [f32]) -> Result< (), Error> { dispatch!(self, try_read_slice_vax_f(stream, values))    }
+    #[inline] pub fn try_read_slice_vax_d   (self, stream: &[u8], values: &mut [f64]) -> Result< (), Error> { dispatch!(self, try_read_slice_vax_d(stream, values))    }
+    #[inline] pub fn try_read_slice_vax_g   (self, stream: &[u8], values: &mut [f64]) -> Result< (), Error> { dispatch!(self, try_read_slice_vax_g(stream, values))    }
+
+    #[inline] pub fn try_write_slice_ibm_f32(self, stream: &mut [u8], values: &[f32]) -> Result< (), Error> { dispatch!(self, try_write_slice_ibm_f32(stream, values)) }
+    #[inline] pub fn try_write_slice_ibm_f64(self, stream: &mut [u8], values: &[f64]) -> Result< (), Error> { dispatch!(self, try_write_slice_ibm_f64(stream, values)) }
+    #[inline] pub fn try_write_slice_vax_f  (self, stream: &mut [u8], values: &[f32]) -> Result< (), Error> { dispatch!(self, try_write_slice_vax_f(stream, values))   }
+    #[inline] pub fn try_write_slice_vax_d  (self, stream: &mut [u8], values: &[f64]) -> Result< (), Error> { dispatch!(self, try_write_slice_vax_d(stream, values))   }
+    #[inline] pub fn try_write_slice_vax_g  (self, stream: &mut [u8], values: &[f64]) -> Result< (), Error> { dispatch!(self, try_write_slice_vax_g(stream, values))   }
+}
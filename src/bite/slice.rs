@@ -0,0 +1,310 @@
+use {
+    core::marker::PhantomData,
+
+    bite::ByteReader,
+    bite::Decimal,
+    bite::Endianness,
+    bite::Error,
+    bite::Fixed,
+    bite::FixedBits,
+    bite::FrameFormat,
+    bite::LittleEndian,
+    bite::Padding,
+    bite::decimal,
+    bite::error::check_length_mut,
+    bite::frame::MAXIMUM_PREFIX_LENGTH,
+    bite::leb128,
+    bite::leb128::MAXIMUM_LENGTH,
+    bite::string::check_nul,
+    bite::string::check_padded,
+};
+
+
+
+// a cursor that reads primitives from a byte slice in the encoding `E`, without requiring `std`.
+//
+// this offers the same methods as `BiteReadExt`, but returns `Error` instead of `std::io::Error`, and framed reads
+// borrow their payload from the underlying slice instead of allocating. it's a `ByteReader` with its encoding fixed.
+#[derive(Clone, Debug)]
+pub struct SliceReader<'a, E: Endianness = LittleEndian> {
+    inner:  ByteReader<'a>,
+    endian: PhantomData<E>,
+}
+
+impl<'a, E: Endianness> SliceReader<'a, E> {
+    #[inline]
+    pub fn new(stream: &'a [u8]) -> SliceReader<'a, E> {
+        SliceReader { inner: ByteReader::new(stream), endian: PhantomData }
+    }
+
+    #[inline] pub fn position  (&self) -> usize          { self.inner.position()  }
+    #[inline] pub fn remaining (&self) -> &'a [u8]       { self.inner.remaining() }
+    #[inline] pub fn is_empty  (&self) -> bool           { self.inner.is_empty()  }
+    #[inline] pub fn into_inner(self)  -> ByteReader<'a> { self.inner             }
+
+    // consumes the next `length` bytes.
+    #[inline]
+    pub fn take(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        self.inner.read_bytes(length)
+    }
+
+
+
+    #[inline] pub fn read_u8        (&mut self)                      -> Result<  u8, Error>     { self.inner.read_u8()                         }
+    #[inline] pub fn read_i8        (&mut self)                      -> Result<  i8, Error>     { self.inner.read_i8()                         }
+    #[inline] pub fn read_u16       (&mut self)                      -> Result< u16, Error>     { self.inner.read_u16::<E>()                   }
+    #[inline] pub fn read_i16       (&mut self)                      -> Result< i16, Error>     { self.inner.read_i16::<E>()                   }
+    #[inline] pub fn read_u24       (&mut self)                      -> Result< u32, Error>     { self.inner.read_u24::<E>()                   }
+    #[inline] pub fn read_i24       (&mut self)                      -> Result< i32, Error>     { self.inner.read_i24::<E>()                   }
+    #[inline] pub fn read_u32       (&mut self)                      -> Result< u32, Error>     { self.inner.read_u32::<E>()                   }
+    #[inline] pub fn read_i32       (&mut self)                      -> Result< i32, Error>     { self.inner.read_i32::<E>()                   }
+    #[inline] pub fn read_u64       (&mut self)                      -> Result< u64, Error>     { self.inner.read_u64::<E>()                   }
+    #[inline] pub fn read_i64       (&mut self)                      -> Result< i64, Error>     { self.inner.read_i64::<E>()                   }
+    #[inline] pub fn read_u128      (&mut self)                      -> Result<u128, Error>     { self.inner.read_u128::<E>()                  }
+    #[inline] pub fn read_i128      (&mut self)                      -> Result<i128, Error>     { self.inner.read_i128::<E>()                  }
+    #[inline] pub fn read_uint      (&mut self, bytes: usize)        -> Result< u64, Error>     { self.inner.read_uint::<E>(bytes)             }
+    #[inline] pub fn read_int       (&mut self, bytes: usize)        -> Result< i64, Error>     { self.inner.read_int::<E>(bytes)              }
+    #[inline] pub fn read_uint128   (&mut self, bytes: usize)        -> Result<u128, Error>     { self.inner.read_uint128::<E>(bytes)          }
+    #[inline] pub fn read_int128    (&mut self, bytes: usize)        -> Result<i128, Error>     { self.inner.read_int128::<E>(bytes)           }
+    #[inline] pub fn read_f32       (&mut self)                      -> Result< f32, Error>     { self.inner.read_f32::<E>()                   }
+    #[inline] pub fn read_f64       (&mut self)                      -> Result< f64, Error>     { self.inner.read_f64::<E>()                   }
+    #[inline] pub fn read_f16       (&mut self)                      -> Result< f32, Error>     { self.inner.read_f16::<E>()                   }
+    #[inline] pub fn read_bf16      (&mut self)                      -> Result< f32, Error>     { self.inner.read_bf16::<E>()                  }
+
+    #[inline] pub fn read_slice_u16 (&mut self, values: &mut [u16])  -> Result<  (), Error>     { self.inner.read_slice_u16::<E>(values)       }
+    #[inline] pub fn read_slice_i16 (&mut self, values: &mut [i16])  -> Result<  (), Error>     { self.inner.read_slice_i16::<E>(values)       }
+    #[inline] pub fn read_slice_u32 (&mut self, values: &mut [u32])  -> Result<  (), Error>     { self.inner.read_slice_u32::<E>(values)       }
+    #[inline] pub fn read_slice_i32 (&mut self, values: &mut [i32])  -> Result<  (), Error>     { self.inner.read_slice_i32::<E>(values)       }
+    #[inline] pub fn read_slice_u64 (&mut self, values: &mut [u64])  -> Result<  (), Error>     { self.inner.read_slice_u64::<E>(values)       }
+    #[inline] pub fn read_slice_i64 (&mut self, values: &mut [i64])  -> Result<  (), Error>     { self.inner.read_slice_i64::<E>(values)       }
+    #[inline] pub fn read_slice_u128(&mut self, values: &mut [u128]) -> Result<  (), Error>     { self.inner.read_slice_u128::<E>(values)      }
+    #[inline] pub fn read_slice_i128(&mut self, values: &mut [i128]) -> Result<  (), Error>     { self.inner.read_slice_i128::<E>(values)      }
+    #[inline] pub fn read_slice_f32 (&mut self, values: &mut [f32])  -> Result<  (), Error>     { self.inner.read_slice_f32::<E>(values)       }
+    #[inline] pub fn read_slice_f64 (&mut self, values: &mut [f64])  -> Result<  (), Error>     { self.inner.read_slice_f64::<E>(values)       }
+    #[inline] pub fn read_slice_f16 (&mut self, values: &mut [f32])  -> Result<  (), Error>     { self.inner.read_slice_f16::<E>(values)       }
+    #[inline] pub fn read_slice_bf16(&mut self, values: &mut [f32])  -> Result<  (), Error>     { self.inner.read_slice_bf16::<E>(values)      }
+
+    #[inline] pub fn read_f80       (&mut self)                      -> Result<(f64, bool), Error> { self.inner.read_f80::<E>()               }
+    #[inline] pub fn read_slice_f80 (&mut self, values: &mut [f64])  -> Result<bool, Error>        { self.inner.read_slice_f80::<E>(values)   }
+
+    #[inline] pub fn read_ibm_f32      (&mut self)                     -> Result<f32, Error> { self.inner.read_ibm_f32::<E>()             }
+    #[inline] pub fn read_ibm_f64      (&mut self)                     -> Result<f64, Error> { self.inner.read_ibm_f64::<E>()             }
+    #[inline] pub fn read_vax_f        (&mut self)                     -> Result<f32, Error> { self.inner.read_vax_f::<E>()               }
+    #[inline] pub fn read_vax_d        (&mut self)                     -> Result<f64, Error> { self.inner.read_vax_d::<E>()               }
+    #[inline] pub fn read_vax_g        (&mut self)                     -> Result<f64, Error> { self.inner.read_vax_g::<E>()               }
+
+    #[inline] pub fn read_slice_ibm_f32(&mut self, values: &mut [f32]) -> Result< (), Error> { self.inner.read_slice_ibm_f32::<E>(values) }
+    #[inline] pub fn read_slice_ibm_f64(&mut self, values: &mut [f64]) -> Result< (), Error> { self.inner.read_slice_ibm_f64::<E>(values) }
+    #[inline] pub fn read_slice_vax_f  (&mut self, values: &mut [f32]) -> Result< (), Error> { self.inner.read_slice_vax_f::<E>(values)   }
+    #[inline] pub fn read_slice_vax_d  (&mut self, values: &mut [f64]) -> Result< (), Error> { self.inner.read_slice_vax_d::<E>(values)   }
+    #[inline] pub fn read_slice_vax_g  (&mut self, values: &mut [f64]) -> Result< (), Error> { self.inner.read_slice_vax_g::<E>(values)   }
+
+    #[inline] pub fn read_leb128_u16 (&mut self) -> Result< u16, Error> { self.inner.read_leb128_u16()  }
+    #[inline] pub fn read_leb128_i16 (&mut self) -> Result< i16, Error> { self.inner.read_leb128_i16()  }
+    #[inline] pub fn read_leb128_u32 (&mut self) -> Result< u32, Error> { self.inner.read_leb128_u32()  }
+    #[inline] pub fn read_leb128_i32 (&mut self) -> Result< i32, Error> { self.inner.read_leb128_i32()  }
+    #[inline] pub fn read_leb128_u64 (&mut self) -> Result< u64, Error> { self.inner.read_leb128_u64()  }
+    #[inline] pub fn read_leb128_i64 (&mut self) -> Result< i64, Error> { self.inner.read_leb128_i64()  }
+    #[inline] pub fn read_leb128_u128(&mut self) -> Result<u128, Error> { self.inner.read_leb128_u128() }
+    #[inline] pub fn read_leb128_i128(&mut self) -> Result<i128, Error> { self.inner.read_leb128_i128() }
+
+    #[inline]
+    pub fn read_fixed_point<I: FixedBits, const FRAC: u32>(&mut self, bytes: usize) -> Result<Fixed<I, FRAC>, Error> {
+        self.inner.read_fixed_point::<E, I, FRAC>(bytes)
+    }
+
+    #[inline]
+    pub fn read_slice_fixed_point<I: FixedBits, const FRAC: u32>(&mut self, values: &mut [Fixed<I, FRAC>], bytes: usize) -> Result<(), Error> {
+        self.inner.read_slice_fixed_point::<E, I, FRAC>(values, bytes)
+    }
+
+    #[inline] pub fn read_packed_bcd   (&mut self, bytes: usize, scale: u32) -> Result<Decimal, Error> { self.inner.read_packed_bcd(bytes, scale)    }
+    #[inline] pub fn read_zoned_decimal(&mut self, bytes: usize, scale: u32) -> Result<Decimal, Error> { self.inner.read_zoned_decimal(bytes, scale) }
+
+    #[inline] pub fn read_framed    (&mut self)                      -> Result<&'a [u8], Error> { self.inner.read_framed_ref::<E>()            }
+    #[inline] pub fn read_framed_max(&mut self, maximum: usize)      -> Result<&'a [u8], Error> { self.inner.read_framed_max_ref::<E>(maximum) }
+
+    #[inline]
+    pub fn read_framed_format(&mut self, format: FrameFormat, maximum: usize) -> Result<&'a [u8], Error> {
+        self.inner.read_framed_format_ref::<E>(format, maximum)
+    }
+
+    #[inline] pub fn read_str      (&mut self, format: FrameFormat, maximum: usize) -> Result<&'a str, Error> { self.inner.read_str_ref::<E>(format, maximum)  }
+    #[inline] pub fn read_cstr     (&mut self, maximum: usize)                      -> Result<&'a str, Error> { self.inner.read_cstr_ref(maximum)              }
+    #[inline] pub fn read_fixed_str(&mut self, width: usize, padding: Padding)      -> Result<&'a str, Error> { self.inner.read_fixed_str_ref(width, padding)  }
+}
+
+
+
+// a cursor that writes primitives into a byte slice in the encoding `E`, without requiring `std`.
+//
+// this offers the same methods as `BiteWriteExt`, but returns `Error` instead of `std::io::Error`. a value that
+// doesn't fit in the remaining space is not written at all.
+#[derive(Debug)]
+pub struct SliceWriter<'a, E: Endianness = LittleEndian> {
+    stream:   &'a mut [u8],
+    position: usize,
+    endian:   PhantomData<E>,
+}
+
+impl<'a, E: Endianness> SliceWriter<'a, E> {
+    #[inline]
+    pub fn new(stream: &'a mut [u8]) -> SliceWriter<'a, E> {
+        SliceWriter { stream, position: 0, endian: PhantomData }
+    }
+
+    #[inline] pub fn position  (&self) -> usize { self.position                      }
+    #[inline] pub fn remaining (&self) -> usize { self.stream.len() - self.position  }
+    #[inline] pub fn written   (&self) -> &[u8] { &self.stream[..self.position]      }
+    #[inline] pub fn into_inner(self)  -> &'a mut [u8] { self.stream                 }
+
+    // reserves the next `length` bytes, returning them to be filled in.
+    #[inline]
+    pub fn take(&mut self, length: usize) -> Result<&mut [u8], Error> {
+        let data = check_length_mut(&mut self.stream[self.position..], length)?;
+
+        self.position += length;
+        Ok(data)
+    }
+
+    #[inline] fn rest   (&mut self)                -> &mut [u8]         { &mut self.stream[self.position..]  }
+    #[inline] fn advance(&mut self, length: usize) -> Result<(), Error> { self.position += length; Ok(())    }
+
+
+
+    #[inline] pub fn write_u8        (&mut self, value: u8)                 -> Result<(), Error> { E::try_write_u8(self.rest(), value)?; self.advance(1)                 }
+    #[inline] pub fn write_i8        (&mut self, value: i8)                 -> Result<(), Error> { E::try_write_u8(self.rest(), value as u8)?; self.advance(1)           }
+    #[inline] pub fn write_u16       (&mut self, value: u16)                -> Result<(), Error> { E::try_write_u16(self.rest(), value)?; self.advance(2)                }
+    #[inline] pub fn write_i16       (&mut self, value: i16)                -> Result<(), Error> { E::try_write_i16(self.rest(), value)?; self.advance(2)                }
+    #[inline] pub fn write_u24       (&mut self, value: u32)                -> Result<(), Error> { E::try_write_u24(self.rest(), value)?; self.advance(3)                }
+    #[inline] pub fn write_i24       (&mut self, value: i32)                -> Result<(), Error> { E::try_write_i24(self.rest(), value)?; self.advance(3)                }
+    #[inline] pub fn write_u32       (&mut self, value: u32)                -> Result<(), Error> { E::try_write_u32(self.rest(), value)?; self.advance(4)                }
+    #[inline] pub fn write_i32       (&mut self, value: i32)                -> Result<(), Error> { E::try_write_i32(self.rest(), value)?; self.advance(4)                }
+    #[inline] pub fn write_u64       (&mut self, value: u64)                -> Result<(), Error> { E::try_write_u64(self.rest(), value)?; self.advance(8)                }
+    #[inline] pub fn write_i64       (&mut self, value: i64)                -> Result<(), Error> { E::try_write_i64(self.rest(), value)?; self.advance(8)                }
+    #[inline] pub fn write_u128      (&mut self, value: u128)               -> Result<(), Error> { E::try_write_u128(self.rest(), value)?; self.advance(16)              }
+    #[inline] pub fn write_i128      (&mut self, value: i128)               -> Result<(), Error> { E::try_write_i128(self.rest(), value)?; self.advance(16)              }
+    #[inline] pub fn write_uint      (&mut self, value: u64, bytes: usize)  -> Result<(), Error> { E::try_write_uint(self.rest(), value, bytes)?; self.advance(bytes)    }
+    #[inline] pub fn write_int       (&mut self, value: i64, bytes: usize)  -> Result<(), Error> { E::try_write_int(self.rest(), value, bytes)?; self.advance(bytes)     }
+    #[inline] pub fn write_uint128   (&mut self, value: u128, bytes: usize) -> Result<(), Error> { E::try_write_uint128(self.rest(), value, bytes)?; self.advance(bytes) }
+    #[inline] pub fn write_int128    (&mut self, value: i128, bytes: usize) -> Result<(), Error> { E::try_write_int128(self.rest(), value, bytes)?; self.advance(bytes)  }
+    #[inline] pub fn write_f32       (&mut self, value: f32)                -> Result<(), Error> { E::try_write_f32(self.rest(), value)?; self.advance(4)                }
+    #[inline] pub fn write_f64       (&mut self, value: f64)                -> Result<(), Error> { E::try_write_f64(self.rest(), value)?; self.advance(8)                }
+    #[inline] pub fn write_f16       (&mut self, value: f32)                -> Result<(), Error> { E::try_write_f16(self.rest(), value)?; self.advance(2)                }
+    #[inline] pub fn write_bf16      (&mut self, value: f32)                -> Result<(), Error> { E::try_write_bf16(self.rest(), value)?; self.advance(2)               }
+    #[inline] pub fn write_f80       (&mut self, value: f64)                -> Result<(), Error> { E::try_write_f80(self.rest(), value)?; self.advance(10)               }
+
+    #[inline] pub fn write_slice_u16 (&mut self, values: &[u16])            -> Result<(), Error> { E::try_write_slice_u16(self.rest(), values)?; self.advance(2 * values.len())   }
+    #[inline] pub fn write_slice_i16 (&mut self, values: &[i16])            -> Result<(), Error> { E::try_write_slice_i16(self.rest(), values)?; self.advance(2 * values.len())   }
+    #[inline] pub fn write_slice_u32 (&mut self, values: &[u32])            -> Result<(), Error> { E::try_write_slice_u32(self.rest(), values)?; self.advance(4 * values.len())   }
+    #[inline] pub fn write_slice_i32 (&mut self, values: &[i32])            -> Result<(), Error> { E::try_write_slice_i32(self.rest(), values)?; self.advance(4 * values.len())   }
+    #[inline] pub fn write_slice_u64 (&mut self, values: &[u64])            -> Result<(), Error> { E::try_write_slice_u64(self.rest(), values)?; self.advance(8 * values.len())   }
+    #[inline] pub fn write_slice_i64 (&mut self, values: &[i64])            -> Result<(), Error> { E::try_write_slice_i64(self.rest(), values)?; self.advance(8 * values.len())   }
+    #[inline] pub fn write_slice_u128(&mut self, values: &[u128])           -> Result<(), Error> { E::try_write_slice_u128(self.rest(), values)?; self.advance(16 * values.len()) }
+    #[inline] pub fn write_slice_i128(&mut self, values: &[i128])           -> Result<(), Error> { E::try_write_slice_i128(self.rest(), values)?; self.advance(16 * values.len()) }
+    #[inline] pub fn write_slice_f32 (&mut self, values: &[f32])            -> Result<(), Error> { E::try_write_slice_f32(self.rest(), values)?; self.advance(4 * values.len())   }
+    #[inline] pub fn write_slice_f64 (&mut self, values: &[f64])            -> Result<(), Error> { E::try_write_slice_f64(self.rest(), values)?; self.advance(8 * values.len())   }
+    #[inline] pub fn write_slice_f16 (&mut self, values: &[f32])            -> Result<(), Error> { E::try_write_slice_f16(self.rest(), values)?; self.advance(2 * values.len())   }
+    #[inline] pub fn write_slice_bf16(&mut self, values: &[f32])            -> Result<(), Error> { E::try_write_slice_bf16(self.rest(), values)?; self.advance(2 * values.len())  }
+    #[inline] pub fn write_slice_f80 (&mut self, values: &[f64])            -> Result<(), Error> { E::try_write_slice_f80(self.rest(), values)?; self.advance(10 * values.len())  }
+
+    #[inline] pub fn write_ibm_f32      (&mut self, value: f32)     -> Result<(), Error> { E::try_write_ibm_f32(self.rest(), value)?; self.advance(4)                       }
+    #[inline] pub fn write_ibm_f64      (&mut self, value: f64)     -> Result<(), Error> { E::try_write_ibm_f64(self.rest(), value)?; self.advance(8)                       }
+    #[inline] pub fn write_vax_f        (&mut self, value: f32)     -> Result<(), Error> { E::try_write_vax_f(self.rest(), value)?; self.advance(4)                         }
+    #[inline] pub fn write_vax_d        (&mut self, value: f64)     -> Result<(), Error> { E::try_write_vax_d(self.rest(), value)?; self.advance(8)                         }
+    #[inline] pub fn write_vax_g        (&mut self, value: f64)     -> Result<(), Error> { E::try_write_vax_g(self.rest(), value)?; self.advance(8)                         }
+
+    #[inline] pub fn write_slice_ibm_f32(&mut self, values: &[f32]) -> Result<(), Error> { E::try_write_slice_ibm_f32(self.rest(), values)?; self.advance(4 * values.len()) }
+    #[inline] pub fn write_slice_ibm_f64(&mut self, values: &[f64]) -> Result<(), Error> { E::try_write_slice_ibm_f64(self.rest(), values)?; self.advance(8 * values.len()) }
+    #[inline] pub fn write_slice_vax_f  (&mut self, values: &[f32]) -> Result<(), Error> { E::try_write_slice_vax_f(self.rest(), values)?; self.advance(4 * values.len())   }
+    #[inline] pub fn write_slice_vax_d  (&mut self, values: &[f64]) -> Result<(), Error> { E::try_write_slice_vax_d(self.rest(), values)?; self.advance(8 * values.len())   }
+    #[inline] pub fn write_slice_vax_g  (&mut self, values: &[f64]) -> Result<(), Error> { E::try_write_slice_vax_g(self.rest(), values)?; self.advance(8 * values.len())   }
+
+    #[inline] pub fn write_leb128_u16 (&mut self, value: u16)  -> Result<(), Error> { self.write_leb128(leb128::encode_unsigned(value as u128)) }
+    #[inline] pub fn write_leb128_i16 (&mut self, value: i16)  -> Result<(), Error> { self.write_leb128(leb128::encode_signed(value as i128))   }
+    #[inline] pub fn write_leb128_u32 (&mut self, value: u32)  -> Result<(), Error> { self.write_leb128(leb128::encode_unsigned(value as u128)) }
+    #[inline] pub fn write_leb128_i32 (&mut self, value: i32)  -> Result<(), Error> { self.write_leb128(leb128::encode_signed(value as i128))   }
+    #[inline] pub fn write_leb128_u64 (&mut self, value: u64)  -> Result<(), Error> { self.write_leb128(leb128::encode_unsigned(value as u128)) }
+    #[inline] pub fn write_leb128_i64 (&mut self, value: i64)  -> Result<(), Error> { self.write_leb128(leb128::encode_signed(value as i128))   }
+    #[inline] pub fn write_leb128_u128(&mut self, value: u128) -> Result<(), Error> { self.write_leb128(leb128::encode_unsigned(value))         }
+    #[inline] pub fn write_leb128_i128(&mut self, value: i128) -> Result<(), Error> { self.write_leb128(leb128::encode_signed(value))           }
+
+    #[inline]
+    pub fn write_fixed_point<I: FixedBits, const FRAC: u32>(&mut self, value: Fixed<I, FRAC>, bytes: usize) -> Result<(), Error> {
+        value.try_write::<E>(self.rest(), bytes)?;
+        self.advance(bytes)
+    }
+
+    #[inline]
+    pub fn write_slice_fixed_point<I: FixedBits, const FRAC: u32>(&mut self, values: &[Fixed<I, FRAC>], bytes: usize) -> Result<(), Error> {
+        Fixed::try_write_slice::<E>(self.rest(), values, bytes)?;
+        self.advance(bytes * values.len())
+    }
+
+    #[inline]
+    pub fn write_packed_bcd(&mut self, value: Decimal, bytes: usize) -> Result<(), Error> {
+        decimal::encode_packed(check_length_mut(self.rest(), decimal::check_packed(bytes, value.scale)?)?, value.unscaled)?;
+        self.advance(bytes)
+    }
+
+    #[inline]
+    pub fn write_zoned_decimal(&mut self, value: Decimal, bytes: usize) -> Result<(), Error> {
+        decimal::encode_zoned(check_length_mut(self.rest(), decimal::check_zoned(bytes, value.scale)?)?, value.unscaled)?;
+        self.advance(bytes)
+    }
+
+    // writes the shortest encoding of a leb128 value. nothing is written if it doesn't fit.
+    #[inline]
+    fn write_leb128(&mut self, (data, length): ([u8; MAXIMUM_LENGTH], usize)) -> Result<(), Error> {
+        self.take(length)?.copy_from_slice(&data[..length]);
+        Ok(())
+    }
+
+
+
+    #[inline]
+    pub fn write_framed(&mut self, value: &[u8]) -> Result<(), Error> {
+        self.write_framed_format(FrameFormat::U32, value)
+    }
+
+    // writes a frame whose length prefix is laid out as `format` describes. nothing is written if it doesn't fit.
+    #[inline]
+    pub fn write_framed_format(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), Error> {
+        let mut prefix = [0; MAXIMUM_PREFIX_LENGTH];
+        let length     = format.write_prefix::<E>(&mut prefix, value.len())?;
+        let data       = self.take(length + value.len())?;
+
+        data[..length].copy_from_slice(&prefix[..length]);
+        data[length..].copy_from_slice(value);
+        Ok(())
+    }
+
+
+
+    #[inline]
+    pub fn write_string(&mut self, format: FrameFormat, value: &str) -> Result<(), Error> {
+        self.write_framed_format(format, value.as_bytes())
+    }
+
+    // writes a string followed by a nul. nothing is written if it doesn't fit, or if the string contains a nul.
+    #[inline]
+    pub fn write_cstring(&mut self, value: &str) -> Result<(), Error> {
+        let value = check_nul(value.as_bytes())?;
+        let data  = self.take(value.len() + 1)?;
+
+        data[..value.len()].copy_from_slice(value);
+        data[value.len()] = 0;
+        Ok(())
+    }
+
+    // writes a string into a field of exactly `width` bytes, filling the rest with `padding`.
+    #[inline]
+    pub fn write_fixed_string(&mut self, value: &str, width: usize, padding: Padding) -> Result<(), Error> {
+        let value = check_padded(value.as_bytes(), width, padding)?;
+        let data  = self.take(width)?;
+
+        data[..value.len()].copy_from_slice(value);
+        data[value.len()..].fill(padding.byte());
+        Ok(())
+    }
+}
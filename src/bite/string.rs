@@ -0,0 +1,76 @@
+use {
+    core,
+
+    bite::Error,
+};
+
+
+
+// how a fixed-width string field is filled out to its width.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Padding {
+    // the field is filled with nul bytes, and its text ends at the first one.
+    Nul,
+
+    // the field is filled with spaces, which are removed from the end of its text. text that ends in spaces of its
+    // own loses them on the way back.
+    Space,
+}
+
+impl Padding {
+    // the byte that the field is filled with.
+    #[inline]
+    pub fn byte(&self) -> u8 {
+        match *self {
+            Padding::Nul   => 0,
+            Padding::Space => b' ',
+        }
+    }
+
+    // the text of a padded field.
+    #[inline]
+    pub fn trim<'a>(&self, field: &'a [u8]) -> &'a [u8] {
+        match *self {
+            Padding::Nul   => &field[..field.iter().position(|&x| x == 0).unwrap_or(field.len())],
+            Padding::Space => &field[..field.iter().rposition(|&x| x != b' ').map_or(0, |x| x + 1)],
+        }
+    }
+}
+
+
+
+#[inline]
+pub(crate) fn check_utf8(data: &[u8]) -> Result<&str, Error> {
+    core::str::from_utf8(data).map_err(|_| Error::InvalidData("string is not valid utf-8"))
+}
+
+// checks that `value` can be written as a c string - it can't hold the nul byte that ends it.
+#[inline]
+pub(crate) fn check_nul(value: &[u8]) -> Result<&[u8], Error> {
+    match value.contains(&0) {
+        true  => Err(Error::InvalidInput("string contains a nul byte")),
+        false => Ok(value),
+    }
+}
+
+// checks that `value` fits in a field of `width` bytes with `padding`.
+#[inline]
+pub(crate) fn check_padded(value: &[u8], width: usize, padding: Padding) -> Result<&[u8], Error> {
+    match padding {
+        _ if value.len() > width => Err(Error::ValueTooWide { bytes: width }),
+        Padding::Nul             => check_nul(value),
+        Padding::Space           => Ok(value),
+    }
+}
+
+// finds the end of the c string at the start of `stream`, looking at no more than `maximum` bytes of text.
+#[inline]
+pub(crate) fn find_nul(stream: &[u8], maximum: usize) -> Result<usize, Error> {
+    let limit = core::cmp::min(stream.len(), maximum.saturating_add(1));
+
+    match stream[..limit].iter().position(|&x| x == 0) {
+        Some(x)                 => Ok(x),
+        None if limit > maximum => Err(Error::InvalidData("c string exceeds the maximum length")),
+        None                    => Err(Error::ShortBuffer { needed: limit + 1, available: stream.len() }),
+    }
+}
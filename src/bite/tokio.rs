@@ -0,0 +1,51 @@
+use {
+    std,
+    std::pin::Pin,
+    std::task::Context,
+    std::task::Poll,
+
+    tokio_crate::io::AsyncRead,
+    tokio_crate::io::AsyncWrite,
+    tokio_crate::io::ReadBuf,
+
+    bite::future::PollRead,
+    bite::future::PollWrite,
+};
+
+
+
+// async equivalents of the blocking extension traits, for tokio's `AsyncRead` and `AsyncWrite`.
+//
+// every method returns a future from `bite::future` that borrows the stream, and encodes exactly as its blocking
+// counterpart does. some method names are shared with tokio's own `AsyncReadExt` and `AsyncWriteExt` - when both are
+// in scope, call these through the trait, as in `AsyncBiteReadExpandedExt::read_u16::<BigEndian>(&mut stream)`.
+
+
+
+// selects tokio's io traits for the futures in `bite::future`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Tokio { }
+
+impl<S: AsyncRead + Unpin + ?Sized> PollRead<S> for Tokio {
+    #[inline]
+    fn poll_read(stream: Pin<&mut S>, context: &mut Context, buffer: &mut [u8]) -> Poll<Result<usize, std::io::Error>> {
+        let mut buffer = ReadBuf::new(buffer);
+
+        match stream.poll_read(context, &mut buffer) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(buffer.filled().len())),
+            Poll::Ready(Err(x)) => Poll::Ready(Err(x)),
+            Poll::Pending       => Poll::Pending,
+        }
+    }
+}
+
+impl<S: AsyncWrite + Unpin + ?Sized> PollWrite<S> for Tokio {
+    #[inline]
+    fn poll_write(stream: Pin<&mut S>, context: &mut Context, buffer: &[u8]) -> Poll<Result<usize, std::io::Error>> {
+        stream.poll_write(context, buffer)
+    }
+}
+
+
+
+async_extension_traits!(AsyncRead, AsyncWrite, Tokio);
@@ -0,0 +1,90 @@
+use {
+    std,
+    std::io::Read,
+    std::io::Write,
+
+    bite::BiteReadExpandedExt,
+    bite::BiteWriteExpandedExt,
+    bite::Endianness,
+};
+
+
+
+// a value that can be read from a stream in the encoding `E`.
+//
+// this is implemented for the primitive types and for fixed size arrays of values, and can be derived for structs
+// with `#[derive(BiteRead)]` (with the `derive` feature).
+pub trait BiteRead: Sized {
+    fn read_from<E: Endianness, R: Read + ?Sized>(stream: &mut R) -> Result<Self, std::io::Error>;
+}
+
+// a value that can be written to a stream in the encoding `E`.
+//
+// this is implemented for the primitive types and for fixed size arrays of values, and can be derived for structs
+// with `#[derive(BiteWrite)]` (with the `derive` feature).
+pub trait BiteWrite {
+    fn write_to<E: Endianness, W: Write + ?Sized>(&self, stream: &mut W) -> Result<(), std::io::Error>;
+}
+
+
+
+macro_rules! primitive_values {
+    ($(($type: ty, $read: ident, $write: ident)),*) => {
+        $(
+            impl BiteRead for $type {
+                #[inline]
+                fn read_from<E: Endianness, R: Read + ?Sized>(stream: &mut R) -> Result<$type, std::io::Error> {
+                    stream.$read::<E>()
+                }
+            }
+
+            impl BiteWrite for $type {
+                #[inline]
+                fn write_to<E: Endianness, W: Write + ?Sized>(&self, stream: &mut W) -> Result<(), std::io::Error> {
+                    stream.$write::<E>(*self)
+                }
+            }
+        )*
+    };
+}
+
+primitive_values!(
+    (u16,  read_u16,  write_u16),
+    (i16,  read_i16,  write_i16),
+    (u32,  read_u32,  write_u32),
+    (i32,  read_i32,  write_i32),
+    (u64,  read_u64,  write_u64),
+    (i64,  read_i64,  write_i64),
+    (u128, read_u128, write_u128),
+    (i128, read_i128, write_i128),
+    (f32,  read_f32,  write_f32),
+    (f64,  read_f64,  write_f64));
+
+impl BiteRead  for u8 { #[inline] fn read_from<E: Endianness, R: Read + ?Sized>(stream: &mut R) -> Result<u8, std::io::Error>         { stream.read_u8()        } }
+impl BiteRead  for i8 { #[inline] fn read_from<E: Endianness, R: Read + ?Sized>(stream: &mut R) -> Result<i8, std::io::Error>         { stream.read_i8()        } }
+impl BiteWrite for u8 { #[inline] fn write_to <E: Endianness, W: Write + ?Sized>(&self, stream: &mut W) -> Result<(), std::io::Error> { stream.write_u8(*self)  } }
+impl BiteWrite for i8 { #[inline] fn write_to <E: Endianness, W: Write + ?Sized>(&self, stream: &mut W) -> Result<(), std::io::Error> { stream.write_i8(*self)  } }
+
+impl<T: BiteRead, const N: usize> BiteRead for [T; N] {
+    #[inline]
+    fn read_from<E: Endianness, R: Read + ?Sized>(stream: &mut R) -> Result<[T; N], std::io::Error> {
+        // elements are read in order, and once one fails the rest are skipped.
+        let mut error  = None;
+        let     values = [(); N].map(|_| match error {
+            Some(_) => None,
+            None    => T::read_from::<E, R>(stream).map_err(|x| error = Some(x)).ok(),
+        });
+
+        match error {
+            Some(x) => Err(x),
+            None    => Ok(values.map(|x| x.unwrap())),
+        }
+    }
+}
+
+impl<T: BiteWrite, const N: usize> BiteWrite for [T; N] {
+    #[inline]
+    fn write_to<E: Endianness, W: Write + ?Sized>(&self, stream: &mut W) -> Result<(), std::io::Error> {
+        self.iter().try_for_each(|x| x.write_to::<E, W>(stream))
+    }
+}
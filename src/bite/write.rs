@@ -1,8 +1,24 @@
 use {
     std,
+    std::io::IoSlice,
+    std::io::Read,
     std::io::Write,
 
+    bite::Decimal,
+    bite::Endian,
     bite::Endianness,
+    bite::Fixed,
+    bite::FixedBits,
+    bite::FrameFormat,
+    bite::Padding,
+    bite::decimal,
+    bite::decimal::MAXIMUM_PACKED_LENGTH,
+    bite::decimal::MAXIMUM_ZONED_LENGTH,
+    bite::error::check_width,
+    bite::frame::MAXIMUM_PREFIX_LENGTH,
+    bite::leb128,
+    bite::string::check_nul,
+    bite::string::check_padded,
 };
 
 
@@ -36,7 +52,7 @@ pub trait BiteWriteExpandedExt: Write {
 
     #[inline]
     fn write_u24<T: Endianness>(&mut self, value: u32) -> Result<(), std::io::Error> {
-        let mut data = [0; 4];
+        let mut data = [0; 3];
         T::write_u24(&mut data, value);
 
         self.write_all(&data)
@@ -44,7 +60,7 @@ pub trait BiteWriteExpandedExt: Write {
 
     #[inline]
     fn write_i24<T: Endianness>(&mut self, value: i32) -> Result<(), std::io::Error> {
-        let mut data = [0; 4];
+        let mut data = [0; 3];
         T::write_i24(&mut data, value);
 
         self.write_all(&data)
@@ -146,14 +162,727 @@ pub trait BiteWriteExpandedExt: Write {
         self.write_all(&data)
     }
 
+    #[inline]
+    fn write_f16<T: Endianness>(&mut self, value: f32) -> Result<(), std::io::Error> {
+        let mut data = [0; 2];
+        T::write_f16(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    #[inline]
+    fn write_bf16<T: Endianness>(&mut self, value: f32) -> Result<(), std::io::Error> {
+        let mut data = [0; 2];
+        T::write_bf16(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    // writes an x87 80-bit extended float. every `f64` is exactly representable, so nothing is lost.
+    #[inline]
+    fn write_f80<T: Endianness>(&mut self, value: f64) -> Result<(), std::io::Error> {
+        let mut data = [0; 10];
+        T::write_f80(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    // writes an ibm hexadecimal or vax float, as described by `Endianness::read_ibm_f32`.
+    #[inline]
+    fn write_ibm_f32<T: Endianness>(&mut self, value: f32) -> Result<(), std::io::Error> {
+        let mut data = [0; 4];
+        T::write_ibm_f32(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    #[inline]
+    fn write_ibm_f64<T: Endianness>(&mut self, value: f64) -> Result<(), std::io::Error> {
+        let mut data = [0; 8];
+        T::write_ibm_f64(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    #[inline]
+    fn write_vax_f<T: Endianness>(&mut self, value: f32) -> Result<(), std::io::Error> {
+        let mut data = [0; 4];
+        T::write_vax_f(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    #[inline]
+    fn write_vax_d<T: Endianness>(&mut self, value: f64) -> Result<(), std::io::Error> {
+        let mut data = [0; 8];
+        T::write_vax_d(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    #[inline]
+    fn write_vax_g<T: Endianness>(&mut self, value: f64) -> Result<(), std::io::Error> {
+        let mut data = [0; 8];
+        T::write_vax_g(&mut data, value);
+
+        self.write_all(&data)
+    }
+
+    #[inline]
+    fn write_slice_u16<T: Endianness>(&mut self, values: &[u16]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_u16)
+    }
+
+    #[inline]
+    fn write_slice_i16<T: Endianness>(&mut self, values: &[i16]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_i16)
+    }
+
+    #[inline]
+    fn write_slice_u32<T: Endianness>(&mut self, values: &[u32]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_u32)
+    }
+
+    #[inline]
+    fn write_slice_i32<T: Endianness>(&mut self, values: &[i32]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_i32)
+    }
+
+    #[inline]
+    fn write_slice_u64<T: Endianness>(&mut self, values: &[u64]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_u64)
+    }
+
+    #[inline]
+    fn write_slice_i64<T: Endianness>(&mut self, values: &[i64]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_i64)
+    }
+
+    #[inline]
+    fn write_slice_u128<T: Endianness>(&mut self, values: &[u128]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_u128)
+    }
+
+    #[inline]
+    fn write_slice_i128<T: Endianness>(&mut self, values: &[i128]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_i128)
+    }
+
+    #[inline]
+    fn write_slice_f32<T: Endianness>(&mut self, values: &[f32]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_f32)
+    }
+
+    #[inline]
+    fn write_slice_f64<T: Endianness>(&mut self, values: &[f64]) -> Result<(), std::io::Error> {
+        write_slice(self, values, T::is_native(), T::write_slice_f64)
+    }
+
+    #[inline]
+    fn write_slice_f16<T: Endianness>(&mut self, values: &[f32]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 2, T::write_slice_f16)
+    }
+
+    #[inline]
+    fn write_slice_bf16<T: Endianness>(&mut self, values: &[f32]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 2, T::write_slice_bf16)
+    }
+
+    #[inline]
+    fn write_slice_f80<T: Endianness>(&mut self, values: &[f64]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 10, T::write_slice_f80)
+    }
+
+    #[inline]
+    fn write_slice_ibm_f32<T: Endianness>(&mut self, values: &[f32]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 4, T::write_slice_ibm_f32)
+    }
+
+    #[inline]
+    fn write_slice_ibm_f64<T: Endianness>(&mut self, values: &[f64]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 8, T::write_slice_ibm_f64)
+    }
+
+    #[inline]
+    fn write_slice_vax_f<T: Endianness>(&mut self, values: &[f32]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 4, T::write_slice_vax_f)
+    }
+
+    #[inline]
+    fn write_slice_vax_d<T: Endianness>(&mut self, values: &[f64]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 8, T::write_slice_vax_d)
+    }
+
+    #[inline]
+    fn write_slice_vax_g<T: Endianness>(&mut self, values: &[f64]) -> Result<(), std::io::Error> {
+        write_slice_as(self, values, 8, T::write_slice_vax_g)
+    }
+
+    // writes a q format number as a `bytes` wide integer - see `Fixed`. fails with `ErrorKind::InvalidInput` if the
+    // value doesn't fit.
+    #[inline]
+    fn write_fixed_point<T, I, const FRAC: u32>(&mut self, value: Fixed<I, FRAC>, bytes: usize) -> Result<(), std::io::Error>
+        where T: Endianness, I: FixedBits
+    {
+        let mut data = [0; 8];
+        value.try_write::<T>(&mut data, bytes)?;
+
+        self.write_all(&data[..bytes])
+    }
+
+    #[inline]
+    fn write_slice_fixed_point<T, I, const FRAC: u32>(&mut self, values: &[Fixed<I, FRAC>], bytes: usize) -> Result<(), std::io::Error>
+        where T: Endianness, I: FixedBits
+    {
+        let mut stage = [0u8; STAGING_LENGTH];
+
+        for chunk in values.chunks(STAGING_LENGTH / check_width(bytes, I::BYTES)?) {
+            let data = &mut stage[..bytes * chunk.len()];
+
+            Fixed::try_write_slice::<T>(data, chunk, bytes)?;
+            self.write_all(data)?;
+        }
+
+        Ok(())
+    }
+
+
+
+    #[inline]
+    fn write_leb128_u16(&mut self, value: u16) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_unsigned(value as u128);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_i16(&mut self, value: i16) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_signed(value as i128);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_u32(&mut self, value: u32) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_unsigned(value as u128);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_i32(&mut self, value: i32) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_signed(value as i128);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_u64(&mut self, value: u64) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_unsigned(value as u128);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_i64(&mut self, value: i64) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_signed(value as i128);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_u128(&mut self, value: u128) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_unsigned(value);
+        self.write_all(&data[..length])
+    }
+
+    #[inline]
+    fn write_leb128_i128(&mut self, value: i128) -> Result<(), std::io::Error> {
+        let (data, length) = leb128::encode_signed(value);
+        self.write_all(&data[..length])
+    }
+
+    // writes the unscaled digits of `value` as a packed bcd field of `bytes` bytes, at most 16, with a `c` or `d`
+    // sign - the inverse of `read_packed_bcd`. the scale isn't recorded in the field, so it's the reader's to know.
+    // fails with `ErrorKind::InvalidInput` if the unscaled value or the scale has more than the `2 * bytes - 1` digits
+    // that the field holds.
+    #[inline]
+    fn write_packed_bcd(&mut self, value: Decimal, bytes: usize) -> Result<(), std::io::Error> {
+        let mut data = [0; MAXIMUM_PACKED_LENGTH];
+        decimal::encode_packed(&mut data[..decimal::check_packed(bytes, value.scale)?], value.unscaled)?;
+
+        self.write_all(&data[..bytes])
+    }
+
+    // as `write_packed_bcd`, for an ebcdic zoned decimal field of at most 31 bytes - a digit apiece.
+    #[inline]
+    fn write_zoned_decimal(&mut self, value: Decimal, bytes: usize) -> Result<(), std::io::Error> {
+        let mut data = [0; MAXIMUM_ZONED_LENGTH];
+        decimal::encode_zoned(&mut data[..decimal::check_zoned(bytes, value.scale)?], value.unscaled)?;
+
+        self.write_all(&data[..bytes])
+    }
+
+
+
     #[inline]
     fn write_framed<T: Endianness>(&mut self, value: &[u8]) -> Result<(), std::io::Error> {
-        let length = value.len() as u32;
+        self.write_framed_format::<T>(FrameFormat::U32, value)
+    }
+
+    // writes a frame whose length prefix is laid out as `format` describes.
+    #[inline]
+    fn write_framed_format<T: Endianness>(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        let mut prefix = [0; MAXIMUM_PREFIX_LENGTH];
+        let length     = format.write_prefix::<T>(&mut prefix, value.len())?;
 
-        self.write_u32::<T>(length)?;
+        self.write_all(&prefix[..length])?;
         self.write_all(value)?;
         Ok(())
     }
+
+    // writes a frame with `write_vectored`, so that an unbuffered stream sees its prefix and payload in one write.
+    #[inline]
+    fn write_framed_vectored<T: Endianness>(&mut self, value: &[u8]) -> Result<(), std::io::Error> {
+        self.write_frames_format::<T>(FrameFormat::U32, &[value])
+    }
+
+    #[inline]
+    fn write_framed_format_vectored<T: Endianness>(&mut self, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        self.write_frames_format::<T>(format, &[value])
+    }
+
+    // writes a frame for each of `values`, batching every prefix and payload into as few `write_vectored` calls as the
+    // stream allows.
+    #[inline]
+    fn write_frames<T: Endianness>(&mut self, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        self.write_frames_format::<T>(FrameFormat::U32, values)
+    }
+
+    // every prefix is encoded before anything is written, so a payload that's too long for its prefix fails the whole
+    // batch without writing any of it.
+    fn write_frames_format<T: Endianness>(&mut self, format: FrameFormat, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        let mut prefixes = vec![([0; MAXIMUM_PREFIX_LENGTH], 0); values.len()];
+
+        for (value, prefix) in values.iter().zip(prefixes.iter_mut()) {
+            prefix.1 = format.write_prefix::<T>(&mut prefix.0, value.len())?;
+        }
+
+        let mut slices = Vec::with_capacity(values.len() * 2);
+
+        for (value, prefix) in values.iter().zip(prefixes.iter()) {
+            slices.push(IoSlice::new(&prefix.0[..prefix.1]));
+            slices.push(IoSlice::new(value));
+        }
+
+        write_all_vectored(self, &mut slices)
+    }
+
+
+
+    // writes a utf-8 string with a length prefix laid out as `format` describes.
+    #[inline]
+    fn write_string<T: Endianness>(&mut self, format: FrameFormat, value: &str) -> Result<(), std::io::Error> {
+        self.write_framed_format::<T>(format, value.as_bytes())
+    }
+
+    // writes a string followed by a nul. a string that contains a nul of its own is rejected with `InvalidInput`.
+    #[inline]
+    fn write_cstring(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_all(check_nul(value.as_bytes())?)?;
+        self.write_all(&[0])
+    }
+
+    // writes a string into a field of exactly `width` bytes, filling the rest with `padding`. a string that's longer
+    // than `width`, or that contains a nul when padded with nuls, is rejected with `InvalidInput`.
+    #[inline]
+    fn write_fixed_string(&mut self, value: &str, width: usize, padding: Padding) -> Result<(), std::io::Error> {
+        let value = check_padded(value.as_bytes(), width, padding)?;
+
+        self.write_all(value)?;
+        std::io::copy(&mut std::io::repeat(padding.byte()).take((width - value.len()) as u64), self)?;
+        Ok(())
+    }
+
+
+
+    // writes a string as utf-16 code units, without a byte order mark or a nul.
+    #[inline]
+    fn write_utf16<T: Endianness>(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_slice_u16::<T>(&value.encode_utf16().collect::<Vec<_>>())
+    }
+
+    #[inline]
+    fn write_utf16_nul<T: Endianness>(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_slice_u16::<T>(&value.encode_utf16().chain(Some(0)).collect::<Vec<_>>())
+    }
+
+    // writes a byte order mark in `T`, followed by the string as utf-16 code units.
+    #[inline]
+    fn write_utf16_bom<T: Endianness>(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_slice_u16::<T>(&Some(0xfeff).into_iter().chain(value.encode_utf16()).collect::<Vec<_>>())
+    }
+
+    // writes a string as utf-32 code units, without a byte order mark or a nul.
+    #[inline]
+    fn write_utf32<T: Endianness>(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_slice_u32::<T>(&value.chars().map(|x| x as u32).collect::<Vec<_>>())
+    }
+
+    #[inline]
+    fn write_utf32_nul<T: Endianness>(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_slice_u32::<T>(&value.chars().map(|x| x as u32).chain(Some(0)).collect::<Vec<_>>())
+    }
+
+    // writes a byte order mark in `T`, followed by the string as utf-32 code units.
+    #[inline]
+    fn write_utf32_bom<T: Endianness>(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.write_slice_u32::<T>(&Some(0xfeff).into_iter().chain(value.chars().map(|x| x as u32)).collect::<Vec<_>>())
+    }
+
+
+
+    #[inline]
+    fn write_u16_with(&mut self, endian: Endian, value: u16) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_u16(value))
+    }
+
+    #[inline]
+    fn write_i16_with(&mut self, endian: Endian, value: i16) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_i16(value))
+    }
+
+    #[inline]
+    fn write_u24_with(&mut self, endian: Endian, value: u32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_u24(value))
+    }
+
+    #[inline]
+    fn write_i24_with(&mut self, endian: Endian, value: i32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_i24(value))
+    }
+
+    #[inline]
+    fn write_u32_with(&mut self, endian: Endian, value: u32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_u32(value))
+    }
+
+    #[inline]
+    fn write_i32_with(&mut self, endian: Endian, value: i32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_i32(value))
+    }
+
+    #[inline]
+    fn write_u64_with(&mut self, endian: Endian, value: u64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_u64(value))
+    }
+
+    #[inline]
+    fn write_i64_with(&mut self, endian: Endian, value: i64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_i64(value))
+    }
+
+    #[inline]
+    fn write_u128_with(&mut self, endian: Endian, value: u128) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_u128(value))
+    }
+
+    #[inline]
+    fn write_i128_with(&mut self, endian: Endian, value: i128) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_i128(value))
+    }
+
+    #[inline]
+    fn write_uint_with(&mut self, endian: Endian, value: u64, bytes: usize) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_uint(value, bytes))
+    }
+
+    #[inline]
+    fn write_int_with(&mut self, endian: Endian, value: i64, bytes: usize) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_int(value, bytes))
+    }
+
+    #[inline]
+    fn write_uint128_with(&mut self, endian: Endian, value: u128, bytes: usize) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_uint128(value, bytes))
+    }
+
+    #[inline]
+    fn write_int128_with(&mut self, endian: Endian, value: i128, bytes: usize) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_int128(value, bytes))
+    }
+
+    #[inline]
+    fn write_f32_with(&mut self, endian: Endian, value: f32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_f32(value))
+    }
+
+    #[inline]
+    fn write_f64_with(&mut self, endian: Endian, value: f64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_f64(value))
+    }
+
+    #[inline]
+    fn write_f16_with(&mut self, endian: Endian, value: f32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_f16(value))
+    }
+
+    #[inline]
+    fn write_bf16_with(&mut self, endian: Endian, value: f32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_bf16(value))
+    }
+
+    #[inline]
+    fn write_f80_with(&mut self, endian: Endian, value: f64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_f80(value))
+    }
+
+    #[inline]
+    fn write_ibm_f32_with(&mut self, endian: Endian, value: f32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_ibm_f32(value))
+    }
+
+    #[inline]
+    fn write_ibm_f64_with(&mut self, endian: Endian, value: f64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_ibm_f64(value))
+    }
+
+    #[inline]
+    fn write_vax_f_with(&mut self, endian: Endian, value: f32) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_vax_f(value))
+    }
+
+    #[inline]
+    fn write_vax_d_with(&mut self, endian: Endian, value: f64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_vax_d(value))
+    }
+
+    #[inline]
+    fn write_vax_g_with(&mut self, endian: Endian, value: f64) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_vax_g(value))
+    }
+
+
+
+    #[inline]
+    fn write_slice_u16_with(&mut self, endian: Endian, values: &[u16]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_u16(values))
+    }
+
+    #[inline]
+    fn write_slice_i16_with(&mut self, endian: Endian, values: &[i16]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_i16(values))
+    }
+
+    #[inline]
+    fn write_slice_u32_with(&mut self, endian: Endian, values: &[u32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_u32(values))
+    }
+
+    #[inline]
+    fn write_slice_i32_with(&mut self, endian: Endian, values: &[i32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_i32(values))
+    }
+
+    #[inline]
+    fn write_slice_u64_with(&mut self, endian: Endian, values: &[u64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_u64(values))
+    }
+
+    #[inline]
+    fn write_slice_i64_with(&mut self, endian: Endian, values: &[i64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_i64(values))
+    }
+
+    #[inline]
+    fn write_slice_u128_with(&mut self, endian: Endian, values: &[u128]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_u128(values))
+    }
+
+    #[inline]
+    fn write_slice_i128_with(&mut self, endian: Endian, values: &[i128]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_i128(values))
+    }
+
+    #[inline]
+    fn write_slice_f32_with(&mut self, endian: Endian, values: &[f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_f32(values))
+    }
+
+    #[inline]
+    fn write_slice_f64_with(&mut self, endian: Endian, values: &[f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_f64(values))
+    }
+
+    #[inline]
+    fn write_slice_f16_with(&mut self, endian: Endian, values: &[f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_f16(values))
+    }
+
+    #[inline]
+    fn write_slice_bf16_with(&mut self, endian: Endian, values: &[f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_bf16(values))
+    }
+
+    #[inline]
+    fn write_slice_f80_with(&mut self, endian: Endian, values: &[f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_f80(values))
+    }
+
+    #[inline]
+    fn write_slice_ibm_f32_with(&mut self, endian: Endian, values: &[f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_ibm_f32(values))
+    }
+
+    #[inline]
+    fn write_slice_ibm_f64_with(&mut self, endian: Endian, values: &[f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_ibm_f64(values))
+    }
+
+    #[inline]
+    fn write_slice_vax_f_with(&mut self, endian: Endian, values: &[f32]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_vax_f(values))
+    }
+
+    #[inline]
+    fn write_slice_vax_d_with(&mut self, endian: Endian, values: &[f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_vax_d(values))
+    }
+
+    #[inline]
+    fn write_slice_vax_g_with(&mut self, endian: Endian, values: &[f64]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_slice_vax_g(values))
+    }
+
+
+
+    #[inline]
+    fn write_framed_with(&mut self, endian: Endian, value: &[u8]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_framed(value))
+    }
+
+    #[inline]
+    fn write_framed_format_with(&mut self, endian: Endian, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_framed_format(format, value))
+    }
+
+    #[inline]
+    fn write_framed_vectored_with(&mut self, endian: Endian, value: &[u8]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_framed_vectored(value))
+    }
+
+    #[inline]
+    fn write_framed_format_vectored_with(&mut self, endian: Endian, format: FrameFormat, value: &[u8]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_framed_format_vectored(format, value))
+    }
+
+    #[inline]
+    fn write_frames_with(&mut self, endian: Endian, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_frames(values))
+    }
+
+    #[inline]
+    fn write_frames_format_with(&mut self, endian: Endian, format: FrameFormat, values: &[&[u8]]) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_frames_format(format, values))
+    }
+
+    #[inline]
+    fn write_string_with(&mut self, endian: Endian, format: FrameFormat, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_string(format, value))
+    }
+
+    #[inline]
+    fn write_utf16_with(&mut self, endian: Endian, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_utf16(value))
+    }
+
+    #[inline]
+    fn write_utf16_nul_with(&mut self, endian: Endian, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_utf16_nul(value))
+    }
+
+    #[inline]
+    fn write_utf16_bom_with(&mut self, endian: Endian, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_utf16_bom(value))
+    }
+
+    #[inline]
+    fn write_utf32_with(&mut self, endian: Endian, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_utf32(value))
+    }
+
+    #[inline]
+    fn write_utf32_nul_with(&mut self, endian: Endian, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_utf32_nul(value))
+    }
+
+    #[inline]
+    fn write_utf32_bom_with(&mut self, endian: Endian, value: &str) -> Result<(), std::io::Error> {
+        with_endian!(endian, self.write_utf32_bom(value))
+    }
 }
 
 impl<T> BiteWriteExpandedExt for T where T: Write + ?Sized { }
+
+
+
+// the size of the stack buffer that values are staged in when they require an endian conversion before writing.
+pub(crate) const STAGING_LENGTH: usize = 4096;
+
+// writes all `values` into `stream`.
+//
+// when `native` is set, the values are already in the requested encoding and are written with a single `write_all`.
+// otherwise, they are converted into a fixed-size staging buffer with `write` and written one buffer at a time.
+#[inline]
+fn write_slice<W, P>(stream: &mut W, values: &[P], native: bool, write: fn(&mut [u8], &[P])) -> Result<(), std::io::Error>
+    where W: Write + ?Sized, P: Copy
+{
+    if native {
+        return stream.write_all(unsafe { as_data(values) });
+    }
+
+    let mut stage = [0u8; STAGING_LENGTH];
+
+    for chunk in values.chunks(STAGING_LENGTH / std::mem::size_of::<P>()) {
+        let data = &mut stage[..std::mem::size_of_val(chunk)];
+
+        write(data, chunk);
+        stream.write_all(data)?;
+    }
+
+    Ok(())
+}
+
+// writes floats that are encoded as `width` bytes apiece through a staging buffer, since their size in the stream
+// differs from their size in memory.
+fn write_slice_as<W, P>(stream: &mut W, values: &[P], width: usize, write: fn(&mut [u8], &[P])) -> Result<(), std::io::Error>
+    where W: Write + ?Sized, P: Copy
+{
+    let mut stage = [0u8; STAGING_LENGTH];
+
+    for chunk in values.chunks(STAGING_LENGTH / width) {
+        let data = &mut stage[..width * chunk.len()];
+
+        write(data, chunk);
+        stream.write_all(data)?;
+    }
+
+    Ok(())
+}
+
+// writes all of `slices` with `write_vectored`, advancing past whatever each call accepts.
+fn write_all_vectored<W: Write + ?Sized>(stream: &mut W, mut slices: &mut [IoSlice]) -> Result<(), std::io::Error> {
+    IoSlice::advance_slices(&mut slices, 0);
+
+    while !slices.is_empty() {
+        match stream.write_vectored(slices) {
+            Ok(0)                                                     => return Err(std::io::ErrorKind::WriteZero.into()),
+            Ok(n)                                                     => IoSlice::advance_slices(&mut slices, n),
+            Err(ref x) if x.kind() == std::io::ErrorKind::Interrupted => { },
+            Err(x)                                                    => return Err(x),
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) unsafe fn as_data<T: Copy>(data: &[T]) -> &[u8] {
+    let source = data.as_ptr() as *const u8;
+    let length = std::mem::size_of_val(data);
+
+    std::slice::from_raw_parts(source, length)
+}
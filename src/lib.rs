@@ -1,6 +1,32 @@
 #![feature(i128_type)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 
 
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(feature = "derive")]
+extern crate bite_derive;
+
+#[cfg(feature = "tokio")]
+extern crate tokio as tokio_crate;
+
+#[cfg(feature = "futures-io")]
+extern crate futures_io;
+
+#[cfg(feature = "tokio-util")]
+extern crate tokio_util;
+
+#[cfg(feature = "tokio-util")]
+extern crate bytes;
+
+#[cfg(feature = "derive")]
+pub use bite_derive::{
+    BiteEnum,
+    BiteRead,
+    BiteWrite,
+};
+
 mod bite;
 pub use bite::*;
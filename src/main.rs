@@ -1,13 +1,61 @@
 extern crate bite;
 
+#[cfg(feature = "tokio-util")] extern crate bytes;
+#[cfg(feature = "tokio-util")] extern crate tokio_util;
+
 use std::io::Cursor;
 
+use bite::Endianness;
+
 
 pub fn main() {
     println!("hello, from main!");
 
     big();
     little();
+    mixed();
+    runtime();
+    sign_extension::<bite::BigEndian>("big");
+    sign_extension::<bite::LittleEndian>("little");
+    leb128_values();
+    bit_fields();
+    slice_cursors::<bite::BigEndian>("big");
+    slice_cursors::<bite::LittleEndian>("little");
+    checked_buffers::<bite::BigEndian>("big");
+    checked_buffers::<bite::LittleEndian>("little");
+    reserved_slots::<bite::BigEndian>("big");
+    reserved_slots::<bite::LittleEndian>("little");
+    frame_formats();
+    hostile_frames();
+    vectored_frames();
+    strings();
+    wide_strings();
+    half_floats();
+    extended_floats();
+    legacy_floats();
+    fixed_point();
+    decimals();
+
+    #[cfg(feature = "derive")]
+    derived_structs::<bite::BigEndian>("big");
+
+    #[cfg(feature = "derive")]
+    derived_structs::<bite::LittleEndian>("little");
+
+    #[cfg(feature = "derive")]
+    derived_enums();
+
+    #[cfg(feature = "tokio")]
+    tokio_streams::<bite::BigEndian>("big");
+
+    #[cfg(feature = "tokio")]
+    tokio_streams::<bite::LittleEndian>("little");
+
+    #[cfg(feature = "futures-io")]
+    cancelled_frames();
+
+    #[cfg(feature = "tokio-util")]
+    codec();
 }
 
 fn big() {
@@ -38,3 +86,1675 @@ fn little() {
     println!("verify: [little] 517 == {}", reader.read_u16().unwrap());
     println!("verify: [little] 768 == {}", reader.read_u16().unwrap());
 }
+
+fn mixed() {
+    use {
+        bite::BiteReadBeExt,
+        bite::BiteReadLeExt,
+    };
+
+    let mut reader = Cursor::new(vec![2, 5, 0, 3]);
+    assert_eq!(517, reader.read_u16_be().unwrap());
+    assert_eq!(768, reader.read_u16_le().unwrap());
+
+    let mut reader = Cursor::new(vec![2, 5, 0, 3]);
+    println!("verify: [mixed] 517 == {}", reader.read_u16_be().unwrap());
+    println!("verify: [mixed] 768 == {}", reader.read_u16_le().unwrap());
+}
+
+fn runtime() {
+    use {
+        bite::BiteReadExpandedExt,
+        bite::Endian,
+    };
+
+    for &(endian, data) in &[(Endian::Big, [2, 5, 3, 0]), (Endian::Little, [5, 2, 0, 3])] {
+        let mut reader = Cursor::new(data);
+        assert_eq!(517, reader.read_u16_with(endian).unwrap());
+        assert_eq!(768, reader.read_u16_with(endian).unwrap());
+
+        let mut reader = Cursor::new(data);
+        println!("verify: [{:?}] 517 == {}", endian, reader.read_u16_with(endian).unwrap());
+        println!("verify: [{:?}] 768 == {}", endian, reader.read_u16_with(endian).unwrap());
+    }
+}
+
+// every signed width from 1 to 16 bytes, at and around the edges of its range, must sign-extend on read and round-trip
+// through both the slice functions and the stream traits.
+fn sign_extension<T: Endianness>(name: &str) {
+    use {
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+    };
+
+    let mut checked = 0;
+
+    for bytes in 1..17 {
+        let bits    = bytes as u32 * 8;
+        let maximum = if bits == 128 { i128::MAX } else { (1i128 << (bits - 1)) - 1 };
+        let minimum = -maximum - 1;
+
+        for &value in &[0, 1, -1, 2, -2, maximum, maximum - 1, minimum, minimum + 1] {
+            let mut data = [0u8; 16];
+            T::write_int128(&mut data, value, bytes);
+            assert_eq!(value, T::read_int128(&data[..bytes], bytes));
+
+            let mut stream = Vec::new();
+            stream.write_int128::<T>(value, bytes).unwrap();
+            assert_eq!(&data[..bytes], &stream[..]);
+            assert_eq!(value, Cursor::new(stream).read_int128::<T>(bytes).unwrap());
+
+            if bytes <= 8 {
+                let value = value as i64;
+
+                let mut data = [0u8; 8];
+                T::write_int(&mut data, value, bytes);
+                assert_eq!(value, T::read_int(&data[..bytes], bytes));
+
+                let mut stream = Vec::new();
+                stream.write_int::<T>(value, bytes).unwrap();
+                assert_eq!(value, Cursor::new(stream).read_int::<T>(bytes).unwrap());
+            }
+
+            if bytes == 3 {
+                let value = value as i32;
+
+                let mut data = [0u8; 3];
+                T::write_i24(&mut data, value);
+                assert_eq!(value, T::read_i24(&data));
+
+                let mut stream = Vec::new();
+                stream.write_i24::<T>(value).unwrap();
+                assert_eq!(3, stream.len());
+                assert_eq!(value, Cursor::new(stream).read_i24::<T>().unwrap());
+            }
+
+            checked += 1;
+        }
+    }
+
+    assert_eq!(-1,       T::read_i24(&[0xff, 0xff, 0xff]));
+    assert_eq!(-1,       Cursor::new(vec![0xff, 0xff, 0xff]).read_i24::<T>().unwrap());
+    assert_eq!(16777215, T::read_u24(&[0xff, 0xff, 0xff]));
+
+    println!("verify: [{}] {} signed boundary values round-trip, and ff ff ff == {}", name, checked, T::read_i24(&[0xff, 0xff, 0xff]));
+}
+
+
+// leb128 values at the edges of every width must round-trip in their shortest encoding, through `Leb128` and the stream
+// traits, and encodings that overflow their type or run past its longest encoding must be rejected as invalid data.
+fn leb128_values() {
+    use {
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::Error,
+        bite::Leb128,
+        std::io::ErrorKind,
+    };
+
+    let mut checked = 0;
+
+    macro_rules! check {
+        ($unsigned: ident, $signed: ident, $read_unsigned: ident, $read_signed: ident, $write_unsigned: ident,
+         $write_signed: ident, $stream_read_unsigned: ident, $stream_read_signed: ident, $stream_write_unsigned: ident,
+         $stream_write_signed: ident) => {{
+            let bits    = $unsigned::BITS;
+            let maximum = bits.div_ceil(7) as usize;
+
+            for &value in &[0, 1, 127, 128, 16383, 16384, $unsigned::MAX - 1, $unsigned::MAX] {
+                let mut data = [0; 19];
+                let length   = Leb128::$write_unsigned(&mut data, value);
+                assert_eq!(Ok((value, length)), Leb128::$read_unsigned(&data));
+                assert_eq!(Leb128::size_unsigned(value as u128), length);
+
+                let mut stream = Vec::new();
+                stream.$stream_write_unsigned(value).unwrap();
+                assert_eq!(&data[..length], &stream[..]);
+                assert_eq!(value, Cursor::new(stream).$stream_read_unsigned().unwrap());
+
+                checked += 1;
+            }
+
+            for &value in &[0, 1, -1, 63, 64, -64, -65, $signed::MIN, $signed::MIN + 1, $signed::MAX - 1, $signed::MAX] {
+                let mut data = [0; 19];
+                let length   = Leb128::$write_signed(&mut data, value);
+                assert_eq!(Ok((value, length)), Leb128::$read_signed(&data));
+                assert_eq!(Leb128::size_signed(value as i128), length);
+
+                let mut stream = Vec::new();
+                stream.$stream_write_signed(value).unwrap();
+                assert_eq!(&data[..length], &stream[..]);
+                assert_eq!(value, Cursor::new(stream).$stream_read_signed().unwrap());
+
+                checked += 1;
+            }
+
+            // the largest value fills the longest encoding - one more bit in its final byte overflows the type.
+            let mut unsigned = [0; 19];
+            let mut signed   = [0; 19];
+            assert_eq!(maximum, Leb128::$write_unsigned(&mut unsigned, $unsigned::MAX));
+            assert_eq!(maximum, Leb128::$write_signed(&mut signed, $signed::MAX));
+
+            unsigned[maximum - 1] |= 1 << (bits as usize - 7 * (maximum - 1));
+            signed[maximum - 1]   |= 1 << (bits as usize - 7 * (maximum - 1));
+
+            let overflow = Error::InvalidData("leb128 value overflows its type");
+            assert_eq!(overflow, Leb128::$read_unsigned(&unsigned[..maximum]).unwrap_err());
+            assert_eq!(overflow, Leb128::$read_signed(&signed[..maximum]).unwrap_err());
+            assert_eq!(ErrorKind::InvalidData, Cursor::new(&unsigned[..maximum]).$stream_read_unsigned().unwrap_err().kind());
+            assert_eq!(ErrorKind::InvalidData, Cursor::new(&signed[..maximum]).$stream_read_signed().unwrap_err().kind());
+
+            // zero padded out to the longest encoding is accepted, and a byte past it is overlong.
+            let mut padded = vec![0x80; maximum];
+            padded[maximum - 1] = 0x00;
+            assert_eq!(Ok((0, maximum)), Leb128::$read_unsigned(&padded));
+            assert_eq!(Ok((0, maximum)), Leb128::$read_signed(&padded));
+
+            padded[maximum - 1] = 0x80;
+            padded.push(0x00);
+
+            let overlong = Error::InvalidData("leb128 encoding is overlong");
+            assert_eq!(overlong, Leb128::$read_unsigned(&padded).unwrap_err());
+            assert_eq!(overlong, Leb128::$read_signed(&padded).unwrap_err());
+            assert_eq!(ErrorKind::InvalidData, Cursor::new(&padded).$stream_read_unsigned().unwrap_err().kind());
+            assert_eq!(ErrorKind::InvalidData, Cursor::new(&padded).$stream_read_signed().unwrap_err().kind());
+        }};
+    }
+
+    check!(u16,  i16,  read_u16,  read_i16,  write_u16,  write_i16,  read_leb128_u16,  read_leb128_i16,  write_leb128_u16,  write_leb128_i16);
+    check!(u32,  i32,  read_u32,  read_i32,  write_u32,  write_i32,  read_leb128_u32,  read_leb128_i32,  write_leb128_u32,  write_leb128_i32);
+    check!(u64,  i64,  read_u64,  read_i64,  write_u64,  write_i64,  read_leb128_u64,  read_leb128_i64,  write_leb128_u64,  write_leb128_i64);
+    check!(u128, i128, read_u128, read_i128, write_u128, write_i128, read_leb128_u128, read_leb128_i128, write_leb128_u128, write_leb128_i128);
+
+    // the examples from the dwarf specification, and a final byte that runs out of stream.
+    let mut data = [0; 3];
+    Leb128::write_u32(&mut data, 624485);
+    assert_eq!([0xe5, 0x8e, 0x26], data);
+    Leb128::write_i32(&mut data, -123456);
+    assert_eq!([0xc0, 0xbb, 0x78], data);
+    assert_eq!(Err(Error::ShortBuffer { needed: 3, available: 2 }), Leb128::read_u32(&[0xe5, 0x8e]));
+
+    println!("verify: [leb128] {} boundary values round-trip, and overflowing and overlong encodings are rejected", checked);
+}
+
+// fields of every width up to 64 bits must round-trip in both bit orders, decode known exp-golomb and deflate bits, and
+// hand the stream back on a byte boundary for byte-level reads to continue.
+fn bit_fields() {
+    use {
+        bite::BitOrder,
+        bite::BitReader,
+        bite::BitWriter,
+        bite::BiteReadExt,
+        bite::BiteWriteExt,
+    };
+
+    let fields   = [(1, 1), (5, 3), (0x1234, 13), (u64::MAX, 64), (0xdead_beef, 32), (0, 7), (i64::MAX as u64, 63)];
+    let signed   = [(-3, 5), (i64::MIN, 64), (15, 5), (-1, 1)];
+    let mut bits = 0;
+
+    for &order in &[BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut writer = BitWriter::new(Vec::new(), order);
+
+        for &(value, count) in &fields {
+            writer.write_bits(value, count).unwrap();
+        }
+
+        for &(value, count) in &signed {
+            writer.write_signed_bits(value, count).unwrap();
+        }
+
+        assert!(!writer.is_aligned());
+        writer.align().unwrap();
+        assert!(writer.is_aligned());
+
+        writer.write_bit(true).unwrap();
+
+        let mut stream = writer.into_inner().unwrap();
+        stream.write_u16(0xbeef).unwrap();
+
+        let mut reader = BitReader::new(Cursor::new(&stream), order);
+
+        for &(value, count) in &fields {
+            assert_eq!(value, reader.read_bits(count).unwrap());
+            bits += count;
+        }
+
+        for &(value, count) in &signed {
+            assert_eq!(value, reader.read_signed_bits(count).unwrap());
+            bits += count;
+        }
+
+        reader.align();
+        assert!(reader.read_bit().unwrap());
+        assert!(!reader.is_aligned());
+
+        let mut stream = reader.into_inner();
+        assert_eq!(0xbeef, stream.read_u16().unwrap());
+    }
+
+    // ue(v) for 0, 1, 2 and 3 in h.264 is 1 010 011 00100.
+    fn exp_golomb<R: std::io::Read>(reader: &mut BitReader<R>) -> u64 {
+        let mut zeroes = 0;
+
+        while !reader.read_bit().unwrap() {
+            zeroes += 1;
+        }
+
+        match zeroes {
+            0 => 0,
+            x => (1 << x) - 1 + reader.read_bits(x).unwrap(),
+        }
+    }
+
+    let mut reader = BitReader::new(Cursor::new([0xa6, 0x40]), BitOrder::MsbFirst);
+    assert_eq!([0, 1, 2, 3], [exp_golomb(&mut reader), exp_golomb(&mut reader), exp_golomb(&mut reader), exp_golomb(&mut reader)]);
+
+    let mut writer = BitWriter::new(Vec::new(), BitOrder::MsbFirst);
+    for &(value, count) in &[(1, 1), (2, 3), (3, 3), (4, 5)] {
+        writer.write_bits(value, count).unwrap();
+    }
+    assert_eq!([0xa6, 0x40], writer.into_inner().unwrap()[..]);
+
+    // an empty deflate stream is a final block of fixed huffman codes, holding only the seven zero bits of its end.
+    let mut reader = BitReader::new(Cursor::new([0x03, 0x00]), BitOrder::LsbFirst);
+    assert_eq!((1, 1, 0), (reader.read_bits(1).unwrap(), reader.read_bits(2).unwrap(), reader.read_bits(7).unwrap()));
+
+    let mut writer = BitWriter::new(Vec::new(), BitOrder::LsbFirst);
+    writer.write_bits(1, 1).unwrap();
+    writer.write_bits(1, 2).unwrap();
+    writer.write_bits(0, 7).unwrap();
+    assert_eq!([0x03, 0x00], writer.into_inner().unwrap()[..]);
+
+    println!("verify: [bits] {} bits of fields round-trip in both orders, with exp-golomb and deflate vectors", bits);
+}
+
+// `SliceWriter` must write the same bytes as the stream traits and `SliceReader` must read them back, and a value that
+// runs past the end of the slice must fail with `ShortBuffer` without writing or consuming anything.
+fn slice_cursors<T: Endianness>(name: &str) {
+    use {
+        bite::BiteWriteExpandedExt,
+        bite::Error,
+        bite::FrameFormat,
+        bite::Padding,
+        bite::SliceReader,
+        bite::SliceWriter,
+    };
+
+    let mut data   = [0; 128];
+    let mut writer = SliceWriter::<T>::new(&mut data);
+    writer.write_u8(0xfe).unwrap();
+    writer.write_i8(-2).unwrap();
+    writer.write_u16(0x1234).unwrap();
+    writer.write_i24(-0x12_3456).unwrap();
+    writer.write_u32(0xdead_beef).unwrap();
+    writer.write_i64(i64::MIN + 1).unwrap();
+    writer.write_u128(u128::MAX / 3).unwrap();
+    writer.write_uint(0xab_cdef, 3).unwrap();
+    writer.write_int(-0x80_0000_0000, 5).unwrap();
+    writer.write_f32(1.5).unwrap();
+    writer.write_f64(-0.1).unwrap();
+    writer.write_slice_u16(&[1, 2, 3]).unwrap();
+    writer.write_leb128_u32(624_485).unwrap();
+    writer.write_leb128_i64(-123_456).unwrap();
+    writer.write_framed_format(FrameFormat::U8, b"bite").unwrap();
+    writer.write_cstring("nul").unwrap();
+    writer.write_fixed_string("pad", 5, Padding::Space).unwrap();
+
+    let mut stream = Vec::new();
+    stream.write_u8(0xfe).unwrap();
+    stream.write_i8(-2).unwrap();
+    stream.write_u16::<T>(0x1234).unwrap();
+    stream.write_i24::<T>(-0x12_3456).unwrap();
+    stream.write_u32::<T>(0xdead_beef).unwrap();
+    stream.write_i64::<T>(i64::MIN + 1).unwrap();
+    stream.write_u128::<T>(u128::MAX / 3).unwrap();
+    stream.write_uint::<T>(0xab_cdef, 3).unwrap();
+    stream.write_int::<T>(-0x80_0000_0000, 5).unwrap();
+    stream.write_f32::<T>(1.5).unwrap();
+    stream.write_f64::<T>(-0.1).unwrap();
+    stream.write_slice_u16::<T>(&[1, 2, 3]).unwrap();
+    stream.write_leb128_u32(624_485).unwrap();
+    stream.write_leb128_i64(-123_456).unwrap();
+    stream.write_framed_format::<T>(FrameFormat::U8, b"bite").unwrap();
+    stream.write_cstring("nul").unwrap();
+    stream.write_fixed_string("pad", 5, Padding::Space).unwrap();
+
+    let length = writer.position();
+    assert_eq!(stream.len(), length);
+    assert_eq!(&stream[..], writer.written());
+
+    // 4 bytes don't fit in the 3 that are left, and a field that doesn't fit isn't started.
+    let mut writer = SliceWriter::<T>::new(&mut data[..length + 3]);
+    writer.take(length).unwrap();
+    assert_eq!(Err(Error::ShortBuffer { needed: 4, available: 3 }), writer.write_u32(0));
+    assert_eq!(Err(Error::ShortBuffer { needed: 4, available: 3 }), writer.write_slice_u16(&[0, 0]));
+    assert_eq!(Err(Error::ShortBuffer { needed: 5, available: 3 }), writer.write_leb128_u64(1 << 28));
+    assert_eq!(length, writer.position());
+    assert_eq!(3, writer.remaining());
+
+    let mut values = [0; 3];
+    let mut reader = SliceReader::<T>::new(&data[..length + 2]);
+    assert_eq!(Ok(0xfe),                reader.read_u8());
+    assert_eq!(Ok(-2),                  reader.read_i8());
+    assert_eq!(Ok(0x1234),              reader.read_u16());
+    assert_eq!(Ok(-0x12_3456),          reader.read_i24());
+    assert_eq!(Ok(0xdead_beef),         reader.read_u32());
+    assert_eq!(Ok(i64::MIN + 1),        reader.read_i64());
+    assert_eq!(Ok(u128::MAX / 3),       reader.read_u128());
+    assert_eq!(Ok(0xab_cdef),           reader.read_uint(3));
+    assert_eq!(Ok(-0x80_0000_0000),     reader.read_int(5));
+    assert_eq!(Ok(1.5),                 reader.read_f32());
+    assert_eq!(Ok(-0.1),                reader.read_f64());
+    assert_eq!(Ok(()),                  reader.read_slice_u16(&mut values));
+    assert_eq!(Ok(624_485),             reader.read_leb128_u32());
+    assert_eq!(Ok(-123_456),            reader.read_leb128_i64());
+    assert_eq!(Ok(&b"bite"[..]),        reader.read_framed_format(FrameFormat::U8, 4));
+    assert_eq!(Ok("nul"),               reader.read_cstr(16));
+    assert_eq!(Ok("pad"),               reader.read_fixed_str(5, Padding::Space));
+    assert_eq!([1, 2, 3], values);
+
+    assert_eq!(Err(Error::ShortBuffer { needed: 4, available: 2 }), reader.read_u32());
+    assert_eq!(Err(Error::ShortBuffer { needed: 6, available: 2 }), reader.read_slice_u16(&mut values));
+    assert_eq!(length, reader.position());
+    assert_eq!(Ok(0), reader.read_u16());
+    assert!(reader.is_empty());
+
+    println!("verify: [{}] {} bytes through SliceWriter match the stream traits, and overruns fail cleanly", name, length);
+}
+
+// the `try_*` functions must report a short buffer, a value too wide for its field and an unsupported width with the
+// exact error, without touching the buffer, and accept everything at the edges of what fits.
+fn checked_buffers<T: Endianness>(name: &str) {
+    use {
+        bite::Error,
+    };
+
+    let short = |needed, available| Error::ShortBuffer { needed, available };
+    let wide  = |bytes| Error::ValueTooWide { bytes };
+    let width = |bytes| Error::InvalidWidth(bytes);
+
+    assert_eq!(short(4, 3),   T::try_read_u32(&[0; 3]).unwrap_err());
+    assert_eq!(short(16, 15), T::try_read_i128(&[0; 15]).unwrap_err());
+    assert_eq!(short(3, 2),   T::try_read_int(&[0; 2], 3).unwrap_err());
+    assert_eq!(short(6, 5),   T::try_read_slice_u16(&[0; 5], &mut [0; 3]).unwrap_err());
+    assert_eq!(short(2, 1),   T::try_write_u16(&mut [0; 1], 0).unwrap_err());
+    assert_eq!(short(8, 0),   T::try_write_f64(&mut [], 0.0).unwrap_err());
+    assert_eq!(short(5, 4),   T::try_write_uint(&mut [0; 4], 0, 5).unwrap_err());
+    assert_eq!(short(12, 11), T::try_write_slice_u32(&mut [0; 11], &[0; 3]).unwrap_err());
+
+    let mut data = [0xa5; 16];
+    assert_eq!(wide(2), T::try_write_uint(&mut data, 0x1_0000, 2).unwrap_err());
+    assert_eq!(wide(3), T::try_write_u24(&mut data, 0x100_0000).unwrap_err());
+    assert_eq!(wide(2), T::try_write_int(&mut data, 0x8000, 2).unwrap_err());
+    assert_eq!(wide(2), T::try_write_int(&mut data, -0x8001, 2).unwrap_err());
+    assert_eq!(wide(1), T::try_write_int(&mut data, -129, 1).unwrap_err());
+    assert_eq!(wide(3), T::try_write_i24(&mut data, -0x80_0001).unwrap_err());
+    assert_eq!(wide(8), T::try_write_uint128(&mut data, 1 << 64, 8).unwrap_err());
+    assert_eq!(wide(8), T::try_write_int128(&mut data, i64::MIN as i128 - 1, 8).unwrap_err());
+    assert_eq!([0xa5; 16], data);
+
+    assert_eq!(Ok(()), T::try_write_uint(&mut data, 0xffff, 2));
+    assert_eq!(Ok(()), T::try_write_int(&mut data, -0x8000, 2));
+    assert_eq!(Ok(-0x8000), T::try_read_int(&data, 2));
+    assert_eq!(Ok(()), T::try_write_int(&mut data, -128, 1));
+    assert_eq!(Ok(-128), T::try_read_int(&data, 1));
+
+    assert_eq!(width(0),  T::try_read_uint(&[0; 16], 0).unwrap_err());
+    assert_eq!(width(9),  T::try_read_int(&[0; 16], 9).unwrap_err());
+    assert_eq!(width(0),  T::try_read_int128(&[0; 16], 0).unwrap_err());
+    assert_eq!(width(17), T::try_read_uint128(&[0; 32], 17).unwrap_err());
+    assert_eq!(width(0),  T::try_write_uint(&mut [0; 16], 0, 0).unwrap_err());
+    assert_eq!(width(9),  T::try_write_int(&mut [0; 16], 0, 9).unwrap_err());
+    assert_eq!(width(17), T::try_write_int128(&mut [0; 32], 0, 17).unwrap_err());
+
+    // an unsupported width is reported before the length of the buffer.
+    assert_eq!(width(9),  T::try_read_uint(&[], 9).unwrap_err());
+
+    println!("verify: [{}] try_ functions report short buffers, wide values and bad widths exactly", name);
+}
+
+// `ByteWriter` must append the same bytes as the `Endianness` functions, and a reserved field must read as zero until
+// it's patched in place - while a slot patched into a writer too short to hold it must panic.
+fn reserved_slots<T: Endianness>(name: &str) {
+    use {
+        bite::ByteWriter,
+    };
+
+    let values = [0x0102_0304, 0x0506_0708, 0x090a_0b0c];
+    let floats = [1.5, -0.25];
+
+    let mut writer = ByteWriter::new();
+    let length     = writer.reserve_u32::<T>();
+
+    writer.write_u8(0xa5);
+    writer.write_u24::<T>(0x12_3456);
+    writer.write_slice_u32::<T>(&values);
+    writer.write_slice_f80::<T>(&floats);
+    writer.write_u128::<T>(!0 / 3);
+
+    let mut expected = vec![0; 4 + 1 + 3 + 12 + 20 + 16];
+    expected[4] = 0xa5;
+    T::write_u24(&mut expected[5..], 0x12_3456);
+    T::write_slice_u32(&mut expected[8..20], &values);
+    T::write_slice_f80(&mut expected[20..40], &floats);
+    T::write_u128(&mut expected[40..], !0 / 3);
+    assert_eq!(&expected[..], writer.as_slice());
+
+    let total = writer.position() as u32;
+
+    writer.patch(length, total);
+    T::write_u32(&mut expected, total);
+    assert_eq!(&expected[..], writer.as_slice());
+    assert_eq!(total, T::read_u32(writer.as_slice()));
+
+    // every slot in a writer from a previous message patches cleanly, but one past the end of a shorter writer doesn't.
+    let mut long  = ByteWriter::new();
+    long.write_bytes(&[0; 8]);
+    let slot      = long.reserve_u16::<T>();
+    let mut short = ByteWriter::new();
+    short.write_bytes(&[0; 8]);
+
+    let hook    = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| ()));
+    let patched = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| short.patch(slot, 0xffff)));
+    std::panic::set_hook(hook);
+
+    assert!(patched.is_err());
+    assert_eq!(8, short.position());
+
+    println!("verify: [{}] ByteWriter appends match the slice functions, and a {} byte message patches its length", name, total);
+}
+
+// every frame format must round-trip through the stream traits and `ByteReader`, including leb128 prefixes that count
+// themselves across a change in width, and payloads too long for their prefix must fail rather than wrap.
+fn frame_formats() {
+    use {
+        bite::BigEndian,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::ByteReader,
+        bite::FrameFormat,
+    };
+
+    let formats = [
+        FrameFormat::U8,
+        FrameFormat::U16.with_includes_prefix(true),
+        FrameFormat::U24.with_adjustment(2),
+        FrameFormat::U32,
+        FrameFormat::U64.with_includes_prefix(true).with_adjustment(5),
+        FrameFormat::LEB128,
+        FrameFormat::LEB128.with_includes_prefix(true),
+    ];
+
+    let mut checked = 0;
+
+    for format in &formats {
+        for &length in &[0, 1, 125, 126, 127, 128, 250, 253] {
+            let payload = vec![0xa5; length];
+
+            let mut stream = Vec::new();
+            stream.write_framed_format::<BigEndian>(*format, &payload).unwrap();
+
+            let mut reader = ByteReader::new(&stream);
+            assert_eq!(&payload[..], reader.read_framed_format_ref::<BigEndian>(*format, usize::MAX).unwrap());
+            assert!(reader.is_empty());
+
+            assert_eq!(payload, Cursor::new(&stream).read_framed_format::<BigEndian>(*format, usize::MAX).unwrap());
+
+            if length > 0 {
+                assert!(Cursor::new(&stream).read_framed_format::<BigEndian>(*format, length - 1).is_err());
+            }
+
+            checked += 1;
+        }
+    }
+
+    // 127 bytes and a one byte prefix is 128, which needs a second byte - so the prefix holds 129.
+    let mut stream = Vec::new();
+    stream.write_framed_format::<BigEndian>(FrameFormat::LEB128.with_includes_prefix(true), &[0; 127]).unwrap();
+    assert_eq!(&[0x81, 0x01], &stream[..2]);
+
+    let mut blocking = Vec::new();
+    let mut preset   = Vec::new();
+    blocking.write_framed::<BigEndian>(b"bite").unwrap();
+    preset.write_framed_format::<BigEndian>(FrameFormat::U32, b"bite").unwrap();
+    assert_eq!(blocking, preset);
+
+    let mut stream = Vec::new();
+    assert!(stream.write_framed_format::<BigEndian>(FrameFormat::U8, &[0; 256]).is_err());
+    assert!(stream.write_framed_format::<BigEndian>(FrameFormat::U8.with_adjustment(-1), &[]).is_err());
+    assert!(stream.is_empty());
+
+    println!("verify: [frames] {} payloads round-trip, and 256 bytes don't fit a u8 prefix: {}", checked, FrameFormat::U8.write_prefix::<BigEndian>(&mut [0; 1], 256).is_err());
+}
+
+// a length prefix that claims far more than arrives must fail without allocating for the claimed length, and a buffer
+// passed to `read_framed_into` must be reused from one frame to the next.
+fn hostile_frames() {
+    use {
+        bite::BiteReadExt,
+        bite::BiteWriteExt,
+    };
+
+    let mut buffer = Vec::new();
+    let mut stream = Cursor::new(vec![0x00, 0x00, 0x00, 0x40, 1, 2, 3]);
+
+    assert!(stream.read_framed_into(&mut buffer).is_err());
+    assert!(buffer.is_empty());
+    assert!(buffer.capacity() < 1 << 16);
+
+    let mut stream = Vec::new();
+    stream.write_framed(&[7; 10000]).unwrap();
+    stream.write_framed(b"bite").unwrap();
+
+    let mut stream = Cursor::new(stream);
+
+    stream.read_framed_into(&mut buffer).unwrap();
+    assert_eq!(&[7; 10000][..], &buffer[..]);
+
+    let capacity = buffer.capacity();
+
+    stream.read_framed_into(&mut buffer).unwrap();
+    assert_eq!(b"bite", &buffer[..]);
+    assert_eq!(capacity, buffer.capacity());
+
+    // a prefix over the maximum, or cut short, must not leave the previous frame behind in the buffer.
+    let mut previous = b"previous frame".to_vec();
+    assert!(Cursor::new([0xff, 0xff, 0xff, 0xff]).read_framed_max_into(16, &mut previous).is_err());
+    assert!(previous.is_empty());
+
+    let mut previous = b"previous frame".to_vec();
+    assert!(Cursor::new([0x00]).read_framed_max_into(16, &mut previous).is_err());
+    assert!(previous.is_empty());
+
+    println!("verify: [frames] a 1 GiB header with 3 bytes behind it fails, and {:?} reuses a {} byte buffer", String::from_utf8_lossy(&buffer), capacity);
+}
+
+// vectored frames must match `write_framed` byte for byte, in a single write when the stream accepts everything, and
+// across as many writes as it takes when it accepts only a few bytes at a time.
+fn vectored_frames() {
+    use {
+        std::io::IoSlice,
+        std::io::Write,
+
+        bite::BiteWriteExt,
+    };
+
+    // accepts at most `limit` bytes per call, and counts its calls.
+    struct Limited {
+        data:  Vec<u8>,
+        limit: usize,
+        calls: usize,
+    }
+
+    impl Write for Limited {
+        fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+            self.write_vectored(&[IoSlice::new(buffer)])
+        }
+
+        fn write_vectored(&mut self, buffers: &[IoSlice]) -> std::io::Result<usize> {
+            let mut written = 0;
+
+            for buffer in buffers {
+                let length = std::cmp::min(buffer.len(), self.limit - written);
+
+                self.data.extend_from_slice(&buffer[..length]);
+                written += length;
+            }
+
+            self.calls += 1;
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let frames: [&[u8]; 3] = [b"bite", b"", b"vectored"];
+
+    let mut expected = Vec::new();
+
+    for frame in &frames {
+        expected.write_framed(frame).unwrap();
+    }
+
+    let mut whole = Limited { data: Vec::new(), limit: usize::MAX, calls: 0 };
+    whole.write_frames(&frames).unwrap();
+    assert_eq!(expected, whole.data);
+    assert_eq!(1, whole.calls);
+
+    let mut trickle = Limited { data: Vec::new(), limit: 3, calls: 0 };
+    trickle.write_frames(&frames).unwrap();
+    assert_eq!(expected, trickle.data);
+
+    let mut single = Limited { data: Vec::new(), limit: usize::MAX, calls: 0 };
+    single.write_framed_vectored(b"bite").unwrap();
+    assert_eq!(&expected[..8], &single.data[..]);
+    assert_eq!(1, single.calls);
+
+    println!("verify: [frames] {} frames in {} vectored write, or {} writes of 3 bytes", frames.len(), whole.calls, trickle.calls);
+}
+
+// each kind of string must round-trip through the stream traits and `ByteReader`, strict reads must reject invalid
+// utf-8 that lossy reads replace, and strings that can't be represented must fail to write.
+fn strings() {
+    use {
+        std::borrow::Cow,
+        std::io::ErrorKind,
+
+        bite::BiteReadExt,
+        bite::BiteWriteExt,
+        bite::ByteReader,
+        bite::ByteWriter,
+        bite::FrameFormat,
+        bite::LittleEndian,
+        bite::Padding,
+    };
+
+    let mut stream = Vec::new();
+    stream.write_string(FrameFormat::U16, "bite").unwrap();
+    stream.write_cstring("c string").unwrap();
+    stream.write_fixed_string("fixed", 8, Padding::Space).unwrap();
+    stream.write_fixed_string("nul", 6, Padding::Nul).unwrap();
+    assert_eq!(&b"fixed   nul\0\0\0"[..], &stream[stream.len() - 14..]);
+
+    let mut writer = ByteWriter::new();
+    writer.write_string::<LittleEndian>(FrameFormat::U16, "bite").unwrap();
+    writer.write_cstring("c string").unwrap();
+    writer.write_fixed_string("fixed", 8, Padding::Space).unwrap();
+    writer.write_fixed_string("nul", 6, Padding::Nul).unwrap();
+    assert_eq!(stream, writer.as_slice());
+
+    let mut cursor = Cursor::new(&stream);
+    assert_eq!("bite",     cursor.read_string(FrameFormat::U16, 4).unwrap());
+    assert_eq!("c string", cursor.read_cstring(8).unwrap());
+    assert_eq!("fixed",    cursor.read_fixed_string(8, Padding::Space).unwrap());
+    assert_eq!("nul",      cursor.read_fixed_string(6, Padding::Nul).unwrap());
+
+    let mut reader = ByteReader::new(&stream);
+    assert_eq!("bite",     reader.read_str_ref::<LittleEndian>(FrameFormat::U16, 4).unwrap());
+    assert_eq!("c string", reader.read_cstr_ref(8).unwrap());
+    assert_eq!("fixed",    reader.read_fixed_str_ref(8, Padding::Space).unwrap());
+    assert_eq!("nul",      reader.read_fixed_str_ref(6, Padding::Nul).unwrap());
+    assert!(reader.is_empty());
+
+    assert!(Cursor::new(&stream[6..]).read_cstring(7).is_err());
+    assert!(ByteReader::new(&stream[6..]).read_cstr_ref(7).is_err());
+
+    let invalid = [2, 0, b'o', 0xff, b'k', 0];
+
+    assert_eq!(ErrorKind::InvalidData, Cursor::new(&invalid).read_string(FrameFormat::U16, 2).unwrap_err().kind());
+    assert_eq!("o\u{fffd}", Cursor::new(&invalid).read_string_lossy(FrameFormat::U16, 2).unwrap());
+    assert_eq!("\u{fffd}k", Cursor::new(&invalid[3..]).read_cstring_lossy(2).unwrap());
+
+    let mut reader = ByteReader::new(&invalid);
+    assert!(reader.read_str_ref::<LittleEndian>(FrameFormat::U16, 2).is_err());
+    assert_eq!(0, reader.position());
+    assert!(matches!(reader.read_str_lossy::<LittleEndian>(FrameFormat::U16, 2).unwrap(), Cow::Owned(_)));
+    assert!(matches!(ByteReader::new(&stream).read_str_lossy::<LittleEndian>(FrameFormat::U16, 4).unwrap(), Cow::Borrowed("bite")));
+
+    let mut stream = Vec::new();
+    assert_eq!(ErrorKind::InvalidInput, stream.write_cstring("nul\0").unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidInput, stream.write_fixed_string("too long", 4, Padding::Space).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidInput, stream.write_fixed_string("n\0l", 4, Padding::Nul).unwrap_err().kind());
+    assert!(stream.is_empty());
+
+    println!("verify: [strings] {:?} == \"o\u{fffd}\" when read lossily", Cursor::new(&invalid).read_string_lossy(FrameFormat::U16, 2).unwrap());
+}
+
+// utf-16 and utf-32 text must round-trip in both byte orders, including characters outside the basic multilingual
+// plane, a byte order mark must override the requested order, and invalid units must be rejected.
+fn wide_strings() {
+    use {
+        std::io::ErrorKind,
+
+        bite::BigEndian,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::LittleEndian,
+    };
+
+    let text = "bite \u{1f96a}";
+
+    let mut stream = Vec::new();
+    stream.write_utf16::<BigEndian>(text).unwrap();
+    stream.write_utf16_nul::<LittleEndian>(text).unwrap();
+    stream.write_utf32::<LittleEndian>(text).unwrap();
+    stream.write_utf32_nul::<BigEndian>(text).unwrap();
+
+    let mut cursor = Cursor::new(&stream);
+    assert_eq!(text, cursor.read_utf16::<BigEndian>(7).unwrap());
+    assert_eq!(text, cursor.read_utf16_nul::<LittleEndian>().unwrap());
+    assert_eq!(text, cursor.read_utf32::<LittleEndian>(6).unwrap());
+    assert_eq!(text, cursor.read_utf32_nul::<BigEndian>().unwrap());
+    assert_eq!(stream.len() as u64, cursor.position());
+
+    let mut stream = Vec::new();
+    stream.write_utf16_bom::<LittleEndian>(text).unwrap();
+    stream.write_utf16_bom::<BigEndian>(text).unwrap();
+    stream.write_u16::<BigEndian>(0).unwrap();
+    stream.write_utf32_bom::<LittleEndian>(text).unwrap();
+    stream.write_u32::<LittleEndian>(0).unwrap();
+    stream.write_utf32_bom::<BigEndian>(text).unwrap();
+
+    let mut cursor = Cursor::new(&stream);
+    assert_eq!(text, cursor.read_utf16_bom::<BigEndian>(8).unwrap());
+    assert_eq!(text, cursor.read_utf16_nul_bom::<LittleEndian>().unwrap());
+    assert_eq!(text, cursor.read_utf32_nul_bom::<BigEndian>().unwrap());
+    assert_eq!(text, cursor.read_utf32_bom::<LittleEndian>(7).unwrap());
+
+    assert_eq!("bi", Cursor::new(&[0, b'b', 0, b'i']).read_utf16_bom::<BigEndian>(2).unwrap());
+    assert_eq!("",   Cursor::new(&[0, 0]).read_utf16_nul_bom::<BigEndian>().unwrap());
+
+    assert_eq!(ErrorKind::InvalidData, Cursor::new(&[0x00, 0xd8, 0x61, 0x00]).read_utf16::<LittleEndian>(2).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, Cursor::new(&[0x00, 0xdc]).read_utf16::<LittleEndian>(1).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, Cursor::new(&[0x00, 0xd8, 0x00, 0x00]).read_utf32::<LittleEndian>(1).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, Cursor::new(&[0x00, 0x00, 0x11, 0x00]).read_utf32::<LittleEndian>(1).unwrap_err().kind());
+
+    // the fixed order and runtime forms must write and read the same units as the generic ones.
+    let mut stream = Vec::new();
+    bite::BiteWriteBeExt::write_utf16_be(&mut stream, text).unwrap();
+    bite::BiteWriteLeExt::write_utf32_nul_le(&mut stream, text).unwrap();
+    bite::BiteWriteExt::write_utf16_bom(&mut stream, text).unwrap();
+    stream.write_utf32_with(bite::Endian::Big, text).unwrap();
+
+    let mut expected = Vec::new();
+    expected.write_utf16::<BigEndian>(text).unwrap();
+    expected.write_utf32_nul::<LittleEndian>(text).unwrap();
+    expected.write_utf16_bom::<LittleEndian>(text).unwrap();
+    expected.write_utf32::<BigEndian>(text).unwrap();
+    assert_eq!(expected, stream);
+
+    let mut cursor = Cursor::new(&stream);
+    assert_eq!(text, cursor.read_utf16_with(bite::Endian::Big, 7).unwrap());
+    assert_eq!(text, bite::BiteReadLeExt::read_utf32_nul_le(&mut cursor).unwrap());
+    assert_eq!(text, bite::BiteReadBeExt::read_utf16_bom_be(&mut cursor, 8).unwrap());
+    assert_eq!(text, bite::BiteReadBeExt::read_utf32_be(&mut cursor, 6).unwrap());
+    assert_eq!(stream.len() as u64, cursor.position());
+
+    println!("verify: [strings] {:?} round-trips through utf-16 and utf-32, with and without a byte order mark", text);
+}
+
+
+// 16-bit floats must round to nearest even when narrowed, and widen exactly - including subnormals, infinities and nan
+// payloads.
+fn half_floats() {
+    use {
+        bite::BigEndian,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::ByteReader,
+        bite::Endian,
+        bite::LittleEndian,
+        bite::SliceWriter,
+    };
+
+    let f16  = |x: f32| { let mut data = [0; 2]; BigEndian::write_f16(&mut data, x); BigEndian::read_u16(&data) };
+    let bf16 = |x: f32| { let mut data = [0; 2]; BigEndian::write_bf16(&mut data, x); BigEndian::read_u16(&data) };
+
+    assert_eq!(0x3c00, f16(1.0));
+    assert_eq!(0x8000, f16(-0.0));
+    assert_eq!(0x7bff, f16(65504.0));
+    assert_eq!(0x7bff, f16(65519.0));
+    assert_eq!(0x7c00, f16(65520.0));
+    assert_eq!(0xfc00, f16(f32::NEG_INFINITY));
+    assert_eq!(0x3c00, f16(1.0 + 2f32.powi(-11)));
+    assert_eq!(0x3c02, f16(1.0 + 3.0 * 2f32.powi(-11)));
+    assert_eq!(0x0001, f16(2f32.powi(-24)));
+    assert_eq!(0x0000, f16(2f32.powi(-25)));
+    assert_eq!(0x0001, f16(1.5 * 2f32.powi(-25)));
+    assert_eq!(0x0002, f16(3.0 * 2f32.powi(-25)));
+    assert_eq!(0x0400, f16(2f32.powi(-14) - 2f32.powi(-26)));
+    assert_eq!(0x8000, f16(-f32::from_bits(1)));
+    assert_eq!(0x7e01, f16(f32::from_bits(0x7fc0_2000)));
+    assert_eq!(0x7e00, f16(f32::from_bits(0x7f80_0001)));
+
+    assert_eq!(0x3f80, bf16(1.0));
+    assert_eq!(0x3f80, bf16(f32::from_bits(0x3f80_8000)));
+    assert_eq!(0x3f82, bf16(f32::from_bits(0x3f81_8000)));
+    assert_eq!(0x7f80, bf16(f32::from_bits(0x7f7f_ffff)));
+    assert_eq!(0x7fc0, bf16(f32::from_bits(0x7f80_0001)));
+    assert_eq!(0xffc1, bf16(f32::from_bits(0xffc1_0000)));
+
+    for x in 0..=0xffffu16 {
+        let mut data = [0; 2];
+        LittleEndian::write_u16(&mut data, x);
+
+        assert_eq!(x, f16(LittleEndian::read_f16(&data)));
+        assert_eq!(x, bf16(LittleEndian::read_bf16(&data)));
+    }
+
+    let values = [1.0, -2.5, 2f32.powi(-24), f32::INFINITY, 65504.0];
+
+    let mut stream = Vec::new();
+    stream.write_slice_f16::<LittleEndian>(&values).unwrap();
+    stream.write_slice_bf16::<BigEndian>(&values[..2]).unwrap();
+    stream.write_f16_with(Endian::Big, 0.5).unwrap();
+
+    let mut f16s  = [0.0; 5];
+    let mut bf16s = [0.0; 2];
+    let mut cursor = Cursor::new(&stream);
+    cursor.read_slice_f16::<LittleEndian>(&mut f16s).unwrap();
+    cursor.read_slice_bf16::<BigEndian>(&mut bf16s).unwrap();
+    assert_eq!(values, f16s);
+    assert_eq!(values[..2], bf16s);
+    assert_eq!(0.5, cursor.read_f16_with(Endian::Big).unwrap());
+
+    let mut reader = ByteReader::new(&stream);
+    reader.read_slice_f16::<LittleEndian>(&mut f16s).unwrap();
+    assert_eq!(values, f16s);
+    assert_eq!(1.0, reader.read_bf16::<BigEndian>().unwrap());
+
+    let mut data   = [0; 4];
+    let mut writer = SliceWriter::<LittleEndian>::new(&mut data);
+    writer.write_slice_f16(&values[..2]).unwrap();
+    assert!(writer.write_bf16(1.0).is_err());
+    assert_eq!(stream[..4], data);
+
+    println!("verify: [half floats] f16 and bf16 round correctly and every bit pattern round-trips through f32");
+}
+
+
+// 80-bit extended floats must decode their explicit integer bit, round correctly into an f64 and say when they did.
+fn extended_floats() {
+    use {
+        bite::BigEndian,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::ByteReader,
+        bite::Endian,
+        bite::LittleEndian,
+        bite::SliceWriter,
+    };
+
+    let f80 = |exponent: u16, significand: u64| {
+        let mut data = [0; 10];
+        BigEndian::write_u16(&mut data, exponent);
+        BigEndian::write_u64(&mut data[2..], significand);
+        BigEndian::read_f80(&data)
+    };
+
+    let (rate, rounded) = f80(0x400e, 0xac44_0000_0000_0000);
+    assert_eq!((44100.0, false), (rate, rounded));
+
+    assert_eq!((1.0, false),                       f80(0x3fff, 0x8000_0000_0000_0000));
+    assert_eq!((-2.0, false),                      f80(0xc000, 0x8000_0000_0000_0000));
+    assert_eq!((1.0, true),                        f80(0x3fff, 0x8000_0000_0000_0400));
+    assert_eq!((1.0 + 2f64.powi(-51), true),       f80(0x3fff, 0x8000_0000_0000_0c00));
+    assert_eq!((f64::INFINITY, true),              f80(0x43ff, 0x8000_0000_0000_0000));
+    assert_eq!((f64::INFINITY, false),             f80(0x7fff, 0x8000_0000_0000_0000));
+    assert_eq!((0.0, true),                        f80(0x3bcc, 0x8000_0000_0000_0000));
+    assert_eq!((f64::from_bits(1), true),          f80(0x3bcc, 0xc000_0000_0000_0000));
+    assert_eq!((1.0, false),                       f80(0x4000, 0x4000_0000_0000_0000));
+    assert_eq!((0.0, true),                        f80(0x0000, 0x8000_0000_0000_0000));
+    assert_eq!((0.0, false),                       f80(0x1234, 0x0000_0000_0000_0000));
+
+    let (nan, rounded) = f80(0x7fff, 0x0000_0000_0000_0000);
+    assert!(nan.is_nan() && rounded);
+
+    let (nan, rounded) = f80(0xffff, 0x8000_0000_0000_0001);
+    assert_eq!((0xfff8_0000_0000_0000, true), (nan.to_bits(), rounded));
+
+    let values = [
+        0.0, -0.0, 1.0, std::f64::consts::PI, f64::MIN_POSITIVE, f64::from_bits(1), f64::MAX, f64::NEG_INFINITY,
+        f64::from_bits(0x7ff4_0000_0000_0001),
+    ];
+
+    let mut stream = Vec::new();
+    stream.write_slice_f80::<LittleEndian>(&values).unwrap();
+    stream.write_f80_with(Endian::Big, 0.5).unwrap();
+
+    let mut decoded = [0.0; 9];
+    let mut cursor  = Cursor::new(&stream);
+    assert!(!cursor.read_slice_f80::<LittleEndian>(&mut decoded).unwrap());
+    assert_eq!((0.5, false), cursor.read_f80_with(Endian::Big).unwrap());
+
+    for (x, y) in values.iter().zip(decoded.iter()) {
+        assert_eq!(x.to_bits(), y.to_bits());
+    }
+
+    let mut reader = ByteReader::new(&stream);
+    assert_eq!((0.0, false), reader.read_f80::<LittleEndian>().unwrap());
+    assert!(!reader.read_slice_f80::<LittleEndian>(&mut decoded[..8]).unwrap());
+    assert!(reader.read_slice_f80::<LittleEndian>(&mut decoded[..2]).is_err());
+
+    let mut data   = [0; 20];
+    let mut writer = SliceWriter::<LittleEndian>::new(&mut data);
+    writer.write_slice_f80(&values[..2]).unwrap();
+    assert!(writer.write_f80(1.0).is_err());
+    assert_eq!(stream[..20], data);
+
+    println!("verify: [extended floats] {} from an aiff header, and {} f64s round-trip exactly", rate, values.len());
+}
+
+
+// ibm hexadecimal and vax floats must decode known encodings, round when encoding and map what they can't represent
+// as documented.
+fn legacy_floats() {
+    use {
+        bite::BigEndian,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::ByteReader,
+        bite::ByteWriter,
+        bite::LittleEndian,
+    };
+
+    let ibm32 = |x: f32| { let mut data = [0; 4]; BigEndian::write_ibm_f32(&mut data, x); BigEndian::read_u32(&data) };
+    let ibm64 = |x: f64| { let mut data = [0; 8]; BigEndian::write_ibm_f64(&mut data, x); BigEndian::read_u64(&data) };
+    let vax_f = |x: f32| { let mut data = [0; 4]; LittleEndian::write_vax_f(&mut data, x); data };
+    let vax_d = |x: u64| { let mut data = [0; 8]; BigEndian::write_u64(&mut data, x); LittleEndian::read_vax_d(&reorder(&data)) };
+    let vax_g = |x: f64| { let mut data = [0; 8]; LittleEndian::write_vax_g(&mut data, x); LittleEndian::read_vax_g(&data) };
+
+    // vax words are little endian, most significant word first - this turns big endian bytes into that order.
+    fn reorder(data: &[u8]) -> Vec<u8> {
+        data.chunks(2).flat_map(|x| vec![x[1], x[0]]).collect()
+    }
+
+    assert_eq!(-118.625, BigEndian::read_ibm_f32(&[0xc2, 0x76, 0xa0, 0x00]));
+    assert_eq!(0x4110_0000, ibm32(1.0));
+    assert_eq!(0x4019_999a, ibm32(0.1));
+    assert_eq!(0xc276_a000, ibm32(-118.625));
+    assert_eq!(0x7fff_ffff, ibm32(f32::INFINITY));
+    assert_eq!(f32::INFINITY, BigEndian::read_ibm_f32(&[0x7f, 0x10, 0x00, 0x00]));
+    assert_eq!(0.0, BigEndian::read_ibm_f32(&[0x00, 0x10, 0x00, 0x00]));
+
+    assert_eq!(0x7fff_ffff_ffff_ffff, ibm64(1e300));
+    assert_eq!(0xffff_ffff_ffff_ffff, ibm64(f64::NEG_INFINITY));
+    assert_eq!(0x0000_0000_0000_0000, ibm64(1e-300));
+
+    for &x in &[std::f64::consts::PI, -1e75, 1e-78, 0.1, 1.0 / 3.0] {
+        assert_eq!(x, BigEndian::read_ibm_f64(&ibm64(x).to_be_bytes()));
+    }
+
+    assert_eq!([0x80, 0x40, 0x00, 0x00], vax_f(1.0));
+    assert_eq!([0x00, 0x80, 0x00, 0x00], vax_f(f32::NAN));
+    assert_eq!([0xff, 0xff, 0xff, 0xff], vax_f(f32::NEG_INFINITY));
+    assert_eq!([0xff, 0x7f, 0xff, 0xff], vax_f(f32::MAX));
+    assert_eq!([0x00, 0x00, 0x00, 0x00], vax_f(2f32.powi(-130)));
+    assert_eq!([0x00, 0x00, 0x00, 0x00], vax_f(-0.0));
+    assert_eq!(2f32.powi(-128), LittleEndian::read_vax_f(&vax_f(2f32.powi(-128))));
+    assert!(LittleEndian::read_vax_f(&[0x00, 0x80, 0x00, 0x00]).is_nan());
+    assert_eq!(0.0, LittleEndian::read_vax_f(&[0x00, 0x00, 0x12, 0x34]));
+
+    assert_eq!(1.0,                  vax_d(0x4080_0000_0000_0000));
+    assert_eq!(1.0,                  vax_d(0x4080_0000_0000_0004));
+    assert_eq!(1.0 + 2f64.powi(-51), vax_d(0x4080_0000_0000_000c));
+    assert_eq!(-0.75,                vax_d(0xc040_0000_0000_0000));
+
+    assert_eq!(f64::MIN_POSITIVE, vax_g(f64::MIN_POSITIVE));
+    assert_eq!(0.0,               vax_g(f64::from_bits(1)));
+    assert_eq!(f64::MAX / 2.0,    vax_g(f64::MAX));
+    assert_eq!(f64::from_bits(1 << 50), LittleEndian::read_vax_g(&reorder(&[0x00, 0x10, 0, 0, 0, 0, 0, 0x01])));
+
+    let values = [1.0, -2.5, 1234.5678, 1e-30];
+
+    let mut stream = Vec::new();
+    stream.write_slice_vax_d::<LittleEndian>(&values).unwrap();
+    stream.write_slice_ibm_f64::<BigEndian>(&values).unwrap();
+    stream.write_vax_g::<LittleEndian>(0.5).unwrap();
+
+    let mut vax = [0.0; 4];
+    let mut ibm = [0.0; 4];
+    let mut cursor = Cursor::new(&stream);
+    cursor.read_slice_vax_d::<LittleEndian>(&mut vax).unwrap();
+    cursor.read_slice_ibm_f64::<BigEndian>(&mut ibm).unwrap();
+    assert_eq!(values, vax);
+    assert_eq!(values, ibm);
+    assert_eq!(0.5, cursor.read_vax_g::<LittleEndian>().unwrap());
+
+    let mut writer = ByteWriter::new();
+    writer.write_slice_vax_d::<LittleEndian>(&values);
+    writer.write_slice_ibm_f64::<BigEndian>(&values);
+    writer.write_vax_g::<LittleEndian>(0.5);
+    assert_eq!(stream, writer.as_slice());
+
+    let mut reader = ByteReader::new(&stream[32..]);
+    assert_eq!(1.0, reader.read_ibm_f64::<BigEndian>().unwrap());
+
+    // the fixed order and runtime forms must match the generic ones.
+    let mut fixed = Vec::new();
+    bite::BiteWriteLeExt::write_slice_vax_d_le(&mut fixed, &values).unwrap();
+    bite::BiteWriteBeExt::write_slice_ibm_f64_be(&mut fixed, &values).unwrap();
+    bite::BiteWriteExt::write_vax_g(&mut fixed, 0.5).unwrap();
+    fixed.write_ibm_f32_with(bite::Endian::Big, -118.625).unwrap();
+    assert_eq!(&stream[..], &fixed[..stream.len()]);
+
+    let mut cursor = Cursor::new(&fixed);
+    cursor.read_slice_vax_d_with(bite::Endian::Little, &mut vax).unwrap();
+    bite::BiteReadBeExt::read_slice_ibm_f64_be(&mut cursor, &mut ibm).unwrap();
+    assert_eq!(values, vax);
+    assert_eq!(values, ibm);
+    assert_eq!(0.5,      bite::BiteReadLeExt::read_vax_g_le(&mut cursor).unwrap());
+    assert_eq!(-118.625, bite::BiteReadBeExt::read_ibm_f32_be(&mut cursor).unwrap());
+
+    // runtime encodings, checked functions and no_std cursors must agree with the stream traits, and refuse short
+    // buffers without writing anything.
+    let mut data = [0; 32];
+    bite::Endian::Little.write_slice_vax_d(&mut data, &values);
+    assert_eq!(&stream[..32], &data[..]);
+    assert_eq!(Ok(()), bite::Endian::Big.try_read_slice_ibm_f64(&stream[32..64], &mut ibm));
+    assert_eq!(values, ibm);
+    assert_eq!(0.5, bite::Endian::Little.read_vax_g(&stream[64..]));
+    assert_eq!(Ok(0.5), LittleEndian::try_read_vax_g(&stream[64..]));
+
+    assert_eq!(Err(bite::Error::ShortBuffer { needed: 8, available: 7 }), LittleEndian::try_read_vax_d(&stream[..7]));
+    assert_eq!(Err(bite::Error::ShortBuffer { needed: 4, available: 3 }), BigEndian::try_write_ibm_f32(&mut data[..3], 1.0));
+    assert_eq!(Err(bite::Error::ShortBuffer { needed: 32, available: 31 }), bite::Endian::Little.try_write_slice_vax_f(&mut data[..31], &[1.0; 8]));
+
+    let mut data   = [0; 48];
+    let mut writer = bite::SliceWriter::<LittleEndian>::new(&mut data);
+    writer.write_slice_vax_d(&values).unwrap();
+    assert!(writer.write_slice_vax_g(&[0.0; 6]).is_err());
+    writer.write_vax_g(0.5).unwrap();
+    writer.write_vax_f(1.0).unwrap();
+    assert!(writer.write_ibm_f64(1.0).is_err());
+    assert_eq!(44, writer.position());
+
+    let mut reader = bite::SliceReader::<LittleEndian>::new(&data);
+    reader.read_slice_vax_d(&mut vax).unwrap();
+    assert_eq!(values, vax);
+    assert_eq!(0.5, reader.read_vax_g().unwrap());
+    assert_eq!(1.0, reader.read_vax_f().unwrap());
+
+    let mut reader = bite::SliceReader::<BigEndian>::new(&stream[32..]);
+    reader.read_slice_ibm_f64(&mut ibm).unwrap();
+    assert_eq!(values, ibm);
+
+    println!("verify: [legacy floats] ibm and vax floats decode, round and saturate as documented");
+}
+
+// q format numbers must round to nearest even and saturate when converted, sign extend from narrow words, and refuse
+// fields wider than their integer.
+fn fixed_point() {
+    use {
+        bite::BigEndian,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::ByteReader,
+        bite::ByteWriter,
+        bite::Error,
+        bite::Fixed,
+        bite::LittleEndian,
+        bite::Q15,
+        bite::Q31,
+    };
+
+    type Q8_8  = Fixed<i16, 8>;
+    type Q23   = Fixed<i32, 23>;
+    type UQ0_8 = Fixed<u8, 8>;
+
+    assert_eq!(0x4000,  Q15::from_f64(0.5).to_bits());
+    assert_eq!(-0x8000, Q15::from_f64(-1.0).to_bits());
+    assert_eq!(0x7fff,  Q15::from_f64(1.0).to_bits());
+    assert_eq!(-0x8000, Q15::from_f32(f32::NEG_INFINITY).to_bits());
+    assert_eq!(0,       Q15::from_f64(f64::NAN).to_bits());
+    assert_eq!(0x7fff_ffff, Q31::from_f64(2.0).to_bits());
+    assert_eq!(0.999_969_482_421_875, Q15::from_bits(0x7fff).to_f64());
+    assert_eq!(-1.0, Q31::from_bits(i32::MIN).to_f32());
+
+    // ties round to even.
+    assert_eq!(2,  Q8_8::from_f64(2.5 / 256.0).to_bits());
+    assert_eq!(4,  Q8_8::from_f64(3.5 / 256.0).to_bits());
+    assert_eq!(-2, Q8_8::from_f64(-2.5 / 256.0).to_bits());
+    assert_eq!(-0x100, Q8_8::from_f64(-1.0).to_bits());
+    assert_eq!(255, UQ0_8::from_f64(1.5).to_bits());
+    assert_eq!(0,   UQ0_8::from_f64(-0.25).to_bits());
+
+    // a q23 coefficient packed into a 24-bit word sign extends.
+    assert_eq!(-0.5, Q23::read::<BigEndian>(&[0xc0, 0x00, 0x00], 3).to_f64());
+    assert_eq!(Err(Error::InvalidWidth(3)), Q15::try_read::<BigEndian>(&[0; 3], 3));
+    assert_eq!(Err(Error::ValueTooWide { bytes: 3 }), Q23::from_bits(1 << 23).try_write::<BigEndian>(&mut [0; 3], 3));
+
+    let coefficients = [0.5, -0.25, 0.999, -1.0, 0.0].iter().map(|&x| Q23::from_f64(x)).collect::<Vec<_>>();
+
+    let mut stream = Vec::new();
+    stream.write_slice_fixed_point::<LittleEndian, _, 23>(&coefficients, 3).unwrap();
+    stream.write_fixed_point::<BigEndian, _, 15>(Q15::from_f64(-0.5), 2).unwrap();
+    assert_eq!([0x00, 0x00, 0x40], stream[..3]);
+    assert_eq!(17, stream.len());
+
+    let mut table = [Q23::default(); 5];
+    let mut cursor = Cursor::new(&stream);
+    cursor.read_slice_fixed_point::<LittleEndian, _, 23>(&mut table, 3).unwrap();
+    assert_eq!(coefficients, table);
+    assert_eq!(-0.5, cursor.read_fixed_point::<BigEndian, i16, 15>(2).unwrap().to_f64());
+
+    let mut writer = ByteWriter::new();
+    writer.write_slice_fixed_point::<LittleEndian, _, 23>(&coefficients, 3).unwrap();
+    writer.write_fixed_point::<BigEndian, _, 15>(Q15::from_f64(-0.5), 2).unwrap();
+    assert!(writer.write_slice_fixed_point::<BigEndian, _, 23>(&[Q23::from_bits(i32::MAX)], 3).is_err());
+    assert_eq!(stream, writer.as_slice());
+
+    let mut reader = ByteReader::new(&stream);
+    let mut table  = [Q23::default(); 5];
+    reader.read_slice_fixed_point::<LittleEndian, _, 23>(&mut table, 3).unwrap();
+    assert_eq!(coefficients, table);
+    assert_eq!(Q15::from_f64(-0.5), reader.read_fixed_point::<BigEndian, i16, 15>(2).unwrap());
+
+    // a field wider than the integer behind it is a bug in the caller, not something to truncate.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| ()));
+
+    assert!(std::panic::catch_unwind(|| Q8_8::read::<BigEndian>(&[0x12, 0x34, 0x56, 0x78], 4)).is_err());
+    assert!(std::panic::catch_unwind(|| Q8_8::from_bits(1).write::<BigEndian>(&mut [0; 4], 4)).is_err());
+    assert!(std::panic::catch_unwind(|| Q8_8::read_slice::<BigEndian>(&[0; 8], &mut [Q8_8::default(); 2], 4)).is_err());
+    assert!(std::panic::catch_unwind(|| Q8_8::write_slice::<BigEndian>(&mut [0; 8], &[Q8_8::default(); 2], 4)).is_err());
+
+    std::panic::set_hook(hook);
+
+    println!("verify: [fixed point] q format numbers convert, saturate and pack into narrow words");
+}
+
+// packed bcd and zoned decimal fields must round-trip up to 31 digits with the scale they're given, reject scales and
+// values too wide for the field, and reject nibbles that aren't digits or signs without consuming anything.
+fn decimals() {
+    use {
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::ByteReader,
+        bite::ByteWriter,
+        bite::Decimal,
+        bite::Error,
+        bite::SliceWriter,
+        std::io::ErrorKind,
+    };
+
+    let largest = 10i128.pow(31) - 1;
+
+    // 123.45 and -123.45 as a cobol `pic s9(3)v99`, then a fraction using every digit of a 16 byte field.
+    let mut stream = Vec::new();
+    stream.write_packed_bcd(Decimal::new(12345, 2), 3).unwrap();
+    stream.write_packed_bcd(Decimal::new(-12345, 2), 3).unwrap();
+    stream.write_packed_bcd(Decimal::new(0, 0), 1).unwrap();
+    stream.write_packed_bcd(Decimal::new(-largest, 31), 16).unwrap();
+    stream.write_zoned_decimal(Decimal::new(-123, 1), 3).unwrap();
+    stream.write_zoned_decimal(Decimal::new(42, 0), 4).unwrap();
+    assert_eq!([0x12, 0x34, 0x5c, 0x12, 0x34, 0x5d, 0x0c], stream[..7]);
+    assert_eq!([0xf1, 0xf2, 0xd3, 0xf0, 0xf0, 0xf4, 0xc2], stream[23..]);
+
+    let mut cursor = Cursor::new(&stream);
+    let fields     = [
+        cursor.read_packed_bcd(3, 2).unwrap(),
+        cursor.read_packed_bcd(3, 2).unwrap(),
+        cursor.read_packed_bcd(1, 0).unwrap(),
+        cursor.read_packed_bcd(16, 31).unwrap(),
+        cursor.read_zoned_decimal(3, 1).unwrap(),
+        cursor.read_zoned_decimal(4, 0).unwrap(),
+    ];
+
+    let expected = [(12345, 2), (-12345, 2), (0, 0), (-largest, 31), (-123, 1), (42, 0)];
+    assert_eq!(expected.map(|(unscaled, scale)| Decimal { unscaled, scale }), fields);
+
+    // what's read writes straight back, scale and all.
+    let mut writer = ByteWriter::new();
+
+    for (&value, &bytes) in fields[..4].iter().zip(&[3, 3, 1, 16]) {
+        writer.write_packed_bcd(value, bytes).unwrap();
+    }
+
+    for (&value, &bytes) in fields[4..].iter().zip(&[3, 4]) {
+        writer.write_zoned_decimal(value, bytes).unwrap();
+    }
+
+    assert_eq!(Err(Error::ValueTooWide { bytes: 2 }), writer.write_zoned_decimal(Decimal::new(100, 0), 2));
+    assert_eq!(stream, writer.as_slice());
+
+    let error = |x: std::io::Error| x.kind();
+    let mut sink = Vec::new();
+    assert_eq!(ErrorKind::InvalidInput, sink.write_packed_bcd(Decimal::new(1000, 0), 2).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidInput, sink.write_packed_bcd(Decimal::new(0, 0), 17).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidInput, sink.write_packed_bcd(Decimal::new(1, 4), 2).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidInput, sink.write_zoned_decimal(Decimal::new(1, 5), 4).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidData,  Cursor::new([0x1a, 0x2c]).read_packed_bcd(2, 0).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidData,  Cursor::new([0x12, 0x34]).read_packed_bcd(2, 0).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidData,  Cursor::new([0xf1, 0x32]).read_zoned_decimal(2, 0).map_err(error).unwrap_err());
+    assert_eq!(ErrorKind::InvalidData,  Cursor::new([0xf1, 0xfa]).read_zoned_decimal(2, 0).map_err(error).unwrap_err());
+    assert!(sink.is_empty());
+
+    // a scale wider than the field is refused before anything is read.
+    let mut cursor = Cursor::new([0x12, 0x34, 0x5c]);
+    assert_eq!(ErrorKind::InvalidInput, cursor.read_packed_bcd(3, 6).map_err(error).unwrap_err());
+    assert_eq!(0, cursor.position());
+    assert_eq!(Decimal::new(12345, 5), cursor.read_packed_bcd(3, 5).unwrap());
+
+    // unsigned `f` signs read as positive, and an invalid field consumes nothing.
+    let scale      = Error::InvalidInput("decimal scale is greater than the digits in the field");
+    let mut reader = ByteReader::new(&[0x99, 0x9f, 0xf9, 0xf9, 0xe9, 0x12, 0x3c]);
+    assert_eq!(Ok(Decimal::new(999, 1)), reader.read_packed_bcd(2, 1));
+    assert_eq!(Err(Error::InvalidData("decimal digit is greater than 9")), reader.read_packed_bcd(2, 0));
+    assert_eq!(Err(Error::InvalidWidth(32)), reader.read_zoned_decimal(32, 0));
+    assert_eq!(Err(scale), reader.read_zoned_decimal(3, 4));
+    assert_eq!(Ok(Decimal::new(999, 3)), reader.read_zoned_decimal(3, 3));
+    assert_eq!(Ok(Decimal::new(123, 0)), reader.read_packed_bcd(2, 0));
+
+    let mut data   = [0; 4];
+    let mut writer = SliceWriter::<bite::BigEndian>::new(&mut data);
+    writer.write_zoned_decimal(Decimal::new(-7, 0), 1).unwrap();
+    writer.write_packed_bcd(Decimal::new(-7, 1), 2).unwrap();
+    assert_eq!(Err(Error::ShortBuffer { needed: 2, available: 1 }), writer.write_packed_bcd(Decimal::new(1, 0), 2));
+    assert_eq!(Err(scale), writer.write_zoned_decimal(Decimal::new(1, 2), 1));
+    assert_eq!([0xd7, 0x00, 0x7d, 0x00], data);
+
+    println!("verify: [decimals] packed bcd and zoned decimal fields round-trip with their scale, as in {:?}", fields[0]);
+}
+
+
+// derived structs must read and write their fields in order - in the encoding they're given unless a struct or field
+// names its own - through fixed arrays, nested structs and `len` counted vectors, and a vector that disagrees with
+// its count must fail to write.
+#[cfg(feature = "derive")]
+fn derived_structs<T: Endianness>(name: &str) {
+    use {
+        std::io::ErrorKind,
+        std::io::Write,
+
+        bite::BiteRead,
+        bite::BiteWrite,
+        bite::BiteWriteExpandedExt,
+    };
+
+    #[derive(BiteRead, BiteWrite, Clone, Copy, Debug, PartialEq)]
+    struct Point(i16, i16);
+
+    #[derive(BiteRead, BiteWrite, Debug, PartialEq)]
+    #[bite(endian = "big")]
+    struct Header {
+        magic:   u32,
+        #[bite(endian = "little")]
+        version: u16,
+        flags:   [u8; 3],
+    }
+
+    #[derive(BiteRead, BiteWrite, Debug, PartialEq)]
+    struct Record {
+        header: Header,
+        origin: Point,
+        count:  u16,
+        #[bite(len = "count")]
+        points: Vec<Point>,
+        #[bite(endian = "little")]
+        crc:    u32,
+        tail:   [i32; 2],
+    }
+
+    let record = Record {
+        header: Header { magic: 0x6269_7465, version: 0x0102, flags: [1, 2, 3] },
+        origin: Point(-1, 2),
+        count:  2,
+        points: vec![Point(3, -4), Point(0x1234, -0x1234)],
+        crc:    0xdead_beef,
+        tail:   [i32::MIN, 0x0506_0708],
+    };
+
+    let mut expected = Vec::new();
+    expected.write_u32::<bite::BigEndian>(0x6269_7465).unwrap();
+    expected.write_u16::<bite::LittleEndian>(0x0102).unwrap();
+    expected.write_all(&[1, 2, 3]).unwrap();
+
+    for &x in &[-1, 2] {
+        expected.write_i16::<T>(x).unwrap();
+    }
+
+    expected.write_u16::<T>(2).unwrap();
+
+    for &x in &[3, -4, 0x1234, -0x1234] {
+        expected.write_i16::<T>(x).unwrap();
+    }
+
+    expected.write_u32::<bite::LittleEndian>(0xdead_beef).unwrap();
+    expected.write_i32::<T>(i32::MIN).unwrap();
+    expected.write_i32::<T>(0x0506_0708).unwrap();
+
+    let mut stream = Vec::new();
+    record.write_to::<T, _>(&mut stream).unwrap();
+    assert_eq!(expected, stream);
+
+    let mut cursor = Cursor::new(&stream);
+    assert_eq!(record, Record::read_from::<T, _>(&mut cursor).unwrap());
+    assert_eq!(stream.len() as u64, cursor.position());
+
+    // a short stream fails part of the way through the counted vector.
+    let error = Record::read_from::<T, _>(&mut Cursor::new(&stream[..20])).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+
+    let mismatched = Record { count: 3, ..record };
+    let error      = mismatched.write_to::<T, _>(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidInput, error.kind());
+    assert_eq!("`Record::points` has 2 elements, but `Record::count` is 3", error.to_string());
+
+    println!("verify: [{}] a derived {} byte record round-trips, and a mismatched count fails with {:?}", name, stream.len(), error.to_string());
+}
+
+
+// derived enums must store their discriminant as the type and encoding that they name, falling back on their own
+// `#[repr]`, and reject unknown discriminants by name unless an `other` variant catches them - which writes whatever
+// it holds, even a discriminant that reads back as a named variant.
+#[cfg(feature = "derive")]
+fn derived_enums() {
+    use {
+        std::io::ErrorKind,
+
+        bite::BigEndian,
+        bite::BiteEnum,
+        bite::BiteRead,
+        bite::BiteWrite,
+        bite::LittleEndian,
+    };
+
+    #[derive(BiteEnum, Clone, Copy, Debug, PartialEq)]
+    #[bite(repr = "u16", endian = "big")]
+    enum Kind { Data = 1, Ack, Reset = 0x100 }
+
+    #[derive(BiteEnum, Clone, Copy, Debug, PartialEq)]
+    #[repr(i8)]
+    enum Level { Low = -1, Middle, High }
+
+    #[derive(BiteEnum, Clone, Copy, Debug, PartialEq)]
+    #[bite(repr = "u32")]
+    #[repr(u8)]
+    enum Opcode { Nop, Load = 7, Store, #[bite(other)] Unknown(u32) }
+
+    fn write<T: BiteWrite, E: Endianness>(value: T) -> Vec<u8> {
+        let mut stream = Vec::new();
+        value.write_to::<E, _>(&mut stream).unwrap();
+        stream
+    }
+
+    fn read<T: BiteRead, E: Endianness>(stream: &[u8]) -> Result<T, std::io::Error> {
+        T::read_from::<E, _>(&mut Cursor::new(stream))
+    }
+
+    // `endian` on the enum wins over the encoding it's written with.
+    assert_eq!(vec![0x00, 0x02], write::<_, LittleEndian>(Kind::Ack));
+    assert_eq!(vec![0x01, 0x00], write::<_, LittleEndian>(Kind::Reset));
+    assert_eq!(Kind::Data,       read::<Kind, LittleEndian>(&[0x00, 0x01]).unwrap());
+    assert_eq!(Kind::Reset,      read::<Kind, BigEndian>(&[0x01, 0x00]).unwrap());
+
+    let error = read::<Kind, LittleEndian>(&[0x00, 0x03]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+    assert_eq!("3 is not a valid `Kind`", error.to_string());
+
+    // without a `repr` attribute the discriminant is the enum's own `#[repr(i8)]`, counting on from `Low`.
+    assert_eq!(vec![0xff],    write::<_, BigEndian>(Level::Low));
+    assert_eq!(vec![0x01],    write::<_, BigEndian>(Level::High));
+    assert_eq!(Level::Middle, read::<Level, BigEndian>(&[0x00]).unwrap());
+    assert_eq!("-2 is not a valid `Level`", read::<Level, BigEndian>(&[0xfe]).unwrap_err().to_string());
+
+    // `repr` on the `bite` attribute wins over `#[repr(u8)]`, and the `other` variant catches unknown discriminants in
+    // either order and writes them back as they were.
+    assert_eq!(vec![0, 0, 0, 8],             write::<_, BigEndian>(Opcode::Store));
+    assert_eq!(vec![8, 0, 0, 0],             write::<_, LittleEndian>(Opcode::Store));
+    assert_eq!(Opcode::Nop,                  read::<Opcode, LittleEndian>(&[0, 0, 0, 0]).unwrap());
+    assert_eq!(Opcode::Unknown(0x0901),      read::<Opcode, BigEndian>(&[0, 0, 9, 1]).unwrap());
+    assert_eq!(Opcode::Unknown(0x0109_0000), read::<Opcode, LittleEndian>(&[0, 0, 9, 1]).unwrap());
+    assert_eq!(vec![0, 0, 9, 1],             write::<_, BigEndian>(Opcode::Unknown(0x0901)));
+
+    // an `Unknown` holding a declared discriminant couldn't be read back as itself, so it isn't written at all.
+    let mut stream = Vec::new();
+    let error      = Opcode::Unknown(7).write_to::<LittleEndian, _>(&mut stream).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidInput, error.kind());
+    assert_eq!("`Opcode::Unknown(7)` holds the discriminant of another variant", error.to_string());
+    assert!(stream.is_empty());
+
+    println!("verify: [derive] enums store their discriminants, and {:?} is refused", Opcode::Unknown(7));
+}
+
+
+// the tokio extension traits must encode exactly as the blocking traits do, and read everything back through a stream
+// that fills its `ReadBuf` a byte at a time and is pending in between.
+#[cfg(feature = "tokio")]
+fn tokio_streams<T: Endianness>(name: &str) {
+    extern crate tokio;
+
+    use {
+        std::future::Future,
+        std::pin::Pin,
+        std::task::Context,
+        std::task::Poll,
+        std::task::Waker,
+
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+        bite::tokio::AsyncBiteReadExpandedExt,
+        bite::tokio::AsyncBiteWriteExpandedExt,
+    };
+
+    // reads a byte and writes up to three on every other poll, and is pending in between.
+    struct Trickle {
+        data:     Vec<u8>,
+        position: usize,
+        pending:  bool,
+    }
+
+    impl tokio::io::AsyncRead for Trickle {
+        fn poll_read(mut self: Pin<&mut Self>, _: &mut Context, buffer: &mut tokio::io::ReadBuf) -> Poll<std::io::Result<()>> {
+            self.pending = !self.pending;
+
+            if self.pending {
+                return Poll::Pending;
+            }
+
+            if let Some(&x) = self.data.get(self.position) {
+                buffer.put_slice(&[x]);
+                self.position += 1;
+            }
+
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl tokio::io::AsyncWrite for Trickle {
+        fn poll_write(mut self: Pin<&mut Self>, _: &mut Context, buffer: &[u8]) -> Poll<std::io::Result<usize>> {
+            self.pending = !self.pending;
+
+            if self.pending {
+                return Poll::Pending;
+            }
+
+            let length = std::cmp::min(3, buffer.len());
+
+            self.data.extend_from_slice(&buffer[..length]);
+            Poll::Ready(Ok(length))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<std::io::Result<()>>    { Poll::Ready(Ok(())) }
+        fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context) -> Poll<std::io::Result<()>> { Poll::Ready(Ok(())) }
+    }
+
+    // polls `future` until it's ready.
+    fn wait<F: Future + Unpin>(mut future: F) -> F::Output {
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(x) = Pin::new(&mut future).poll(&mut context) {
+                return x;
+            }
+        }
+    }
+
+    let values = [1, 0x0203_0405, 0xdead_beef, u32::MAX];
+
+    let mut stream = Trickle { data: Vec::new(), position: 0, pending: false };
+    wait(stream.write_u16::<T>(0x1234)).unwrap();
+    wait(stream.write_i24::<T>(-2)).unwrap();
+    wait(stream.write_u64::<T>(0x0102_0304_0506_0708)).unwrap();
+    wait(stream.write_f64::<T>(-1.5)).unwrap();
+    wait(stream.write_int::<T>(-3, 5)).unwrap();
+    wait(stream.write_slice_u32::<T>(&values)).unwrap();
+    wait(stream.write_framed::<T>(b"bite")).unwrap();
+    wait(stream.write_u8(7)).unwrap();
+
+    let mut blocking = Vec::new();
+    BiteWriteExpandedExt::write_u16::<T>(&mut blocking, 0x1234).unwrap();
+    BiteWriteExpandedExt::write_i24::<T>(&mut blocking, -2).unwrap();
+    BiteWriteExpandedExt::write_u64::<T>(&mut blocking, 0x0102_0304_0506_0708).unwrap();
+    BiteWriteExpandedExt::write_f64::<T>(&mut blocking, -1.5).unwrap();
+    BiteWriteExpandedExt::write_int::<T>(&mut blocking, -3, 5).unwrap();
+    BiteWriteExpandedExt::write_slice_u32::<T>(&mut blocking, &values).unwrap();
+    BiteWriteExpandedExt::write_framed::<T>(&mut blocking, b"bite").unwrap();
+    BiteWriteExpandedExt::write_u8(&mut blocking, 7).unwrap();
+    assert_eq!(blocking, stream.data);
+
+    let mut slice = [0; 4];
+
+    stream.pending = false;
+    assert_eq!(0x1234,                wait(stream.read_u16::<T>()).unwrap());
+    assert_eq!(-2,                    wait(stream.read_i24::<T>()).unwrap());
+    assert_eq!(0x0102_0304_0506_0708, wait(stream.read_u64::<T>()).unwrap());
+    assert_eq!(-1.5,                  wait(stream.read_f64::<T>()).unwrap());
+    assert_eq!(-3,                    wait(stream.read_int::<T>(5)).unwrap());
+    wait(stream.read_slice_u32::<T>(&mut slice)).unwrap();
+    assert_eq!(values, slice);
+    assert_eq!(b"bite".to_vec(),      wait(stream.read_framed_max::<T>(16)).unwrap());
+    assert_eq!(7,                     wait(stream.read_u8()).unwrap());
+    assert_eq!(std::io::ErrorKind::UnexpectedEof, wait(stream.read_u8()).unwrap_err().kind());
+
+    // a frame over the maximum fails just as it does through the blocking traits.
+    let mut blocking = Vec::new();
+    BiteWriteExpandedExt::write_framed::<T>(&mut blocking, &[0; 17]).unwrap();
+
+    let expected   = BiteReadExpandedExt::read_framed_max::<T>(&mut Cursor::new(&blocking), 16).unwrap_err();
+    let mut stream = Trickle { data: blocking, position: 0, pending: false };
+    let error      = wait(stream.read_framed_max::<T>(16)).unwrap_err();
+
+    assert_eq!(expected.kind(), error.kind());
+
+    println!("verify: [{}] tokio streams match the blocking traits a byte at a time, and refuse a 17 byte frame with {:?}", name, error.kind());
+}
+
+
+// a frame read through `FrameReader` must survive its future being dropped part of the way through, and async writes
+// must produce the same bytes as the blocking traits.
+#[cfg(feature = "futures-io")]
+fn cancelled_frames() {
+    extern crate futures_io;
+
+    use {
+        std::future::Future,
+        std::pin::Pin,
+        std::task::Context,
+        std::task::Poll,
+        std::task::Waker,
+
+        bite::BiteWriteExt,
+        bite::futures::AsyncBiteWriteExt,
+        bite::futures::FrameReader,
+    };
+
+    // yields a single byte on every other poll, and is pending in between.
+    struct Trickle {
+        data:    Vec<u8>,
+        pending: bool,
+    }
+
+    impl futures_io::AsyncRead for Trickle {
+        fn poll_read(mut self: Pin<&mut Self>, _: &mut Context, buffer: &mut [u8]) -> Poll<std::io::Result<usize>> {
+            self.pending = !self.pending;
+
+            match (self.pending, self.data.is_empty()) {
+                (true, _)      => Poll::Pending,
+                (false, true)  => Poll::Ready(Ok(0)),
+                (false, false) => { buffer[0] = self.data.remove(0); Poll::Ready(Ok(1)) },
+            }
+        }
+    }
+
+    let mut context = Context::from_waker(Waker::noop());
+
+    let mut stream = Vec::new();
+
+    for value in &[&b"bite"[..], b"ok"] {
+        let mut future = AsyncBiteWriteExt::write_framed(&mut stream, value);
+        assert!(matches!(Pin::new(&mut future).poll(&mut context), Poll::Ready(Ok(()))));
+    }
+
+    let mut blocking = Vec::new();
+    BiteWriteExt::write_framed(&mut blocking, b"bite").unwrap();
+    BiteWriteExt::write_framed(&mut blocking, b"ok").unwrap();
+    assert_eq!(blocking, stream);
+
+    let mut reader: FrameReader<_> = FrameReader::new(Trickle { data: stream, pending: false });
+
+    // poll the first read until it has consumed the length and one byte of the payload, then drop it.
+    {
+        let mut frame = reader.read_frame();
+
+        for _ in 0..6 {
+            assert!(Pin::new(&mut frame).poll(&mut context).is_pending());
+        }
+    }
+
+    assert!(reader.is_partial());
+
+    let mut frames = Vec::new();
+
+    while frames.len() < 2 {
+        if let Poll::Ready(frame) = reader.poll_frame(&mut context) {
+            frames.push(frame.unwrap());
+        }
+    }
+
+    assert_eq!(frames, [b"bite".to_vec(), b"ok".to_vec()]);
+    assert!(!reader.is_partial());
+
+    println!("verify: [futures] {:?} == [\"bite\", \"ok\"] after a cancelled read", frames.iter().map(|x| String::from_utf8_lossy(x)).collect::<Vec<_>>());
+}
+
+
+
+// `BiteFrameCodec` must match the blocking wire format, decode a frame that arrives one byte at a time, and refuse
+// frames over its maximum in both directions.
+#[cfg(feature = "tokio-util")]
+fn codec() {
+    use {
+        bytes::BytesMut,
+        tokio_util::codec::Decoder,
+        tokio_util::codec::Encoder,
+
+        bite::BigEndian,
+        bite::BiteFrameCodec,
+        bite::BiteReadExpandedExt,
+        bite::BiteWriteExpandedExt,
+    };
+
+    let mut codec: BiteFrameCodec<BigEndian> = BiteFrameCodec::with_maximum(8);
+
+    let mut encoded = BytesMut::new();
+    codec.encode(&b"bite"[..], &mut encoded).unwrap();
+    codec.encode(Vec::new(), &mut encoded).unwrap();
+    assert!(codec.encode(&[0; 9][..], &mut encoded).is_err());
+
+    let mut blocking = Vec::new();
+    blocking.write_framed::<BigEndian>(b"bite").unwrap();
+    blocking.write_framed::<BigEndian>(b"").unwrap();
+    assert_eq!(&blocking[..], &encoded[..]);
+
+    let mut source = BytesMut::new();
+    let mut frames = Vec::new();
+
+    for &x in encoded.iter() {
+        source.extend_from_slice(&[x]);
+
+        while let Some(frame) = codec.decode(&mut source).unwrap() {
+            frames.push(frame);
+        }
+    }
+
+    assert_eq!(frames, [&b"bite"[..], b""]);
+    assert!(codec.decode_eof(&mut source).unwrap().is_none());
+
+    let mut cursor = Cursor::new(&blocking[..]);
+    assert_eq!(b"bite", &cursor.read_framed::<BigEndian>().unwrap()[..]);
+
+    let mut oversized = BytesMut::from(&[0, 0, 0, 9][..]);
+    assert!(codec.decode(&mut oversized).is_err());
+
+    let mut truncated = BytesMut::from(&[0, 0, 0, 2, 1][..]);
+    assert!(codec.decode_eof(&mut truncated).is_err());
+
+    println!("verify: [codec] {:?} == [\"bite\", \"\"] from single bytes", frames.iter().map(|x| String::from_utf8_lossy(x)).collect::<Vec<_>>());
+}